@@ -0,0 +1,553 @@
+//! Build script: compiles the built-in verb data and word list into static
+//! lookup tables, so engines start without building any index at runtime.
+//!
+//! - `verb_tables.rs`: verb entries, perfect-hash indexes of bases, forms
+//!   (with their roles), aliases and the synonym/antonym graph, plus the
+//!   category and group indexes. Mirrors what `VerbDatabase::add` builds.
+//! - `dictionary_tables.rs`: the word list, its perfect-hash index and a
+//!   flattened BK-tree for fuzzy search.
+//! - `semantic_tables.rs`, `sentiment_tables.rs`, `gazetteer_tables.rs`: the
+//!   noun lexicon, the sentiment word list and the named-entity gazetteer,
+//!   each with a perfect-hash index.
+//! - `frequency_tables.rs`: Zipf values of frequent words, for topic keywords.
+
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/phf.rs"]
+mod phf;
+
+#[allow(dead_code)]
+#[path = "src/verbs/inflection.rs"]
+mod inflection;
+
+#[allow(dead_code)]
+#[path = "src/verbs/tsv.rs"]
+mod tsv;
+
+#[allow(dead_code)]
+#[path = "src/dictionary/bktree.rs"]
+mod bktree;
+
+const VERB_DATA: &str = "src/verbs/builtin_verbs.tsv";
+const WORD_DATA: &str = "src/dictionary/common_words.txt";
+const NOUN_DATA: &str = "src/semantic/nouns.tsv";
+const SENTIMENT_DATA: &str = "src/sentiment/words.tsv";
+const GAZETTEER_DATA: &str = "src/ner/gazetteer.tsv";
+const FREQUENCY_DATA: &str = "src/topic/word_frequencies.tsv";
+
+fn main() {
+    for path in [VERB_DATA, WORD_DATA, NOUN_DATA, SENTIMENT_DATA, GAZETTEER_DATA, FREQUENCY_DATA, "src/phf.rs", "src/verbs/inflection.rs", "src/verbs/tsv.rs", "src/dictionary/bktree.rs"] {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR");
+
+    let verbs = fs::read_to_string(VERB_DATA).expect("read verb data");
+    let entries = parse_verbs(&verbs);
+    let tables = VerbTables::build(&entries);
+    fs::write(Path::new(&out_dir).join("verb_tables.rs"), tables.render()).expect("write verb tables");
+
+    let words = fs::read_to_string(WORD_DATA).expect("read word list");
+    fs::write(Path::new(&out_dir).join("dictionary_tables.rs"), render_dictionary(&words)).expect("write dictionary tables");
+
+    let nouns = fs::read_to_string(NOUN_DATA).expect("read noun lexicon");
+    fs::write(Path::new(&out_dir).join("semantic_tables.rs"), render_nouns(&nouns)).expect("write semantic tables");
+
+    let sentiment = fs::read_to_string(SENTIMENT_DATA).expect("read sentiment words");
+    fs::write(Path::new(&out_dir).join("sentiment_tables.rs"), render_sentiment(&sentiment)).expect("write sentiment tables");
+
+    let gazetteer = fs::read_to_string(GAZETTEER_DATA).expect("read gazetteer");
+    fs::write(Path::new(&out_dir).join("gazetteer_tables.rs"), render_gazetteer(&gazetteer)).expect("write gazetteer tables");
+
+    let frequencies = fs::read_to_string(FREQUENCY_DATA).expect("read word frequencies");
+    fs::write(Path::new(&out_dir).join("frequency_tables.rs"), render_frequencies(&frequencies)).expect("write frequency tables");
+}
+
+/// Fields of the data lines of a TSV file, with their line numbers
+fn data_lines(text: &str) -> impl Iterator<Item = (usize, Vec<&str>)> {
+    text.lines().enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| (i + 1, line.split('\t').map(str::trim).collect()))
+}
+
+/// A static table of `(key, ...)` rows with a perfect-hash index of the keys
+fn render_keyed(out: &mut String, prefix: &str, ty: &str, rows: &[(String, String)]) {
+    writeln!(out, "static {}: [{}; {}] = [", prefix, ty, rows.len()).unwrap();
+    for (_, row) in rows {
+        writeln!(out, "    {},", row).unwrap();
+    }
+    out.push_str("];\n");
+    let keys: Vec<&str> = rows.iter().map(|(key, _)| key.as_str()).collect();
+    PerfectHash::build(&keys).render(out, prefix);
+}
+
+// ============================================================================
+// Perfect hashing
+// ============================================================================
+
+/// Displacement and slot arrays for `phf::index`
+struct PerfectHash {
+    displacements: Vec<u32>,
+    slots: Vec<u32>,
+}
+
+impl PerfectHash {
+    /// Find a displacement per bucket so that every key gets its own slot
+    fn build(keys: &[&str]) -> Self {
+        let bucket_count = (keys.len() / 4).max(1);
+        let slot_count = (keys.len() + keys.len() / 4).max(1);
+
+        let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); bucket_count];
+        for (i, key) in keys.iter().enumerate() {
+            buckets[phf::hash(key, 0) as usize % bucket_count].push(i);
+        }
+        let mut order: Vec<usize> = (0..bucket_count).collect();
+        order.sort_by_key(|&b| std::cmp::Reverse(buckets[b].len()));
+
+        let mut displacements = vec![0; bucket_count];
+        let mut slots = vec![phf::EMPTY; slot_count];
+        for b in order {
+            if buckets[b].is_empty() {
+                continue;
+            }
+            let mut seed = 1u32;
+            loop {
+                let targets: Vec<usize> = buckets[b].iter()
+                    .map(|&i| phf::hash(keys[i], seed) as usize % slot_count)
+                    .collect();
+                let distinct: HashSet<usize> = targets.iter().copied().collect();
+                if distinct.len() == targets.len() && targets.iter().all(|&t| slots[t] == phf::EMPTY) {
+                    for (&i, &t) in buckets[b].iter().zip(&targets) {
+                        slots[t] = i as u32;
+                    }
+                    displacements[b] = seed;
+                    break;
+                }
+                seed += 1;
+            }
+        }
+        Self { displacements, slots }
+    }
+
+    fn render(&self, out: &mut String, prefix: &str) {
+        writeln!(out, "static {}_DISPLACEMENTS: [u32; {}] = {:?};", prefix, self.displacements.len(), self.displacements).unwrap();
+        writeln!(out, "static {}_SLOTS: [u32; {}] = {:?};", prefix, self.slots.len(), self.slots).unwrap();
+    }
+}
+
+// ============================================================================
+// Verbs
+// ============================================================================
+
+/// One parsed line of the verb data (format of `verbs::format`)
+struct Verb {
+    base: String,
+    past: String,
+    past_participle: String,
+    present_participle: String,
+    third_person: String,
+    variants: Vec<String>,
+    category: String,
+    group: String,
+    irregular: bool,
+    transitive: &'static str,
+    frequency: u8,
+    synonyms: Vec<String>,
+    antonyms: Vec<String>,
+    /// `Frame` constant names, `None` for the group defaults
+    frames: Option<Vec<String>>,
+}
+
+impl Verb {
+    /// Surface forms with their `FormRole` variant, as `VerbEntry::forms`
+    fn forms(&self) -> Vec<(&str, &'static str)> {
+        let mut forms = vec![
+            (self.base.as_str(), "Base"),
+            (self.past.as_str(), "Past"),
+            (self.past_participle.as_str(), "PastParticiple"),
+            (self.present_participle.as_str(), "PresentParticiple"),
+            (self.third_person.as_str(), "ThirdPerson"),
+        ];
+        for variant in &self.variants {
+            if variant.ends_with("ing") {
+                forms.push((variant.as_str(), "PresentParticiple"));
+            } else {
+                forms.push((variant.as_str(), "Past"));
+                forms.push((variant.as_str(), "PastParticiple"));
+            }
+        }
+        forms
+    }
+}
+
+fn parse_verbs(text: &str) -> Vec<Verb> {
+    let mut verbs = Vec::new();
+    for (i, raw) in text.lines().enumerate() {
+        if tsv::is_blank(raw) {
+            continue;
+        }
+        let line = tsv::parse_line(
+            raw,
+            |name| variant(tsv::variant_name(name, tsv::CATEGORY_NAMES)),
+            |name| variant(tsv::variant_name(name, tsv::GROUP_NAMES)),
+        ).unwrap_or_else(|e| panic!("{}:{}: {:?}", VERB_DATA, i + 1, e));
+        if line.custom_category.is_some() || line.custom_group.is_some() {
+            panic!("{}:{}: built-in verbs cannot name a custom category or group", VERB_DATA, i + 1);
+        }
+
+        let variants = if line.irregular { Vec::new() } else { inflection::british_variants(&line.base) };
+        verbs.push(Verb {
+            base: line.base,
+            past: line.past,
+            past_participle: line.past_participle,
+            present_participle: line.present_participle,
+            third_person: line.third_person,
+            variants,
+            category: line.category,
+            group: line.group,
+            irregular: line.irregular,
+            transitive: match line.transitive {
+                Some(true) => "Some(true)",
+                Some(false) => "Some(false)",
+                None => "None",
+            },
+            frequency: line.frequency,
+            synonyms: line.synonyms,
+            antonyms: line.antonyms,
+            frames: line.frames.map(|names| names.iter().map(|n| n.to_uppercase()).collect()),
+        });
+    }
+    verbs
+}
+
+/// An enum variant name as written; the generated code names the variant
+/// directly, so anything else is rejected here
+fn variant(name: &str) -> Option<String> {
+    let mut chars = name.chars();
+    (chars.next().is_some_and(|c| c.is_ascii_uppercase()) && chars.all(|c| c.is_ascii_alphanumeric()))
+        .then(|| name.to_string())
+}
+
+/// Indexes built by replaying `VerbDatabase::add` over the data in order
+struct VerbTables<'a> {
+    verbs: &'a [Verb],
+    bases: Vec<&'a str>,
+    base_entries: Vec<Vec<usize>>,
+    forms: Vec<&'a str>,
+    readings: HashMap<&'a str, Vec<(usize, &'static str)>>,
+    categories: Vec<(&'a str, Vec<usize>)>,
+    groups: Vec<(&'a str, Vec<usize>)>,
+    synonyms: HashMap<String, HashSet<String>>,
+    antonyms: HashMap<String, HashSet<String>>,
+    aliases: Vec<(String, String, String)>,
+}
+
+impl<'a> VerbTables<'a> {
+    fn build(verbs: &'a [Verb]) -> Self {
+        let mut tables = VerbTables {
+            verbs,
+            bases: Vec::new(),
+            base_entries: Vec::new(),
+            forms: Vec::new(),
+            readings: HashMap::new(),
+            categories: Vec::new(),
+            groups: Vec::new(),
+            synonyms: HashMap::new(),
+            antonyms: HashMap::new(),
+            aliases: Vec::new(),
+        };
+        let mut base_index: HashMap<&str, usize> = HashMap::new();
+        let mut alias_forms: HashSet<String> = HashSet::new();
+
+        for (i, verb) in verbs.iter().enumerate() {
+            let b = *base_index.entry(&verb.base).or_insert_with(|| {
+                tables.bases.push(&verb.base);
+                tables.base_entries.push(Vec::new());
+                tables.bases.len() - 1
+            });
+            tables.base_entries[b].push(i);
+
+            for (form, role) in verb.forms() {
+                let readings = tables.readings.entry(form).or_insert_with(|| {
+                    tables.forms.push(form);
+                    Vec::new()
+                });
+                if !readings.contains(&(b, role)) {
+                    readings.push((b, role));
+                }
+            }
+            push_index(&mut tables.categories, &verb.category, b);
+            push_index(&mut tables.groups, &verb.group, b);
+
+            for syn in &verb.synonyms {
+                link(&mut tables.synonyms, &verb.base, syn);
+                if !syn.contains(' ') && !tables.readings.contains_key(syn.as_str()) && !alias_forms.contains(syn) {
+                    let forms = inflection::inflect(syn);
+                    for form in [syn.clone(), forms.past, forms.present_participle, forms.third_person] {
+                        if alias_forms.insert(form.clone()) {
+                            tables.aliases.push((form, syn.clone(), verb.base.clone()));
+                        }
+                    }
+                }
+            }
+            for ant in &verb.antonyms {
+                link(&mut tables.antonyms, &verb.base, ant);
+            }
+        }
+        assert!(verbs.len() < u16::MAX as usize && tables.bases.len() < u16::MAX as usize, "too many verbs for u16 indexes");
+        tables
+    }
+
+    fn render(&self) -> String {
+        let mut out = String::from("// @generated by build.rs from src/verbs/builtin_verbs.tsv\n\n");
+
+        writeln!(out, "pub(super) static ENTRIES: [BuiltinEntry; {}] = [", self.verbs.len()).unwrap();
+        for v in self.verbs {
+            writeln!(
+                out,
+                "    BuiltinEntry {{ base: {:?}, past: {:?}, past_participle: {:?}, present_participle: {:?}, \
+                 third_person: {:?}, variants: &{:?}, category: FunctionalCategory::{}, group: VerbGroup::{}, \
+                 irregular: {}, transitive: {}, frequency: {}, synonyms: &{:?}, antonyms: &{:?}, frames: {} }},",
+                v.base, v.past, v.past_participle, v.present_participle, v.third_person, v.variants,
+                v.category, v.group, v.irregular, v.transitive, v.frequency, v.synonyms, v.antonyms,
+                v.frames.as_ref().map_or("None".to_string(), |names| {
+                    let frames: Vec<String> = names.iter().map(|n| format!("Frame::{}", n)).collect();
+                    format!("Some(&[{}])", frames.join(", "))
+                }),
+            ).unwrap();
+        }
+        out.push_str("];\n\n");
+
+        writeln!(out, "pub(super) const BASE_COUNT: usize = {};", self.bases.len()).unwrap();
+        writeln!(out, "pub(super) static BASES: [BuiltinBase; BASE_COUNT] = [").unwrap();
+        for (b, base) in self.bases.iter().enumerate() {
+            let entries = &self.base_entries[b];
+            let frequency = entries.iter().map(|&i| self.verbs[i].frequency).max().unwrap_or(0);
+            writeln!(out, "    BuiltinBase {{ name: {:?}, entries: &{:?}, frequency: {} }},", base, entries, frequency).unwrap();
+        }
+        out.push_str("];\n");
+        PerfectHash::build(&self.bases).render(&mut out, "BASE");
+        out.push('\n');
+
+        writeln!(out, "pub(super) static FORMS: [(&str, &[(u16, FormRole)]); {}] = [", self.forms.len()).unwrap();
+        for form in &self.forms {
+            let readings: Vec<String> = self.readings[form].iter()
+                .map(|(b, role)| format!("({}, FormRole::{})", b, role))
+                .collect();
+            writeln!(out, "    ({:?}, &[{}]),", form, readings.join(", ")).unwrap();
+        }
+        out.push_str("];\n");
+        PerfectHash::build(&self.forms).render(&mut out, "FORM");
+        out.push('\n');
+
+        writeln!(out, "pub(super) static ALIASES: [(&str, &str, &str); {}] = [", self.aliases.len()).unwrap();
+        for (form, syn, head) in &self.aliases {
+            writeln!(out, "    ({:?}, {:?}, {:?}),", form, syn, head).unwrap();
+        }
+        out.push_str("];\n");
+        let alias_keys: Vec<&str> = self.aliases.iter().map(|(f, ..)| f.as_str()).collect();
+        PerfectHash::build(&alias_keys).render(&mut out, "ALIAS");
+        out.push('\n');
+
+        render_graph(&mut out, "SYNONYM", &self.synonyms);
+        render_graph(&mut out, "ANTONYM", &self.antonyms);
+        render_index(&mut out, "category_bases", "FunctionalCategory", &self.categories);
+        render_index(&mut out, "group_bases", "VerbGroup", &self.groups);
+        out
+    }
+}
+
+fn push_index<'a>(index: &mut Vec<(&'a str, Vec<usize>)>, key: &'a str, base: usize) {
+    match index.iter_mut().find(|(k, _)| *k == key) {
+        Some((_, bases)) => bases.push(base),
+        None => index.push((key, vec![base])),
+    }
+}
+
+fn link(graph: &mut HashMap<String, HashSet<String>>, a: &str, b: &str) {
+    if a == b {
+        return;
+    }
+    graph.entry(a.to_string()).or_default().insert(b.to_string());
+    graph.entry(b.to_string()).or_default().insert(a.to_string());
+}
+
+/// Graph as (node, sorted neighbours) pairs with a perfect-hash index
+fn render_graph(out: &mut String, prefix: &str, graph: &HashMap<String, HashSet<String>>) {
+    let mut nodes: Vec<&str> = graph.keys().map(|k| k.as_str()).collect();
+    nodes.sort();
+    writeln!(out, "pub(super) static {}_GRAPH: [(&str, &[&str]); {}] = [", prefix, nodes.len()).unwrap();
+    for node in &nodes {
+        let mut next: Vec<&str> = graph[*node].iter().map(|s| s.as_str()).collect();
+        next.sort();
+        writeln!(out, "    ({:?}, &{:?}),", node, next).unwrap();
+    }
+    out.push_str("];\n");
+    PerfectHash::build(&nodes).render(out, prefix);
+    out.push('\n');
+}
+
+/// Enum -> base indices as a `match`
+fn render_index(out: &mut String, name: &str, ty: &str, index: &[(&str, Vec<usize>)]) {
+    writeln!(out, "#[allow(unreachable_patterns)]\npub(super) fn {}(key: {}) -> &'static [u16] {{\n    match key {{", name, ty).unwrap();
+    for (key, bases) in index {
+        writeln!(out, "        {}::{} => &{:?},", ty, key, bases).unwrap();
+    }
+    out.push_str("        _ => &[],\n    }\n}\n\n");
+}
+
+// ============================================================================
+// Dictionary
+// ============================================================================
+
+fn render_dictionary(text: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    for line in text.lines() {
+        let word = line.trim_start_matches('\u{feff}').trim().to_lowercase();
+        if !word.is_empty() && !word.starts_with('#') && seen.insert(word.clone()) {
+            words.push(word);
+        }
+    }
+    assert!(words.len() < u16::MAX as usize, "too many words for u16 indexes");
+
+    // BK-tree in insertion order; node i holds word i and lists its
+    // (distance, child) edges as a run of BK_EDGES
+    let mut children: Vec<Vec<(usize, usize)>> = vec![Vec::new(); words.len()];
+    for i in 1..words.len() {
+        let mut node = 0;
+        loop {
+            let dist = bktree::levenshtein(&words[node], &words[i]);
+            match children[node].iter().find(|(d, _)| *d == dist) {
+                Some(&(_, child)) => node = child,
+                None => {
+                    children[node].push((dist, i));
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut out = String::from("// @generated by build.rs from src/dictionary/common_words.txt\n\n");
+    writeln!(out, "pub(super) static WORDS: [&str; {}] = {:?};", words.len(), words).unwrap();
+    let keys: Vec<&str> = words.iter().map(|w| w.as_str()).collect();
+    PerfectHash::build(&keys).render(&mut out, "WORD");
+
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    for edges_of in &children {
+        nodes.push(format!("({}, {})", edges.len(), edges_of.len()));
+        edges.extend(edges_of.iter().map(|(d, c)| format!("({}, {})", d, c)));
+    }
+    writeln!(out, "pub(super) static BK_NODES: [(u32, u16); {}] = [{}];", nodes.len(), nodes.join(", ")).unwrap();
+    writeln!(out, "pub(super) static BK_EDGES: [(u16, u32); {}] = [{}];", edges.len(), edges.join(", ")).unwrap();
+    out
+}
+
+// ============================================================================
+// Semantic lexicon, sentiment words, gazetteer
+// ============================================================================
+
+/// A field that may be empty or '-'
+fn optional<'a>(fields: &[&'a str], i: usize) -> Option<&'a str> {
+    fields.get(i).copied().filter(|f| !f.is_empty() && *f != "-")
+}
+
+/// Whether `SemanticCategory::parse` accepts a "name[:detail]" spec
+fn is_noun_category(spec: &str) -> bool {
+    let (name, detail) = match spec.split_once(':') {
+        Some((name, detail)) => (name, Some(detail)),
+        None => (spec, None),
+    };
+    match name {
+        "place" | "object" | "action" | "time" => detail.is_some(),
+        "emotion" => matches!(detail, Some("positive" | "negative" | "neutral")),
+        "person" | "concept" | "quantity" | "quality" | "unknown" => true,
+        _ => false,
+    }
+}
+
+/// Nouns as (word, category spec, subcategory, tags); the first line for a word wins
+fn render_nouns(text: &str) -> String {
+    let mut rows = Vec::new();
+    let mut seen = HashSet::new();
+    for (n, fields) in data_lines(text) {
+        let word = fields[0].to_lowercase();
+        let category = fields.get(1).copied().filter(|c| is_noun_category(c))
+            .unwrap_or_else(|| panic!("{}:{}: bad semantic category", NOUN_DATA, n));
+        let tags: Vec<&str> = optional(&fields, 3).map(|t| t.split(',').collect()).unwrap_or_default();
+        if seen.insert(word.clone()) {
+            let row = format!("({:?}, {:?}, {:?}, &{:?})", word, category, optional(&fields, 2), tags);
+            rows.push((word, row));
+        }
+    }
+    let mut out = String::from("// @generated by build.rs from src/semantic/nouns.tsv\n\n");
+    render_keyed(&mut out, "NOUNS", "(&str, &str, Option<&str>, &[&str])", &rows);
+    out
+}
+
+/// Words with their prior polarity
+fn render_sentiment(text: &str) -> String {
+    let mut rows = Vec::new();
+    let mut seen = HashSet::new();
+    for (n, fields) in data_lines(text) {
+        let word = fields[0].to_lowercase();
+        let score: f64 = fields.get(1).and_then(|s| s.parse().ok()).filter(|s: &f64| (-1.0..=1.0).contains(s))
+            .unwrap_or_else(|| panic!("{}:{}: expected word<TAB>polarity in [-1, 1]", SENTIMENT_DATA, n));
+        if seen.insert(word.clone()) {
+            rows.push((word.clone(), format!("({:?}, {:?})", word, score)));
+        }
+    }
+    let mut out = String::from("// @generated by build.rs from src/sentiment/words.tsv\n\n");
+    render_keyed(&mut out, "POLARITY", "(&str, f64)", &rows);
+    out
+}
+
+/// Names as (lowercase name, entity type, tag)
+fn render_gazetteer(text: &str) -> String {
+    let mut rows = Vec::new();
+    let mut seen = HashSet::new();
+    for (n, fields) in data_lines(text) {
+        let name = fields[0].to_lowercase();
+        let kind = match fields.get(1).copied() {
+            Some("person") => "Person",
+            Some("organization") => "Organization",
+            Some("place") => "Place",
+            Some("date") => "Date",
+            Some("product") => "Product",
+            Some("other") => "Other",
+            _ => panic!("{}:{}: expected name<TAB>entity type", GAZETTEER_DATA, n),
+        };
+        if seen.insert(name.clone()) {
+            let row = format!("({:?}, EntityType::{}, {:?})", name, kind, optional(&fields, 2));
+            rows.push((name, row));
+        }
+    }
+    let mut out = String::from("// @generated by build.rs from src/ner/gazetteer.tsv\n\n");
+    render_keyed(&mut out, "NAMES", "(&str, EntityType, Option<&str>)", &rows);
+    out
+}
+
+// ============================================================================
+// Word frequencies
+// ============================================================================
+
+fn render_frequencies(text: &str) -> String {
+    let mut rows = Vec::new();
+    let mut seen = HashSet::new();
+    for (n, fields) in data_lines(text) {
+        let word = fields[0].to_lowercase();
+        let zipf: f64 = fields.get(1).and_then(|z| z.parse().ok())
+            .unwrap_or_else(|| panic!("{}:{}: expected word<TAB>Zipf value", FREQUENCY_DATA, n));
+        if seen.insert(word.clone()) {
+            rows.push((word.clone(), format!("({:?}, {:?})", word, zipf)));
+        }
+    }
+    let mut out = String::from("// @generated by build.rs from src/topic/word_frequencies.tsv\n\n");
+    render_keyed(&mut out, "ZIPF", "(&str, f64)", &rows);
+    out
+}
@@ -0,0 +1,20 @@
+//! # Form Collisions Example
+//!
+//! Lists the built-in verb forms shared by more than one verb, with the
+//! verb each one resolves to by default.
+
+use nl_sre_english::verbs::VerbDatabase;
+
+fn main() {
+    let db = VerbDatabase::with_builtin();
+    let collisions = db.form_collisions();
+
+    println!("=== Forms shared by several verbs ({}) ===\n", collisions.len());
+    for collision in &collisions {
+        let readings: Vec<String> = collision.readings.iter()
+            .map(|(base, role)| format!("{} ({})", base, role.name()))
+            .collect();
+        let primary = db.base_form(&collision.form).unwrap_or("-");
+        println!("{:<12} -> {:<10} {}", collision.form, primary, readings.join(", "));
+    }
+}
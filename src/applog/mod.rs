@@ -1,18 +1,91 @@
 //! # Application Logic (APPLOG)
 //!
 //! Layer 2: Shared context and constraint management.
+//!
+//! `SharedContext` is also the state of a conversation. The engines update
+//! it as they go:
+//!
+//! - `SemanticDisambiguator::update_context` sets the current topic and
+//!   domain and collects named entities
+//! - `CommandParser::parse_turn` records commands, mentions, named entities
+//!   and unresolved references, resolves pronouns against earlier turns and
+//!   answers follow-ups: "do it again", "the same for the other server",
+//!   "undo that"
+//!
+//! Variables live in nested scopes (sentence inside paragraph inside
+//! session); inner scopes shadow outer ones and vanish when left. Every
+//! change is logged as a `ContextEvent`, and `snapshot` / `restore` roll
+//! the whole context back, so speculative parses can be discarded:
+//!
+//! ```
+//! use nl_sre_english::SharedContext;
+//!
+//! let mut ctx = SharedContext::new();
+//! ctx.set_bool("confirmed", false);
+//! let parsed = ctx.speculate(|ctx| {
+//!     ctx.set_bool("confirmed", true);
+//!     None::<()>
+//! });
+//! assert!(parsed.is_none());
+//! assert_eq!(ctx.get_bool("confirmed"), Some(false));
+//! ```
+
+mod scope;
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
 
-use std::collections::HashMap;
+use crate::command_parser::ParsedCommand;
+use crate::coreference::{Mention, Reference};
+use crate::ner::Entity;
+
+/// Earlier sentences pronouns can reach back to
+const MAX_RECENT_SENTENCES: usize = 3;
+
+/// Commands kept in the history
+const MAX_HISTORY: usize = 20;
+
+/// Mentions and entities kept
+const MAX_MENTIONS: usize = 20;
+
+pub use scope::{ContextError, ContextEvent, Scope, Snapshot};
+
+use scope::Frame;
 
 /// Shared context for semantic analysis
-#[derive(Debug, Clone, Default)]
+///
+/// The state sits behind `Arc`s and is copied on write, so snapshots are cheap.
+#[derive(Debug, Clone)]
 pub struct SharedContext {
-    variables: HashMap<String, ContextValue>,
-    metadata: HashMap<String, String>,
+    /// Variable scopes, session first
+    frames: Vec<Arc<Frame>>,
+    metadata: Arc<HashMap<String, String>>,
+    conversation: Arc<Conversation>,
+    /// Changes not yet drained, oldest first
+    events: VecDeque<ContextEvent>,
+}
+
+/// State of a conversation
+#[derive(Debug, Clone, Default)]
+struct Conversation {
+    /// Category or domain the conversation is about
+    topic: Option<String>,
+    /// Active domain plugin
+    domain: Option<String>,
+    /// Named entities, most recent last
+    entities: Vec<Entity>,
+    /// Noun phrases of recent turns, most recent last
+    mentions: Vec<Mention>,
+    /// Sentences of recent turns
+    recent: VecDeque<String>,
+    /// Commands, most recent last
+    history: VecDeque<ParsedCommand>,
+    /// References of the last turn that found no antecedent
+    unresolved: Vec<Reference>,
 }
 
 /// Context value types
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ContextValue {
     String(String),
     Number(f64),
@@ -20,43 +93,206 @@ pub enum ContextValue {
     List(Vec<ContextValue>),
 }
 
-impl SharedContext {
-    pub fn new() -> Self {
-        Self::default()
+impl ContextValue {
+    /// String value
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ContextValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Number value
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            ContextValue::Number(n) => Some(*n),
+            _ => None,
+        }
     }
 
-    /// Set a string value
-    pub fn set_string(&mut self, key: &str, value: &str) {
-        self.variables.insert(key.to_string(), ContextValue::String(value.to_string()));
+    /// Boolean value
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ContextValue::Boolean(b) => Some(*b),
+            _ => None,
+        }
     }
 
-    /// Set a number value
-    pub fn set_number(&mut self, key: &str, value: f64) {
-        self.variables.insert(key.to_string(), ContextValue::Number(value));
+    /// List value
+    pub fn as_list(&self) -> Option<&[ContextValue]> {
+        match self {
+            ContextValue::List(items) => Some(items),
+            _ => None,
+        }
     }
+}
 
-    /// Get a value
-    pub fn get(&self, key: &str) -> Option<&ContextValue> {
-        self.variables.get(key)
+impl From<&str> for ContextValue {
+    fn from(value: &str) -> Self {
+        ContextValue::String(value.to_string())
     }
+}
 
-    /// Get as string
-    pub fn get_string(&self, key: &str) -> Option<&str> {
-        match self.get(key) {
-            Some(ContextValue::String(s)) => Some(s.as_str()),
-            _ => None,
+impl From<String> for ContextValue {
+    fn from(value: String) -> Self {
+        ContextValue::String(value)
+    }
+}
+
+impl From<f64> for ContextValue {
+    fn from(value: f64) -> Self {
+        ContextValue::Number(value)
+    }
+}
+
+impl From<bool> for ContextValue {
+    fn from(value: bool) -> Self {
+        ContextValue::Boolean(value)
+    }
+}
+
+impl<T: Into<ContextValue>> From<Vec<T>> for ContextValue {
+    fn from(values: Vec<T>) -> Self {
+        ContextValue::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl Default for SharedContext {
+    fn default() -> Self {
+        Self {
+            frames: vec![Arc::new(Frame::new(Scope::Session))],
+            metadata: Arc::default(),
+            conversation: Arc::default(),
+            events: VecDeque::new(),
         }
     }
+}
+
+impl SharedContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
     /// Set metadata
     pub fn set_metadata(&mut self, key: &str, value: &str) {
-        self.metadata.insert(key.to_string(), value.to_string());
+        Arc::make_mut(&mut self.metadata).insert(key.to_string(), value.to_string());
+        self.emit(ContextEvent::MetadataSet { key: key.to_string(), value: value.to_string() });
     }
 
     /// Get metadata
     pub fn get_metadata(&self, key: &str) -> Option<&str> {
         self.metadata.get(key).map(|s| s.as_str())
     }
+
+    /// Current topic
+    pub fn topic(&self) -> Option<&str> {
+        self.conversation.topic.as_deref()
+    }
+
+    /// Set the current topic
+    pub fn set_topic(&mut self, topic: &str) {
+        self.conversation_mut().topic = Some(topic.to_string());
+        self.emit(ContextEvent::TopicSet(topic.to_string()));
+    }
+
+    /// Active domain
+    pub fn domain(&self) -> Option<&str> {
+        self.conversation.domain.as_deref()
+    }
+
+    /// Set the active domain
+    pub fn set_domain(&mut self, domain: &str) {
+        self.conversation_mut().domain = Some(domain.to_string());
+        self.emit(ContextEvent::DomainSet(domain.to_string()));
+    }
+
+    /// Named entities mentioned so far, most recent last
+    pub fn entities(&self) -> &[Entity] {
+        &self.conversation.entities
+    }
+
+    /// Remember an entity; a repeated name moves to the end
+    pub fn add_entity(&mut self, entity: Entity) {
+        let text = entity.text.clone();
+        let entities = &mut self.conversation_mut().entities;
+        entities.retain(|e| !e.text.eq_ignore_ascii_case(&entity.text));
+        entities.push(entity);
+        trim_front(entities, MAX_MENTIONS);
+        self.emit(ContextEvent::EntityAdded(text));
+    }
+
+    /// Noun phrases of recent turns, most recent last
+    pub fn mentions(&self) -> &[Mention] {
+        &self.conversation.mentions
+    }
+
+    /// Most recent mention with a head noun ("server")
+    pub fn mention(&self, head: &str) -> Option<&Mention> {
+        self.conversation.mentions.iter().rev().find(|m| m.head == head)
+    }
+
+    /// Sentences of recent turns, oldest first
+    pub fn recent_sentences(&self) -> impl Iterator<Item = &str> {
+        self.conversation.recent.iter().map(|s| s.as_str())
+    }
+
+    /// Last command
+    pub fn last_command(&self) -> Option<&ParsedCommand> {
+        self.conversation.history.back()
+    }
+
+    /// Commands so far, oldest first
+    pub fn history(&self) -> impl Iterator<Item = &ParsedCommand> {
+        self.conversation.history.iter()
+    }
+
+    /// References of the last turn without an antecedent
+    pub fn unresolved(&self) -> &[Reference] {
+        &self.conversation.unresolved
+    }
+
+    /// Record a command
+    pub fn record_command(&mut self, command: ParsedCommand) {
+        let action = command.action.clone();
+        let history = &mut self.conversation_mut().history;
+        history.push_back(command);
+        if history.len() > MAX_HISTORY {
+            history.pop_front();
+        }
+        self.emit(ContextEvent::CommandRecorded(action));
+    }
+
+    /// Record the sentences of a turn with their mentions and unresolved references
+    pub fn record_turn(&mut self, sentences: &[&str], mentions: Vec<Mention>, unresolved: Vec<Reference>) {
+        let conversation = self.conversation_mut();
+        for sentence in sentences {
+            conversation.recent.push_back(sentence.to_string());
+            if conversation.recent.len() > MAX_RECENT_SENTENCES {
+                conversation.recent.pop_front();
+            }
+        }
+        conversation.mentions.extend(mentions);
+        trim_front(&mut conversation.mentions, MAX_MENTIONS);
+        conversation.unresolved = unresolved;
+        self.emit(ContextEvent::TurnRecorded { sentences: sentences.len() });
+    }
+
+    /// Forget the conversation, keeping variables and metadata
+    pub fn reset_conversation(&mut self) {
+        self.conversation = Arc::default();
+        self.emit(ContextEvent::ConversationReset);
+    }
+
+    fn conversation_mut(&mut self) -> &mut Conversation {
+        Arc::make_mut(&mut self.conversation)
+    }
+}
+
+/// Drop the oldest items beyond `max`
+fn trim_front<T>(items: &mut Vec<T>, max: usize) {
+    if items.len() > max {
+        items.drain(..items.len() - max);
+    }
 }
 
 #[cfg(test)]
@@ -69,4 +305,43 @@ mod tests {
         ctx.set_string("theme", "technology");
         assert_eq!(ctx.get_string("theme"), Some("technology"));
     }
+
+    #[test]
+    fn test_conversation_state() {
+        use crate::{CommandParser, DomainPlugin, DomainRegistry, SemanticDisambiguator, ValidatedQuantity};
+
+        struct Servers;
+        impl DomainPlugin for Servers {
+            fn name(&self) -> &str { "servers" }
+            fn get_special_units(&self) -> Vec<&str> { vec![] }
+            fn get_context_keywords(&self) -> Vec<&str> { vec!["server", "database"] }
+            fn sanitize_quantity(&self, _: f64, _: &str) -> Option<ValidatedQuantity> { None }
+        }
+        let mut domains = DomainRegistry::new();
+        domains.register(Servers);
+
+        let dis = SemanticDisambiguator::new();
+        let mut parser = CommandParser::new();
+        let mut ctx = SharedContext::new();
+        ctx.set_string("user", "ops");
+
+        let turn = "Stop the database server for Maria Garcia.";
+        dis.update_context(turn, &domains, &mut ctx);
+        parser.parse_turn(turn, &mut ctx);
+        assert_eq!(ctx.domain(), Some("servers"));
+        assert_eq!(ctx.topic(), Some("servers"));
+        assert_eq!(ctx.entities()[0].text, "Maria Garcia");
+        assert_eq!(ctx.last_command().unwrap().action, "stop");
+
+        // A turn about nothing in particular keeps the topic
+        dis.update_context("ok", &domains, &mut ctx);
+        assert_eq!(ctx.topic(), Some("servers"));
+
+        ctx.reset_conversation();
+        assert!(ctx.last_command().is_none() && ctx.topic().is_none());
+        dis.update_context("Dr. Smith called. Mr. Jones left.", &domains, &mut ctx);
+        let names: Vec<&str> = ctx.entities().iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(names, vec!["Dr Smith", "Mr Jones"]);
+        assert_eq!(ctx.get_string("user"), Some("ops"));
+    }
 }
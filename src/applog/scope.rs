@@ -0,0 +1,367 @@
+//! Variable scopes, change events and snapshots
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
+use std::sync::Arc;
+
+use super::{Conversation, ContextValue, SharedContext};
+
+/// Events kept until drained
+const MAX_EVENTS: usize = 256;
+
+/// Nesting level of a variable scope
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Scope {
+    /// Outermost scope, open for the lifetime of the context
+    Session,
+    /// A paragraph or turn
+    Paragraph,
+    /// A single sentence
+    Sentence,
+}
+
+/// A change to the context
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContextEvent {
+    /// A variable was set in a scope, replacing `old` there
+    Set { key: String, scope: Scope, old: Option<ContextValue>, value: ContextValue },
+    /// A variable was removed from a scope
+    Removed { key: String, scope: Scope, value: ContextValue },
+    /// A scope was entered
+    Entered(Scope),
+    /// A scope was left, dropping its variables
+    Exited(Scope),
+    /// The context was rolled back to a snapshot
+    Restored,
+    /// Metadata was set
+    MetadataSet { key: String, value: String },
+    /// The conversation topic was set
+    TopicSet(String),
+    /// The active domain was set
+    DomainSet(String),
+    /// A named entity was remembered
+    EntityAdded(String),
+    /// A command was added to the history (its action)
+    CommandRecorded(String),
+    /// The sentences of a turn were recorded
+    TurnRecorded { sentences: usize },
+    /// The conversation state was cleared
+    ConversationReset,
+}
+
+/// Scope errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContextError {
+    /// Scopes nest sentence inside paragraph inside session
+    InvalidNesting { outer: Scope, inner: Scope },
+    /// No scope of this level is open
+    NotOpen(Scope),
+    /// The session scope cannot be left
+    SessionScope,
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContextError::InvalidNesting { outer, inner } => write!(f, "{:?} scope cannot be entered inside {:?}", inner, outer),
+            ContextError::NotOpen(scope) => write!(f, "no {:?} scope is open", scope),
+            ContextError::SessionScope => write!(f, "the session scope cannot be left"),
+        }
+    }
+}
+
+impl std::error::Error for ContextError {}
+
+/// Saved state of a context; taking one copies a few pointers
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    frames: Vec<Arc<Frame>>,
+    metadata: Arc<HashMap<String, String>>,
+    conversation: Arc<Conversation>,
+}
+
+/// Variables of one open scope
+#[derive(Debug, Clone)]
+pub(super) struct Frame {
+    scope: Scope,
+    variables: HashMap<String, ContextValue>,
+}
+
+impl Frame {
+    pub(super) fn new(scope: Scope) -> Self {
+        Self { scope, variables: HashMap::new() }
+    }
+}
+
+impl SharedContext {
+    /// Set a value in the innermost scope
+    pub fn set(&mut self, key: &str, value: impl Into<ContextValue>) {
+        let index = self.frames.len() - 1;
+        self.set_at(index, key, value.into());
+    }
+
+    /// Set a value in the innermost open scope of a level
+    pub fn set_in(&mut self, scope: Scope, key: &str, value: impl Into<ContextValue>) -> Result<(), ContextError> {
+        let index = self.frames.iter().rposition(|f| f.scope == scope).ok_or(ContextError::NotOpen(scope))?;
+        self.set_at(index, key, value.into());
+        Ok(())
+    }
+
+    /// Set a string value
+    pub fn set_string(&mut self, key: &str, value: &str) {
+        self.set(key, value);
+    }
+
+    /// Set a number value
+    pub fn set_number(&mut self, key: &str, value: f64) {
+        self.set(key, value);
+    }
+
+    /// Set a boolean value
+    pub fn set_bool(&mut self, key: &str, value: bool) {
+        self.set(key, value);
+    }
+
+    /// Set a list value
+    pub fn set_list(&mut self, key: &str, values: Vec<ContextValue>) {
+        self.set(key, ContextValue::List(values));
+    }
+
+    /// Get a value, looking from the innermost scope outwards
+    pub fn get(&self, key: &str) -> Option<&ContextValue> {
+        self.frames.iter().rev().find_map(|f| f.variables.get(key))
+    }
+
+    /// Get as string
+    pub fn get_string(&self, key: &str) -> Option<&str> {
+        self.get(key)?.as_str()
+    }
+
+    /// Get as number
+    pub fn get_number(&self, key: &str) -> Option<f64> {
+        self.get(key)?.as_number()
+    }
+
+    /// Get as boolean
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key)?.as_bool()
+    }
+
+    /// Get as list
+    pub fn get_list(&self, key: &str) -> Option<&[ContextValue]> {
+        self.get(key)?.as_list()
+    }
+
+    /// Whether a value is visible
+    pub fn contains(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Scope of the visible value
+    pub fn scope_of(&self, key: &str) -> Option<Scope> {
+        self.frames.iter().rev().find(|f| f.variables.contains_key(key)).map(|f| f.scope)
+    }
+
+    /// Remove the innermost value, uncovering any it shadowed
+    pub fn remove(&mut self, key: &str) -> Option<ContextValue> {
+        let index = self.frames.iter().rposition(|f| f.variables.contains_key(key))?;
+        let frame = Arc::make_mut(&mut self.frames[index]);
+        let value = frame.variables.remove(key)?;
+        let scope = frame.scope;
+        self.emit(ContextEvent::Removed { key: key.to_string(), scope, value: value.clone() });
+        Some(value)
+    }
+
+    /// Visible values by key, shadowed ones left out
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ContextValue)> {
+        let mut visible = BTreeMap::new();
+        for frame in &self.frames {
+            visible.extend(frame.variables.iter().map(|(k, v)| (k.as_str(), v)));
+        }
+        visible.into_iter()
+    }
+
+    /// Innermost open scope
+    pub fn scope(&self) -> Scope {
+        self.frames.last().map_or(Scope::Session, |f| f.scope)
+    }
+
+    /// Enter a scope nested inside the current one
+    pub fn enter(&mut self, scope: Scope) -> Result<(), ContextError> {
+        let outer = self.scope();
+        if scope <= outer {
+            return Err(ContextError::InvalidNesting { outer, inner: scope });
+        }
+        self.frames.push(Arc::new(Frame::new(scope)));
+        self.emit(ContextEvent::Entered(scope));
+        Ok(())
+    }
+
+    /// Leave the innermost scope, dropping its values
+    pub fn exit(&mut self) -> Result<Scope, ContextError> {
+        if self.frames.len() == 1 {
+            return Err(ContextError::SessionScope);
+        }
+        let scope = self.frames.pop().map_or(Scope::Session, |f| f.scope);
+        self.emit(ContextEvent::Exited(scope));
+        Ok(scope)
+    }
+
+    /// Changes not yet drained, oldest first
+    pub fn events(&self) -> &VecDeque<ContextEvent> {
+        &self.events
+    }
+
+    /// Take the changes made since the last drain
+    pub fn drain_events(&mut self) -> Vec<ContextEvent> {
+        std::mem::take(&mut self.events).into()
+    }
+
+    /// Save the variables, metadata and conversation state
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            frames: self.frames.clone(),
+            metadata: Arc::clone(&self.metadata),
+            conversation: Arc::clone(&self.conversation),
+        }
+    }
+
+    /// Roll back to a snapshot
+    pub fn restore(&mut self, snapshot: Snapshot) {
+        self.rollback(snapshot);
+        self.emit(ContextEvent::Restored);
+    }
+
+    /// Run `f`, rolling back everything it changed when it returns `None`
+    ///
+    /// Events of a discarded run are dropped as well.
+    pub fn speculate<T>(&mut self, f: impl FnOnce(&mut Self) -> Option<T>) -> Option<T> {
+        let snapshot = self.snapshot();
+        let earlier = std::mem::take(&mut self.events);
+        let result = f(self);
+        let events = std::mem::replace(&mut self.events, earlier);
+        match result {
+            Some(result) => {
+                events.into_iter().for_each(|e| self.emit(e));
+                Some(result)
+            }
+            None => {
+                self.rollback(snapshot);
+                None
+            }
+        }
+    }
+
+    fn rollback(&mut self, snapshot: Snapshot) {
+        self.frames = snapshot.frames;
+        self.metadata = snapshot.metadata;
+        self.conversation = snapshot.conversation;
+    }
+
+    fn set_at(&mut self, index: usize, key: &str, value: ContextValue) {
+        let frame = Arc::make_mut(&mut self.frames[index]);
+        let old = frame.variables.insert(key.to_string(), value.clone());
+        let scope = frame.scope;
+        self.emit(ContextEvent::Set { key: key.to_string(), scope, old, value });
+    }
+
+    pub(super) fn emit(&mut self, event: ContextEvent) {
+        self.events.push_back(event);
+        if self.events.len() > MAX_EVENTS {
+            self.events.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SharedContext;
+    use super::*;
+
+    #[test]
+    fn test_scopes_and_events() {
+        let mut ctx = SharedContext::new();
+        ctx.set("unit", "metric");
+        ctx.set_list("tags", vec!["urgent".into(), ContextValue::Number(2.0)]);
+
+        ctx.enter(Scope::Paragraph).unwrap();
+        ctx.enter(Scope::Sentence).unwrap();
+        assert_eq!(ctx.enter(Scope::Paragraph), Err(ContextError::InvalidNesting { outer: Scope::Sentence, inner: Scope::Paragraph }));
+        ctx.set("unit", "imperial");
+        ctx.set_in(Scope::Paragraph, "negated", true).unwrap();
+        assert_eq!(ctx.get_string("unit"), Some("imperial"));
+        assert_eq!(ctx.scope_of("negated"), Some(Scope::Paragraph));
+        assert_eq!(ctx.get_number("tags"), None);
+        assert_eq!(ctx.get_list("tags").unwrap()[1].as_number(), Some(2.0));
+        let keys: Vec<&str> = ctx.iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, vec!["negated", "tags", "unit"]);
+
+        // Removing uncovers the shadowed value, leaving drops the scope
+        assert_eq!(ctx.remove("unit"), Some("imperial".into()));
+        assert_eq!(ctx.get_string("unit"), Some("metric"));
+        assert_eq!(ctx.exit(), Ok(Scope::Sentence));
+        assert_eq!(ctx.exit(), Ok(Scope::Paragraph));
+        assert_eq!(ctx.exit(), Err(ContextError::SessionScope));
+        assert!(!ctx.contains("negated"));
+
+        let events = ctx.drain_events();
+        assert_eq!(events.len(), 9);
+        assert_eq!(events[4], ContextEvent::Set {
+            key: "unit".to_string(),
+            scope: Scope::Sentence,
+            old: None,
+            value: "imperial".into(),
+        });
+        assert!(ctx.events().is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_rollback() {
+        use crate::CommandParser;
+
+        let mut parser = CommandParser::new();
+        let mut ctx = SharedContext::new();
+        ctx.set_number("count", 1.0);
+        parser.parse_turn("Open the report", &mut ctx);
+        let snapshot = ctx.snapshot();
+
+        ctx.set_number("count", 2.0);
+        ctx.enter(Scope::Paragraph).unwrap();
+        parser.parse_turn("Close the window", &mut ctx);
+        ctx.restore(snapshot);
+        assert_eq!(ctx.get_number("count"), Some(1.0));
+        assert_eq!(ctx.scope(), Scope::Session);
+        assert_eq!(ctx.last_command().unwrap().action, "open");
+        assert_eq!(ctx.events().back(), Some(&ContextEvent::Restored));
+
+        // A speculative parse that is kept, and one that is discarded
+        ctx.drain_events();
+        let kept = ctx.speculate(|ctx| {
+            ctx.set_bool("confirmed", true);
+            parser.parse_turn("Print it", ctx).pop()
+        });
+        assert_eq!(kept.unwrap().object.as_deref(), Some("the report"));
+        let discarded = ctx.speculate(|ctx| {
+            ctx.remove("confirmed");
+            parser.parse_turn("Delete it", ctx);
+            None::<()>
+        });
+        assert!(discarded.is_none());
+        assert_eq!(ctx.get_bool("confirmed"), Some(true));
+        assert_eq!(ctx.last_command().unwrap().action, "print");
+        let kept: Vec<&ContextEvent> = ctx.events().iter().collect();
+        assert!(matches!(kept[0], ContextEvent::Set { key, .. } if key == "confirmed"));
+        assert_eq!(kept[1..], [&ContextEvent::TurnRecorded { sentences: 1 }, &ContextEvent::CommandRecorded("print".to_string())]);
+
+        // Conversation and metadata changes are logged as well
+        ctx.drain_events();
+        ctx.set_topic("billing");
+        ctx.set_metadata("user", "ana");
+        ctx.reset_conversation();
+        assert_eq!(ctx.drain_events(), vec![
+            ContextEvent::TopicSet("billing".to_string()),
+            ContextEvent::MetadataSet { key: "user".to_string(), value: "ana".to_string() },
+            ContextEvent::ConversationReset,
+        ]);
+    }
+}
@@ -0,0 +1,212 @@
+//! Commands in a conversation
+//!
+//! `parse_turn` parses a turn against a `SharedContext`: pronouns may refer
+//! to earlier turns, and follow-ups reuse the last command:
+//!
+//! - "do it again" repeats it
+//! - "the same for the other server" repeats it on another object
+//! - "undo that" runs its inverse ("open" → "close")
+//!
+//! The parser records commands, mentions, named entities and unresolved
+//! references. Topic and domain need the domain registry, so a caller that
+//! tracks them also passes each turn to `SemanticDisambiguator::update_context`.
+
+use crate::applog::SharedContext;
+use crate::coreference::{Reference, ReferenceKind};
+use crate::grammar::EnglishGrammar;
+use super::{CommandParser, ParsedCommand};
+
+/// Follow-ups repeating the last command
+const REPEAT: &[&str] = &[
+    "again", "do it again", "do that again", "do this again", "repeat", "repeat it",
+    "repeat that", "once more", "one more time", "same again", "do it once more",
+];
+
+/// Follow-ups reversing the last command
+const UNDO: &[&str] = &[
+    "undo", "undo it", "undo that", "undo this", "revert", "revert it", "revert that",
+    "reverse that", "cancel that", "take that back", "take it back",
+];
+
+/// Openers of a follow-up on another object ("the same for the other server")
+const SAME_FOR: &[&[&str]] = &[
+    &["do", "the", "same", "for"], &["do", "the", "same", "with"], &["do", "the", "same", "to"],
+    &["the", "same", "for"], &["the", "same", "with"], &["same", "for"], &["same", "with"],
+];
+
+/// Actions that undo each other, either way round
+///
+/// Only true reversals: "create" and "delete" are opposites, but creating a
+/// file again does not undo its deletion.
+const INVERSES: &[(&str, &str)] = &[
+    ("open", "close"), ("start", "stop"), ("add", "remove"), ("enable", "disable"),
+    ("lock", "unlock"), ("connect", "disconnect"), ("attach", "detach"), ("pause", "resume"),
+    ("raise", "lower"), ("turn on", "turn off"), ("switch on", "switch off"),
+    ("install", "uninstall"), ("mount", "unmount"), ("load", "unload"),
+    ("subscribe", "unsubscribe"), ("block", "unblock"), ("mute", "unmute"),
+];
+
+impl CommandParser {
+    /// Parse a conversation turn, reading and updating the conversation state
+    ///
+    /// Returns no commands when a follow-up has nothing to refer to or cannot
+    /// be undone; the context then lists it among the unresolved references.
+    /// Topic and domain are left to `SemanticDisambiguator::update_context`.
+    pub fn parse_turn(&mut self, input: &str, context: &mut SharedContext) -> Vec<ParsedCommand> {
+        let tokens = self.lexicon.grammar().tokenize(input);
+        let words: Vec<&str> = tokens.iter().map(|t| t.as_str()).filter(|w| *w != "please").collect();
+        let phrase = words.join(" ");
+
+        let follow_up = if REPEAT.contains(&phrase.as_str()) {
+            Some(context.last_command().cloned())
+        } else if UNDO.contains(&phrase.as_str()) {
+            Some(self.undo(context))
+        } else {
+            SAME_FOR.iter()
+                .find(|opener| words.len() > opener.len() && words.starts_with(opener))
+                .map(|opener| self.same_for(&words[opener.len()..], context))
+        };
+        if let Some(command) = follow_up {
+            return match command {
+                Some(mut command) => {
+                    command.original = input.to_string();
+                    context.record_turn(&[input], Vec::new(), Vec::new());
+                    context.record_command(command.clone());
+                    vec![command]
+                }
+                None => {
+                    let sentence = context.recent_sentences().count();
+                    context.record_turn(&[input], Vec::new(), vec![unresolved(&words, sentence)]);
+                    Vec::new()
+                }
+            };
+        }
+
+        // Resolve pronouns against the last few sentences as well
+        let sentences = split_sentences(self.lexicon.grammar(), input);
+        let recent: Vec<String> = context.recent_sentences().map(|s| s.to_string()).collect();
+        let all: Vec<&str> = recent.iter().map(|s| s.as_str()).chain(sentences.iter().copied()).collect();
+        let discourse = self.lexicon.resolve_references(&all);
+        let offset = recent.len();
+        let commands: Vec<ParsedCommand> = sentences.iter().enumerate()
+            .filter_map(|(n, s)| self.parse_in(s, &discourse, offset + n))
+            .collect();
+
+        let mentions = discourse.mentions.iter().filter(|m| m.sentence >= offset).cloned().collect();
+        let unresolved = discourse.unresolved().filter(|r| r.sentence >= offset).cloned().collect();
+        context.record_turn(&sentences, mentions, unresolved);
+        for sentence in &sentences {
+            for entity in self.lexicon.recognize_entities(sentence) {
+                context.add_entity(entity);
+            }
+        }
+        for command in &commands {
+            context.record_command(command.clone());
+        }
+        commands
+    }
+
+    /// Inverse of the last command ("close the file" after "open the file"),
+    /// `None` when no action reverses it
+    fn undo(&mut self, context: &SharedContext) -> Option<ParsedCommand> {
+        let last = context.last_command()?;
+        let inverse = INVERSES.iter()
+            .find_map(|(a, b)| if *a == last.action { Some(*b) } else if *b == last.action { Some(*a) } else { None })?;
+        let text = format!("{} {}", inverse, last.object.as_deref().unwrap_or_default());
+        self.parse(text.trim())
+    }
+
+    /// The last command on another object; "the other server" is the most
+    /// recent server other than the last command's
+    fn same_for(&mut self, target: &[&str], context: &SharedContext) -> Option<ParsedCommand> {
+        let last = context.last_command()?;
+        let mut object = target.join(" ");
+        if target.contains(&"other") {
+            let head = self.lexicon.grammar().noun_lemma(target.last()?);
+            let other = context.mentions().iter().rev()
+                .find(|m| m.head == head && Some(m.text.as_str()) != last.object.as_deref());
+            if let Some(mention) = other {
+                object = mention.text.clone();
+            }
+        }
+        let text = format!("{} {}", last.action, object);
+        self.parse(&text)
+    }
+}
+
+/// Sentences of a text, with semicolons separating commands too
+pub(super) fn split_sentences<'a>(grammar: &EnglishGrammar, text: &'a str) -> Vec<&'a str> {
+    grammar.split_sentences(text).into_iter()
+        .flat_map(|s| s.split(';'))
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// A follow-up with nothing to refer to, as a reference to its pronoun in
+/// sentence `sentence`
+fn unresolved(words: &[&str], sentence: usize) -> Reference {
+    let start = words.iter().position(|w| matches!(*w, "it" | "that" | "this")).unwrap_or(0);
+    let end = if words.is_empty() { 0 } else { start + 1 };
+    Reference {
+        text: words.get(start).map_or(String::new(), |w| w.to_string()),
+        sentence,
+        start,
+        end,
+        kind: ReferenceKind::Pronoun,
+        possessive: false,
+        antecedent: None,
+        score: 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CommandParser, SharedContext};
+
+    #[test]
+    fn test_follow_ups() {
+        let mut parser = CommandParser::new();
+        let mut context = SharedContext::new();
+
+        assert!(parser.parse_turn("undo that", &mut context).is_empty());
+        assert_eq!(context.unresolved()[0].text, "that");
+
+        parser.parse_turn("Stop the web server.", &mut context);
+        parser.parse_turn("Stop the database server.", &mut context);
+        let again = parser.parse_turn("Do it again", &mut context);
+        assert_eq!(again[0].action, "stop");
+        assert_eq!(again[0].object.as_deref(), Some("the database server"));
+        assert_eq!(again[0].original, "Do it again");
+
+        let other = parser.parse_turn("The same for the other server", &mut context);
+        assert_eq!(other[0].object.as_deref(), Some("the web server"));
+
+        let undo = parser.parse_turn("undo that", &mut context);
+        assert_eq!(undo[0].action, "start");
+        assert_eq!(undo[0].object.as_deref(), Some("the web server"));
+        assert_eq!(context.last_command().unwrap().action, "start");
+        assert!(context.unresolved().is_empty());
+
+        // Creating it again would not bring a deleted file back
+        parser.parse_turn("Delete the file", &mut context);
+        assert!(parser.parse_turn("undo that", &mut context).is_empty());
+        assert_eq!(context.last_command().unwrap().action, "delete");
+        // Numbered like the sentences of a turn: after the three recent ones
+        assert_eq!(context.unresolved()[0].sentence, 3);
+    }
+
+    #[test]
+    fn test_pronouns_across_turns() {
+        let mut parser = CommandParser::new();
+        let mut context = SharedContext::new();
+        parser.parse_turn("Open the report", &mut context);
+        let commands = parser.parse_turn("Now print it", &mut context);
+        assert_eq!(commands[0].object.as_deref(), Some("the report"));
+        assert_eq!(context.mention("report").unwrap().text, "the report");
+        assert_eq!(context.history().count(), 2);
+
+        parser.parse_turn("Send the report to Maria Garcia", &mut context);
+        assert_eq!(context.entities()[0].text, "Maria Garcia");
+    }
+}
@@ -2,8 +2,15 @@
 //!
 //! Parse natural language into structured commands.
 
-use crate::verbs::{VerbDatabase, FunctionalCategory, VerbGroup};
-use crate::grammar::EnglishGrammar;
+mod conversation;
+
+use std::sync::{Arc, RwLockReadGuard};
+
+use crate::verbs::{VerbDatabase, FunctionalCategory, VerbGroup, Frame, ThematicRole, CategoryKey};
+use crate::verbs::frames::{self, Argument};
+use crate::lexicon::Lexicon;
+use crate::coreference::{Discourse, Mention, Reference};
+use crate::temporal::{TemporalTagger, TemporalExpression, DateTime};
 
 /// Parsed command structure
 #[derive(Debug, Clone)]
@@ -18,14 +25,31 @@ pub struct ParsedCommand {
     pub subject: Option<String>,
     /// Object (who/what receives)
     pub object: Option<String>,
-    /// Modifiers
+    /// Modifiers (trailing phrases not covered by the frame)
     pub modifiers: Vec<String>,
+    /// Subcategorisation frame that best fits the input
+    pub frame: Option<Frame>,
+    /// Arguments with thematic roles, filled from the frame
+    pub arguments: Vec<Argument>,
+    /// Times, durations and schedules ("every friday"), kept out of the object;
+    /// empty unless the parser has a reference date (`set_reference`)
+    pub temporal: Vec<TemporalExpression>,
+    /// Pronouns and definite phrases with their antecedents, already substituted
+    /// into subject, object and arguments ("delete it" after "open the file")
+    pub references: Vec<(Reference, Option<Mention>)>,
     /// Confidence score
     pub confidence: f64,
     /// Original input
     pub original: String,
 }
 
+impl ParsedCommand {
+    /// Get the argument filling a thematic role
+    pub fn argument(&self, role: ThematicRole) -> Option<&str> {
+        self.arguments.iter().find(|a| a.role == role).map(|a| a.text.as_str())
+    }
+}
+
 /// Parser statistics
 #[derive(Debug, Clone, Default)]
 pub struct ParserStats {
@@ -36,8 +60,8 @@ pub struct ParserStats {
 
 /// Natural language command parser
 pub struct CommandParser {
-    verbs: VerbDatabase,
-    grammar: EnglishGrammar,
+    lexicon: Arc<Lexicon>,
+    reference: Option<DateTime>,
     stats: ParserStats,
 }
 
@@ -49,80 +73,180 @@ impl Default for CommandParser {
 
 impl CommandParser {
     pub fn new() -> Self {
+        Self::with_lexicon(Arc::new(Lexicon::new()))
+    }
+
+    /// Create a parser on a shared lexicon
+    pub fn with_lexicon(lexicon: Arc<Lexicon>) -> Self {
         Self {
-            verbs: VerbDatabase::with_builtin(),
-            grammar: EnglishGrammar::new(),
+            lexicon,
+            reference: None,
             stats: ParserStats::default(),
         }
     }
 
     /// Parse a command from natural language
     pub fn parse(&mut self, input: &str) -> Option<ParsedCommand> {
-        let tokens = self.grammar.tokenize(input);
+        let discourse = self.lexicon.resolve_references(&[input]);
+        self.parse_in(input, &discourse, 0)
+    }
+
+    /// Parse sentence `n` of a discourse, with its pronouns resolved
+    fn parse_in(&mut self, input: &str, discourse: &Discourse, n: usize) -> Option<ParsedCommand> {
+        let grammar = self.lexicon.grammar();
+        let (tokens, substituted) = discourse.substitute_spans(n, &grammar.tokenize(input));
         if tokens.is_empty() { return None; }
+        // Antecedents put in for pronouns are noun phrases ("archive the report")
+        let is_substituted = |i: usize| substituted.iter().any(|span| span.contains(&i));
 
-        // Find the main verb
+        // Temporal expressions are not verbs or arguments ("on March 5")
+        let temporal = self.reference
+            .map(|reference| TemporalTagger::new(reference).tag_tokens(&tokens))
+            .unwrap_or_default();
+        let is_temporal = |i: usize| temporal.iter().any(|e| e.covers(i));
+        let verbs = self.lexicon.verbs();
+
+        // Find the main verb (phrasal verbs take precedence over their head)
         let mut action_idx = None;
-        let mut action_entry = None;
+        let mut action = None;
+        let mut particles: Vec<usize> = Vec::new();
 
         for (i, token) in tokens.iter().enumerate() {
-            if let Some(entry) = self.verbs.lookup(token) {
+            if is_temporal(i) || is_substituted(i) {
+                continue;
+            }
+            if let Some(m) = verbs.match_phrasal(&tokens, i) {
+                action_idx = Some(i);
+                action = Some((m.entry.base(), m.entry.category, m.entry.group, m.entry.frames.clone()));
+                particles = m.particle_positions;
+                break;
+            }
+            if let Some(entry) = verbs.lookup(token) {
                 action_idx = Some(i);
-                action_entry = Some(entry.clone());
+                action = Some((entry.base.clone(), entry.category, entry.group, entry.frames.clone()));
                 break;
             }
         }
 
-        let entry = action_entry?;
+        let (base, category, group, verb_frames) = action?;
         let idx = action_idx?;
 
+        // A conjunction before another verb ends the clause ("open the file and delete it")
+        let clause_end = (idx + 1..tokens.len())
+            .find(|&i| grammar.is_conjunction(&tokens[i])
+                && tokens.get(i + 1).is_some_and(|next| verbs.lookup(next).is_some() && !is_substituted(i + 1)))
+            .unwrap_or(tokens.len());
+        drop(verbs);
+
         // Extract subject (before verb)
-        let subject = if idx > 0 {
-            Some(tokens[..idx].join(" "))
-        } else {
+        let before: Vec<&str> = tokens[..idx].iter().enumerate()
+            .filter(|(i, _)| !is_temporal(*i))
+            .map(|(_, t)| t.as_str())
+            .collect();
+        let subject = if before.is_empty() {
             None
+        } else {
+            Some(before.join(" "))
         };
 
-        // Extract object (after verb)
-        let object = if idx + 1 < tokens.len() {
-            Some(tokens[idx + 1..].join(" "))
-        } else {
+        // Extract object (after verb, without particles or times)
+        let rest: Vec<&str> = tokens[..clause_end].iter().enumerate()
+            .skip(idx + 1)
+            .filter(|(i, _)| !particles.contains(i) && !is_temporal(*i))
+            .map(|(_, t)| t.as_str())
+            .collect();
+        let object = if rest.is_empty() {
             None
+        } else {
+            Some(rest.join(" "))
         };
 
+        // Fill thematic roles from the best-fitting frame
+        let rest: Vec<String> = rest.iter().map(|t| t.to_string()).collect();
+        let fill = frames::best_fill(&verb_frames, &rest, grammar);
+        let mut arguments = Vec::new();
+        let mut modifiers = Vec::new();
+        if let Some(fill) = &fill {
+            if let Some(subj) = &subject {
+                arguments.push(Argument { role: fill.frame.subject, text: subj.clone() });
+            }
+            arguments.extend(fill.arguments.iter().cloned());
+            if !fill.unmatched.is_empty() {
+                modifiers.push(fill.unmatched.join(" "));
+            }
+        }
+
         // Update stats
         self.stats.commands_parsed += 1;
         self.stats.verbs_detected += 1;
 
         Some(ParsedCommand {
-            action: entry.base.clone(),
-            category: entry.category,
-            group: entry.group,
+            action: base,
+            category,
+            group,
             subject,
             object,
-            modifiers: vec![],
+            modifiers,
+            frame: fill.map(|f| f.frame),
+            arguments,
+            temporal,
+            references: discourse.references_in(n)
+                .map(|r| (r.clone(), discourse.antecedent(r).cloned()))
+                .collect(),
             confidence: 0.85,
             original: input.to_string(),
         })
     }
 
     /// Parse multiple commands from text
+    ///
+    /// Pronouns are resolved across commands: in "Open the file. Then
+    /// delete it." the second command deletes "the file".
     pub fn parse_all(&mut self, text: &str) -> Vec<ParsedCommand> {
-        // Split by common sentence delimiters
-        text.split(['.', '!', '?', ';'])
-            .filter(|s| !s.trim().is_empty())
-            .filter_map(|s| self.parse(s.trim()))
+        let sentences = conversation::split_sentences(self.lexicon.grammar(), text);
+        let discourse = self.lexicon.resolve_references(&sentences);
+        sentences.iter().enumerate()
+            .filter_map(|(n, s)| self.parse_in(s, &discourse, n))
             .collect()
     }
 
+    /// Date, or date and time, to resolve times against
+    ///
+    /// Without one, times are not recognised and stay part of the arguments.
+    pub fn set_reference(&mut self, reference: impl Into<DateTime>) {
+        self.reference = Some(reference.into());
+    }
+
+    /// Get the shared lexicon
+    pub fn lexicon(&self) -> &Arc<Lexicon> {
+        &self.lexicon
+    }
+
+    /// Read the verb database
+    pub fn with_verbs<R>(&self, f: impl FnOnce(&VerbDatabase) -> R) -> R {
+        f(&self.verbs())
+    }
+
+    /// Change the verb database (to add custom verbs)
+    ///
+    /// Changes are seen by every engine sharing the lexicon; `f` must not
+    /// call back into them.
+    pub fn with_verbs_mut<R>(&self, f: impl FnOnce(&mut VerbDatabase) -> R) -> R {
+        f(&mut self.lexicon.verbs_mut())
+    }
+
+    fn verbs(&self) -> RwLockReadGuard<'_, VerbDatabase> {
+        self.lexicon.verbs()
+    }
+
     /// Get parser statistics
     pub fn stats(&self) -> &ParserStats {
         &self.stats
     }
 
-    /// Get verbs for a specific category (for autocompletion)
-    pub fn suggest_verbs(&self, category: FunctionalCategory, limit: usize) -> Vec<String> {
-        self.verbs.by_category(category)
+    /// Get verbs for a built-in or custom category (for autocompletion)
+    pub fn suggest_verbs(&self, category: impl Into<CategoryKey>, limit: usize) -> Vec<String> {
+        self.verbs().by_category(category)
             .iter()
             .take(limit)
             .map(|e| e.base.clone())
@@ -131,12 +255,12 @@ impl CommandParser {
 
     /// Check if word is a known verb
     pub fn is_action(&self, word: &str) -> bool {
-        self.verbs.is_verb(word)
+        self.verbs().is_verb(word)
     }
 
     /// Get action category
     pub fn get_action_category(&self, word: &str) -> Option<FunctionalCategory> {
-        self.verbs.get_category(word)
+        self.verbs().get_category(word)
     }
 }
 
@@ -166,4 +290,106 @@ mod tests {
         let movement = parser.suggest_verbs(FunctionalCategory::Movement, 5);
         assert!(!movement.is_empty());
     }
+
+    #[test]
+    fn test_suggest_custom_verbs() {
+        use crate::verbs::VerbEntry;
+
+        let parser = CommandParser::new();
+        parser.with_verbs_mut(|db| {
+            let devops = db.register_category("DevOps", "Deployment and operations").unwrap();
+            for verb in ["deploy", "rollback", "provision"] {
+                db.add(VerbEntry::regular(verb, FunctionalCategory::Control, VerbGroup::Generic, Some(true), 30)
+                    .in_custom_category(devops));
+            }
+        });
+        let key = parser.with_verbs(|db| db.resolve_category("devops")).unwrap();
+        let mut suggested = parser.suggest_verbs(key, 10);
+        suggested.sort();
+        assert_eq!(suggested, vec!["deploy", "provision", "rollback"]);
+    }
+
+    #[test]
+    fn test_parse_phrasal() {
+        let mut parser = CommandParser::new();
+        let cmd = parser.parse("turn the lights off").unwrap();
+        assert_eq!(cmd.action, "turn off");
+        assert_eq!(cmd.object.as_deref(), Some("the lights"));
+
+        let cmd = parser.parse("look after the kids").unwrap();
+        assert_eq!(cmd.action, "look after");
+        assert_eq!(cmd.category, FunctionalCategory::Social);
+        assert_eq!(cmd.object.as_deref(), Some("the kids"));
+    }
+
+    #[test]
+    fn test_frame_arguments() {
+        let mut parser = CommandParser::new();
+        let cmd = parser.parse("give Mary the book").unwrap();
+        assert_eq!(cmd.frame, Some(Frame::DITRANSITIVE));
+        assert_eq!(cmd.argument(ThematicRole::Recipient), Some("mary"));
+        assert_eq!(cmd.argument(ThematicRole::Theme), Some("the book"));
+
+        let cmd = parser.parse("put the cup on the table with care").unwrap();
+        assert_eq!(cmd.argument(ThematicRole::Location), Some("the table"));
+        assert_eq!(cmd.modifiers, vec!["with care".to_string()]);
+
+        let cmd = parser.parse("I want to go home").unwrap();
+        assert_eq!(cmd.argument(ThematicRole::Agent), Some("i"));
+        assert_eq!(cmd.argument(ThematicRole::Theme), Some("go home"));
+    }
+
+    #[test]
+    fn test_temporal_modifiers() {
+        use crate::temporal::{Date, TemporalValue, Weekday};
+
+        // Without a reference date times are not resolved
+        let mut parser = CommandParser::new();
+        assert!(parser.parse("send the report every Friday").unwrap().temporal.is_empty());
+        parser.set_reference(Date::new(2026, 10, 16).unwrap());
+        let cmd = parser.parse("send the report every Friday").unwrap();
+        assert_eq!(cmd.action, "send");
+        assert_eq!(cmd.object.as_deref(), Some("the report"));
+        match &cmd.temporal[0].value {
+            TemporalValue::Recurrence(r) => assert_eq!(r.weekdays, vec![Weekday::Friday]),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let cmd = parser.parse("tomorrow at 3pm remind the dentist").unwrap();
+        assert_eq!(cmd.action, "remind");
+        assert_eq!(cmd.subject, None);
+        assert_eq!(cmd.object.as_deref(), Some("the dentist"));
+        assert_eq!(cmd.temporal[0].value, TemporalValue::Instant(
+            DateTime::new(Date::new(2026, 10, 17).unwrap(), crate::temporal::Time::new(15, 0).unwrap())));
+    }
+
+    #[test]
+    fn test_pronouns_across_commands() {
+        let mut parser = CommandParser::new();
+        let cmds = parser.parse_all("Open the file. Then delete it.");
+        assert_eq!(cmds[1].action, "delete");
+        assert_eq!(cmds[1].object.as_deref(), Some("the file"));
+        let (reference, antecedent) = &cmds[1].references[0];
+        assert_eq!(reference.text, "it");
+        assert_eq!(antecedent.as_ref().unwrap().head, "file");
+
+        let cmd = parser.parse("delete it").unwrap();
+        assert_eq!(cmd.object.as_deref(), Some("it"));
+        assert!(cmd.references[0].1.is_none());
+    }
+
+    #[test]
+    fn test_substituted_and_coordinated() {
+        let mut parser = CommandParser::new();
+        // "report" put in for "it" is not taken for a verb
+        let cmds = parser.parse_all("Find the report. Send it to Mary. Then archive it.");
+        assert_eq!(cmds.len(), 2);
+        assert_eq!(cmds[1].action, "send");
+        assert!(cmds.iter().all(|c| c.action != "report"));
+
+        let cmd = parser.parse("Open the file and delete it").unwrap();
+        assert_eq!(cmd.action, "open");
+        assert_eq!(cmd.object.as_deref(), Some("the file"));
+        assert_eq!(parser.parse("Delete the files and folders").unwrap().object.as_deref(), Some("the files and folders"));
+    }
 }
@@ -0,0 +1,690 @@
+//! # Coreference Resolution
+//!
+//! Links third-person pronouns ("it", "she", "them", a standalone "that")
+//! and definite noun phrases ("the file") to earlier mentions across a
+//! sequence of sentences. Candidates are ranked by:
+//!
+//! - agreement in number and gender: "she" needs a female or unmarked
+//!   person, "it" something that is not a person; given names in the
+//!   gazetteer carry their gender
+//! - recency: nearer mentions first, at most `MAX_DISTANCE` sentences back
+//! - salience: subjects before other mentions
+//! - semantic fit: antecedents meeting the selectional preferences of the
+//!   verb governing the pronoun come first ("drink it" wants a liquid)
+//!
+//! `Discourse::substitute` rewrites a sentence with its references replaced
+//! by their antecedents. `CommandParser::parse_all` and `Transpiler::to_pirs`
+//! use it, so "Open the file. Then delete it." deletes the file.
+//!
+//! ```
+//! use nl_sre_english::Lexicon;
+//!
+//! let lexicon = Lexicon::new();
+//! let discourse = lexicon.resolve_references(&["Open the file", "Then delete it"]);
+//! let it = &discourse.references[0];
+//! assert_eq!(discourse.antecedent(it).unwrap().head, "file");
+//! ```
+
+use std::ops::Range;
+
+use crate::dictionary::EnglishDictionary;
+use crate::grammar::EnglishGrammar;
+use crate::lexicon::Lexicon;
+use crate::ner::{EntityRecognizer, EntityType, Gazetteer};
+use crate::semantic::{SemanticCategory, SemanticDB, SemanticEntry};
+use crate::srl::SemanticRole;
+use crate::verbs::VerbDatabase;
+
+/// Sentences a reference may look back
+const MAX_DISTANCE: usize = 3;
+
+/// Score lost per sentence between a reference and its antecedent
+const DISTANCE_PENALTY: f64 = 0.2;
+
+/// Score lost per closer candidate in between
+const INTERVENING_PENALTY: f64 = 0.05;
+
+/// Score gained by the subject of a sentence
+const SUBJECT_BONUS: f64 = 0.15;
+
+/// Score gained when the antecedent meets the governing verb's preferences
+const PREFERENCE_BONUS: f64 = 0.2;
+
+/// Score lost by loose agreement ("they" for a company or a single person)
+const LOOSE_AGREEMENT: f64 = 0.3;
+
+/// Lowest score of a resolved reference
+const MIN_SCORE: f64 = 0.3;
+
+/// Longest noun phrase in words, determiner included
+const MAX_PHRASE_WORDS: usize = 5;
+
+/// Words that open a noun phrase
+const DETERMINERS: &[&str] = &[
+    "the", "a", "an", "this", "that", "these", "those", "my", "your", "his", "her", "its",
+    "our", "their", "some", "any", "every", "each", "another", "no", "all",
+];
+
+/// Determiners of a definite noun phrase, which may refer back
+const DEFINITE: &[&str] = &["the", "this", "that", "these", "those"];
+
+/// Determiners of a plural noun phrase
+const PLURAL_DETERMINERS: &[&str] = &["these", "those", "all"];
+
+/// Adverbs that end a noun phrase ("delete the file then")
+const ADVERBS: &[&str] = &[
+    "then", "again", "now", "first", "too", "also", "later", "soon", "please", "here",
+    "there", "today", "tomorrow", "yesterday", "immediately", "instead", "already",
+];
+
+/// Titles marking a man or a woman
+const MALE_TITLES: &[&str] = &["mr", "sir", "lord", "fr"];
+const FEMALE_TITLES: &[&str] = &["mrs", "ms", "miss", "dame", "lady"];
+
+/// Words after "it is" that make "it" refer to nothing ("it is raining")
+const PLEONASTIC: &[&str] = &[
+    "raining", "snowing", "likely", "unlikely", "possible", "impossible", "necessary",
+    "important", "time", "clear", "obvious", "true", "seems", "appears", "rains", "snows",
+];
+
+/// Grammatical number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Number {
+    Singular,
+    Plural,
+}
+
+/// Grammatical gender
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+    /// Things, animals and organisations
+    Neuter,
+    /// People of unknown gender; pronouns that do not mark gender ("they")
+    Unknown,
+}
+
+/// How a reference points back
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReferenceKind {
+    /// "it", "she", "them", a standalone "that"
+    Pronoun,
+    /// "the file" after "a file"
+    Definite,
+}
+
+/// A noun phrase or name that later text may refer to
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mention {
+    /// Lowercase tokens of the phrase ("the red file")
+    pub text: String,
+    /// Head noun lemma ("file")
+    pub head: String,
+    /// Sentence index
+    pub sentence: usize,
+    /// First token index
+    pub start: usize,
+    /// One past the last token index
+    pub end: usize,
+    pub number: Number,
+    pub gender: Gender,
+    /// Semantic category of the head noun
+    pub category: Option<SemanticCategory>,
+    /// Entity type of a name
+    pub entity: Option<EntityType>,
+    /// Comes before the sentence's first verb
+    pub subject: bool,
+}
+
+/// A pronoun or definite noun phrase and what it refers to
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+    /// Lowercase text ("it", "the file")
+    pub text: String,
+    /// Sentence index
+    pub sentence: usize,
+    /// First token index
+    pub start: usize,
+    /// One past the last token index
+    pub end: usize,
+    pub kind: ReferenceKind,
+    /// Possessive pronoun ("its", "their"), resolved but never substituted
+    pub possessive: bool,
+    /// Index of the antecedent in `Discourse::mentions`
+    pub antecedent: Option<usize>,
+    /// Score of the chosen antecedent (0.0 when unresolved)
+    pub score: f64,
+}
+
+impl Reference {
+    /// Check if an antecedent was found
+    pub fn is_resolved(&self) -> bool {
+        self.antecedent.is_some()
+    }
+}
+
+/// Mentions and references of a sequence of sentences
+#[derive(Debug, Clone, Default)]
+pub struct Discourse {
+    /// Mentions, in order of appearance
+    pub mentions: Vec<Mention>,
+    /// References, in order of appearance
+    pub references: Vec<Reference>,
+}
+
+impl Discourse {
+    /// Antecedent of a reference
+    pub fn antecedent(&self, reference: &Reference) -> Option<&Mention> {
+        reference.antecedent.map(|i| &self.mentions[i])
+    }
+
+    /// References of one sentence
+    pub fn references_in(&self, sentence: usize) -> impl Iterator<Item = &Reference> {
+        self.references.iter().filter(move |r| r.sentence == sentence)
+    }
+
+    /// References without an antecedent
+    pub fn unresolved(&self) -> impl Iterator<Item = &Reference> {
+        self.references.iter().filter(|r| !r.is_resolved())
+    }
+
+    /// Tokens of a sentence with resolved pronouns replaced by their antecedents
+    ///
+    /// An indefinite antecedent comes back definite: "Find a book. Read it."
+    /// reads "read the book". Possessives and definite noun phrases stay.
+    pub fn substitute(&self, sentence: usize, tokens: &[String]) -> Vec<String> {
+        self.substitute_spans(sentence, tokens).0
+    }
+
+    /// `substitute`, with the token spans of the inserted antecedents
+    ///
+    /// The spans are noun phrases: a parser should not look for verbs there.
+    pub fn substitute_spans(&self, sentence: usize, tokens: &[String]) -> (Vec<String>, Vec<Range<usize>>) {
+        let mut tokens = tokens.to_vec();
+        let mut spans = Vec::new();
+        let mut references: Vec<&Reference> = self.references_in(sentence)
+            .filter(|r| r.kind == ReferenceKind::Pronoun && !r.possessive)
+            .collect();
+        references.sort_by_key(|r| r.start);
+        let mut offset = 0isize;
+        for reference in references {
+            let Some(antecedent) = self.antecedent(reference) else { continue };
+            let start = (reference.start as isize + offset) as usize;
+            let end = (reference.end as isize + offset) as usize;
+            if end > tokens.len() {
+                continue;
+            }
+            let replacement: Vec<String> = antecedent.text.split(' ').enumerate()
+                .map(|(k, w)| if k == 0 && (w == "a" || w == "an") { "the".to_string() } else { w.to_string() })
+                .collect();
+            offset += replacement.len() as isize - (end - start) as isize;
+            spans.push(start..start + replacement.len());
+            tokens.splice(start..end, replacement);
+        }
+        (tokens, spans)
+    }
+}
+
+/// A place where a mention occurs: the mention itself or a reference resolved to it
+#[derive(Debug, Clone, Copy)]
+struct Occurrence {
+    mention: usize,
+    sentence: usize,
+    /// One past its last token
+    end: usize,
+    subject: bool,
+}
+
+/// Agreement features of a third-person pronoun: number, gender, possessive, reflexive
+fn pronoun_features(word: &str) -> Option<(Number, Gender, bool, bool)> {
+    use Gender::*;
+    use Number::*;
+    Some(match word {
+        "he" | "him" => (Singular, Masculine, false, false),
+        "himself" => (Singular, Masculine, false, true),
+        "his" => (Singular, Masculine, true, false),
+        "she" | "her" => (Singular, Feminine, false, false),
+        "herself" => (Singular, Feminine, false, true),
+        "hers" => (Singular, Feminine, true, false),
+        "it" | "this" | "that" => (Singular, Neuter, false, false),
+        "itself" => (Singular, Neuter, false, true),
+        "its" => (Singular, Neuter, true, false),
+        "they" | "them" | "these" | "those" => (Plural, Unknown, false, false),
+        "themselves" => (Plural, Unknown, false, true),
+        "their" | "theirs" => (Plural, Unknown, true, false),
+        _ => return None,
+    })
+}
+
+/// Resolves references with a grammar, verb database, dictionary, semantic lexicon and gazetteer
+pub struct CoreferenceResolver<'a> {
+    grammar: &'a EnglishGrammar,
+    verbs: &'a VerbDatabase,
+    dictionary: &'a EnglishDictionary,
+    semantics: &'a SemanticDB,
+    gazetteer: &'a Gazetteer,
+}
+
+impl<'a> CoreferenceResolver<'a> {
+    pub fn new(
+        grammar: &'a EnglishGrammar,
+        verbs: &'a VerbDatabase,
+        dictionary: &'a EnglishDictionary,
+        semantics: &'a SemanticDB,
+        gazetteer: &'a Gazetteer,
+    ) -> Self {
+        Self { grammar, verbs, dictionary, semantics, gazetteer }
+    }
+
+    /// Mentions and references of consecutive sentences
+    ///
+    /// Token indices follow `EnglishGrammar::tokenize` of each sentence.
+    pub fn resolve(&self, sentences: &[&str]) -> Discourse {
+        let mut discourse = Discourse::default();
+        let mut occurrences: Vec<Occurrence> = Vec::new();
+        for (n, sentence) in sentences.iter().enumerate() {
+            let tokens = self.grammar.tokenize(sentence);
+            let first_mention = discourse.mentions.len();
+            self.find_mentions(sentence, n, &tokens, &mut discourse.mentions);
+            let pronouns = self.find_pronouns(&tokens, &discourse.mentions[first_mention..]);
+
+            // Walk mentions and pronouns left to right so each sees only what precedes it
+            let mut next_mention = first_mention;
+            for (position, number, gender, possessive, reflexive) in pronouns {
+                while next_mention < discourse.mentions.len() && discourse.mentions[next_mention].start < position {
+                    self.add_mention(&mut discourse, &mut occurrences, next_mention);
+                    next_mention += 1;
+                }
+                // "John saw him", "It ate it": an object pronoun does not
+                // refer to what its own subject refers to
+                let barred: Vec<usize> = occurrences.iter()
+                    .filter(|o| !reflexive && o.sentence == n && o.subject && self.same_clause(&tokens, o.end, position))
+                    .map(|o| o.mention)
+                    .collect();
+                let best = occurrences.iter().enumerate().rev()
+                    .filter(|(_, o)| !barred.contains(&o.mention))
+                    .filter_map(|(k, o)| {
+                        let intervening = occurrences.len() - 1 - k;
+                        self.score(&discourse.mentions[o.mention], o, intervening, n, position, &tokens, number, gender, reflexive)
+                            .map(|score| (o.mention, score))
+                    })
+                    .fold(None, |best: Option<(usize, f64)>, (m, score)| match best {
+                        Some((_, s)) if s >= score => best,
+                        _ => Some((m, score)),
+                    })
+                    .filter(|(_, score)| *score >= MIN_SCORE);
+                if let Some((mention, _)) = best {
+                    let subject = self.first_verb(&tokens).is_some_and(|v| position < v);
+                    occurrences.push(Occurrence { mention, sentence: n, end: position + 1, subject });
+                }
+                discourse.references.push(Reference {
+                    text: tokens[position].clone(),
+                    sentence: n,
+                    start: position,
+                    end: position + 1,
+                    kind: ReferenceKind::Pronoun,
+                    possessive,
+                    antecedent: best.map(|(m, _)| m),
+                    score: best.map_or(0.0, |(_, s)| s),
+                });
+            }
+            while next_mention < discourse.mentions.len() {
+                self.add_mention(&mut discourse, &mut occurrences, next_mention);
+                next_mention += 1;
+            }
+        }
+        discourse.references.sort_by_key(|r| (r.sentence, r.start));
+        discourse
+    }
+
+    /// Record a mention, linking it back when it is a definite repeat ("the file")
+    fn add_mention(&self, discourse: &mut Discourse, occurrences: &mut Vec<Occurrence>, index: usize) {
+        let mention = &discourse.mentions[index];
+        let determiner = mention.text.split(' ').next().unwrap_or_default();
+        if mention.entity.is_none() && DEFINITE.contains(&determiner) {
+            let earlier = occurrences.iter().rev()
+                .find(|o| mention.sentence - o.sentence <= MAX_DISTANCE && discourse.mentions[o.mention].head == mention.head);
+            if let Some(o) = earlier {
+                let distance = (mention.sentence - o.sentence) as f64;
+                discourse.references.push(Reference {
+                    text: mention.text.clone(),
+                    sentence: mention.sentence,
+                    start: mention.start,
+                    end: mention.end,
+                    kind: ReferenceKind::Definite,
+                    possessive: false,
+                    antecedent: Some(o.mention),
+                    score: (1.0 - DISTANCE_PENALTY * distance).max(MIN_SCORE),
+                });
+            }
+        }
+        occurrences.push(Occurrence {
+            mention: index,
+            sentence: mention.sentence,
+            end: mention.end,
+            subject: mention.subject,
+        });
+    }
+
+    /// Score of a candidate antecedent, `None` when it cannot be one
+    #[allow(clippy::too_many_arguments)]
+    fn score(
+        &self,
+        mention: &Mention,
+        occurrence: &Occurrence,
+        intervening: usize,
+        sentence: usize,
+        position: usize,
+        tokens: &[String],
+        number: Number,
+        gender: Gender,
+        reflexive: bool,
+    ) -> Option<f64> {
+        let distance = sentence - occurrence.sentence;
+        if distance > MAX_DISTANCE || (reflexive && distance > 0) {
+            return None;
+        }
+        let human = matches!(mention.category, Some(SemanticCategory::Person { .. }))
+            || mention.entity == Some(EntityType::Person);
+        let organization = mention.entity == Some(EntityType::Organization)
+            || self.entry(&mention.head).is_some_and(|e| e.has_tag("organization") || e.has_tag("group"));
+
+        let mut score = 1.0;
+        match gender {
+            Gender::Masculine | Gender::Feminine if !human || (mention.gender != gender && mention.gender != Gender::Unknown) => return None,
+            Gender::Neuter if human => return None,
+            _ => {}
+        }
+        if number != mention.number {
+            // "they" for a company or a single person
+            if number == Number::Plural && (organization || human) {
+                score -= LOOSE_AGREEMENT;
+            } else {
+                return None;
+            }
+        }
+
+        // Selectional preferences of the verb governing the pronoun; a
+        // violation costs as much as a fit gains, since preferences can be
+        // too narrow ("the cat ate a mouse")
+        if let (Some((group_key, group, role)), Some(entry)) = (self.governing_verb(tokens, position), self.entry(&mention.head)) {
+            if let Some(preference) = self.verbs.sense_preference(group_key, group, role) {
+                score += if preference.accepts(entry) { PREFERENCE_BONUS } else { -PREFERENCE_BONUS };
+            }
+        }
+
+        score -= DISTANCE_PENALTY * distance as f64;
+        score -= INTERVENING_PENALTY * intervening.min(4) as f64;
+        if occurrence.subject {
+            score += SUBJECT_BONUS;
+        }
+        Some(score)
+    }
+
+    /// A subject ending at `end` and a pronoun at `position` with one verb between
+    fn same_clause(&self, tokens: &[String], end: usize, position: usize) -> bool {
+        let verbs_between = (end..position).filter(|&i| self.is_verb_at(tokens, i)).count();
+        verbs_between == 1 && self.first_verb(tokens).is_some_and(|v| v < position)
+    }
+
+    /// Third-person pronouns of a sentence with their features
+    fn find_pronouns(&self, tokens: &[String], mentions: &[Mention]) -> Vec<(usize, Number, Gender, bool, bool)> {
+        tokens.iter().enumerate()
+            .filter(|(i, _)| !mentions.iter().any(|m| (m.start..m.end).contains(i)))
+            .filter_map(|(i, word)| {
+                let (number, gender, mut possessive, reflexive) = pronoun_features(word)?;
+                let next = tokens.get(i + 1).map(|s| s.as_str());
+                match word.as_str() {
+                    // Demonstratives refer only when they stand alone ("delete that")
+                    "this" | "that" | "these" | "those" => {
+                        let alone = next.is_none_or(|w| self.grammar.is_preposition(w)
+                            || self.grammar.is_conjunction(w) || ADVERBS.contains(&w));
+                        if !alone || (word == "that" && i > 0 && !self.is_verb_at(tokens, i - 1)) {
+                            return None;
+                        }
+                    }
+                    // "her" before a noun is a possessive
+                    "her" => possessive = next.is_some_and(|w| self.is_nominal(w)),
+                    "it" if next.is_some_and(|w| matches!(w, "is" | "was"))
+                        && tokens.get(i + 2).is_some_and(|w| PLEONASTIC.contains(&w.as_str())) => return None,
+                    "it" if next.is_some_and(|w| PLEONASTIC.contains(&w)) => return None,
+                    _ => {}
+                }
+                Some((i, number, gender, possessive, reflexive))
+            })
+            .collect()
+    }
+
+    /// Names and noun phrases of a sentence
+    fn find_mentions(&self, sentence: &str, n: usize, tokens: &[String], mentions: &mut Vec<Mention>) {
+        let entities = EntityRecognizer::new(self.grammar, self.verbs, self.dictionary, self.semantics, self.gazetteer)
+            .recognize(sentence);
+        let first_verb = self.first_verb(tokens);
+        let mut push = |start: usize, end: usize, gender: Gender, number: Number, entity: Option<EntityType>| {
+            // Names keep their spelling ("james" is not a plural)
+            let head = match entity {
+                Some(_) => tokens[end - 1].clone(),
+                None => self.grammar.noun_lemma(&tokens[end - 1]),
+            };
+            mentions.push(Mention {
+                text: tokens[start..end].join(" "),
+                category: self.entry(&head).map(|e| e.category.clone()),
+                head,
+                sentence: n,
+                start,
+                end,
+                number,
+                gender,
+                entity,
+                subject: first_verb.is_some_and(|v| start < v),
+            });
+        };
+
+        let mut i = 0;
+        while i < tokens.len() {
+            if let Some(entity) = entities.iter().find(|e| e.start == i && e.end <= tokens.len() && e.kind != EntityType::Date) {
+                let gender = match entity.kind {
+                    EntityType::Person if MALE_TITLES.contains(&tokens[i].as_str()) => Gender::Masculine,
+                    EntityType::Person if FEMALE_TITLES.contains(&tokens[i].as_str()) => Gender::Feminine,
+                    EntityType::Person => self.name_gender(&tokens[entity.start..entity.end]),
+                    _ => Gender::Neuter,
+                };
+                push(entity.start, entity.end, gender, Number::Singular, Some(entity.kind));
+                i = entity.end;
+                continue;
+            }
+            let word = tokens[i].as_str();
+            let determiner = DETERMINERS.contains(&word) && tokens.get(i + 1).is_some_and(|w| self.is_nominal(w));
+            let bare = !determiner && self.is_bare_noun(tokens, i);
+            if !(determiner || bare) {
+                i += 1;
+                continue;
+            }
+            let end = self.phrase_end(tokens, if determiner { i + 1 } else { i });
+            let head = &tokens[end - 1];
+            let plural = PLURAL_DETERMINERS.contains(&word) || self.grammar.noun_lemma(head) != *head;
+            let number = if plural { Number::Plural } else { Number::Singular };
+            push(i, end, self.gender(head), number, None);
+            i = end;
+        }
+    }
+
+    /// End of a noun phrase whose words start at `start`: the run of nominal
+    /// words, so the head is its last word even when the lexicon does not
+    /// know it ("the second invoice", "the new config")
+    fn phrase_end(&self, tokens: &[String], start: usize) -> usize {
+        let limit = (start + MAX_PHRASE_WORDS - 1).min(tokens.len());
+        let mut end = start;
+        while end < limit && self.is_nominal(&tokens[end]) {
+            // A verb the lexicon does not know as a noun ends it ("the file delete")
+            if end > start && self.verbs.base_form(&tokens[end]).is_some() && self.entry(&tokens[end]).is_none() {
+                break;
+            }
+            end += 1;
+        }
+        end.max(start + 1)
+    }
+
+    /// A noun without a determiner the lexicon knows ("files", "water" after a verb)
+    fn is_bare_noun(&self, tokens: &[String], i: usize) -> bool {
+        let Some(entry) = self.entry(&tokens[i]) else { return false };
+        if entry.category.detail() == Some("pronoun") || matches!(entry.category, SemanticCategory::Time { .. }) {
+            return false;
+        }
+        if !self.is_nominal(&tokens[i]) {
+            return false;
+        }
+        // A word that is also a verb is a noun only after a verb or preposition
+        self.verbs.base_form(&tokens[i]).is_none()
+            || i.checked_sub(1).is_some_and(|p| self.grammar.is_preposition(&tokens[p]) || self.is_verb_at(tokens, p))
+    }
+
+    /// A word that can be part of a noun phrase
+    fn is_nominal(&self, word: &str) -> bool {
+        let g = self.grammar;
+        if g.is_article(word) || g.is_preposition(word) || g.is_pronoun(word) || g.is_conjunction(word)
+            || g.is_auxiliary(word) || ADVERBS.contains(&word) || word.starts_with(|c: char| !c.is_alphanumeric()) {
+            return false;
+        }
+        // Inflected verb forms end it ("the dog ate"), unless also nouns ("the files")
+        match self.verbs.base_form(word) {
+            Some(base) => base == word || self.entry(word).is_some(),
+            None => true,
+        }
+    }
+
+    /// Verb at a token not preceded by a determiner or preposition ("the file")
+    fn is_verb_at(&self, tokens: &[String], i: usize) -> bool {
+        let word = &tokens[i];
+        !self.grammar.is_auxiliary(word) && self.verbs.base_form(word).is_some()
+            && i.checked_sub(1).is_none_or(|p| !DETERMINERS.contains(&tokens[p].as_str()) && !self.grammar.is_preposition(&tokens[p]))
+    }
+
+    /// Index of the first verb
+    fn first_verb(&self, tokens: &[String]) -> Option<usize> {
+        (0..tokens.len()).find(|&i| self.is_verb_at(tokens, i))
+    }
+
+    /// Verb governing a pronoun and the pronoun's role: Patient after the
+    /// verb with no preposition between, Agent before it
+    fn governing_verb(&self, tokens: &[String], position: usize) -> Option<(crate::verbs::GroupKey, crate::verbs::VerbGroup, SemanticRole)> {
+        let (verb, role) = match (0..position).rev().find(|&i| self.is_verb_at(tokens, i)) {
+            Some(v) if !(v + 1..position).any(|i| self.grammar.is_preposition(&tokens[i])) => (v, SemanticRole::Patient),
+            Some(_) => return None,
+            None => ((position + 1..tokens.len()).find(|&i| self.is_verb_at(tokens, i))?, SemanticRole::Agent),
+        };
+        let entry = self.verbs.lookup(&tokens[verb])?;
+        Some((entry.group_key(), entry.group, role))
+    }
+
+    /// Gender of a noun or name word
+    fn gender(&self, word: &str) -> Gender {
+        match self.entry(word) {
+            Some(e) if e.has_tag("male") => Gender::Masculine,
+            Some(e) if e.has_tag("female") => Gender::Feminine,
+            Some(e) if matches!(e.category, SemanticCategory::Person { .. }) => Gender::Unknown,
+            Some(_) => Gender::Neuter,
+            // Names not in the lexicon are taken to be people's
+            None if self.gazetteer.get(word).is_none_or(|k| k == EntityType::Person) => Gender::Unknown,
+            None => Gender::Neuter,
+        }
+    }
+
+    /// Gender of a person's name: its given name's, else its last word's
+    fn name_gender(&self, name: &[String]) -> Gender {
+        let given = name.first().map_or("", |w| w.as_str());
+        if self.gazetteer.has_tag(given, "male") {
+            return Gender::Masculine;
+        }
+        if self.gazetteer.has_tag(given, "female") {
+            return Gender::Feminine;
+        }
+        match name.last().map(|w| self.gender(w)) {
+            Some(Gender::Neuter) | None => Gender::Unknown,
+            Some(gender) => gender,
+        }
+    }
+
+    fn entry(&self, word: &str) -> Option<&'a SemanticEntry> {
+        self.semantics.get(word).or_else(|| self.semantics.get(&self.grammar.noun_lemma(word)))
+    }
+}
+
+impl Lexicon {
+    /// Resolve the references of consecutive sentences
+    pub fn resolve_references(&self, sentences: &[&str]) -> Discourse {
+        let verbs = self.verbs();
+        let dictionary = self.dictionary();
+        let semantics = self.semantics();
+        let gazetteer = self.gazetteer();
+        CoreferenceResolver::new(self.grammar(), &verbs, &dictionary, &semantics, &gazetteer).resolve(sentences)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn antecedents(text: &[&str]) -> Vec<(String, Option<String>)> {
+        let discourse = Lexicon::new().resolve_references(text);
+        discourse.references.iter()
+            .map(|r| (r.text.clone(), discourse.antecedent(r).map(|m| m.text.clone())))
+            .collect()
+    }
+
+    #[test]
+    fn test_pronoun_agreement() {
+        assert_eq!(antecedents(&["Open the file", "Then delete it"]),
+            vec![("it".to_string(), Some("the file".to_string()))]);
+        assert_eq!(antecedents(&["The boy found a book", "He read it"]), vec![
+            ("he".to_string(), Some("the boy".to_string())),
+            ("it".to_string(), Some("a book".to_string())),
+        ]);
+        assert_eq!(antecedents(&["The king met the queen", "She smiled"]),
+            vec![("she".to_string(), Some("the queen".to_string()))]);
+        assert_eq!(antecedents(&["Copy the files", "Then compress them"]),
+            vec![("them".to_string(), Some("the files".to_string()))]);
+        // Nothing to refer to
+        assert_eq!(antecedents(&["Delete it"]), vec![("it".to_string(), None)]);
+        assert!(antecedents(&["It is raining"]).is_empty());
+    }
+
+    #[test]
+    fn test_name_gender() {
+        assert_eq!(antecedents(&["John loves Mary", "She loves him"]), vec![
+            ("she".to_string(), Some("mary".to_string())),
+            ("him".to_string(), Some("john".to_string())),
+        ]);
+        assert_eq!(antecedents(&["Mary called John Smith", "He answered her"]), vec![
+            ("he".to_string(), Some("john smith".to_string())),
+            ("her".to_string(), Some("mary".to_string())),
+        ]);
+        // A preference the antecedent misses only lowers its score
+        assert_eq!(antecedents(&["The cat caught a mouse", "It ate it"]), vec![
+            ("it".to_string(), Some("the cat".to_string())),
+            ("it".to_string(), Some("a mouse".to_string())),
+        ]);
+    }
+
+    #[test]
+    fn test_semantic_fit_and_definites() {
+        // "drink" wants a liquid, so "it" skips the nearer cup
+        let found = antecedents(&["Pour the water into the cup", "Then drink it"]);
+        assert_eq!(found, vec![("it".to_string(), Some("the water".to_string()))]);
+
+        // An unknown head after a known word ("second" is also a noun)
+        let discourse = Lexicon::new().resolve_references(&["Pay the second invoice"]);
+        assert_eq!(discourse.mentions[0].head, "invoice");
+
+        let discourse = Lexicon::new().resolve_references(&["Open a red file", "Close the file"]);
+        let reference = &discourse.references[0];
+        assert_eq!(reference.kind, ReferenceKind::Definite);
+        assert_eq!(discourse.antecedent(reference).unwrap().text, "a red file");
+
+        let tokens: Vec<String> = ["then", "delete", "it"].iter().map(|s| s.to_string()).collect();
+        let discourse = Lexicon::new().resolve_references(&["Open the file", "Then delete it"]);
+        assert_eq!(discourse.substitute(1, &tokens), vec!["then", "delete", "the", "file"]);
+        assert_eq!(discourse.substitute_spans(1, &tokens).1, vec![2..4]);
+    }
+}
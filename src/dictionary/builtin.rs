@@ -0,0 +1,56 @@
+//! Built-in word list
+//!
+//! `build.rs` compiles `common_words.txt` into a perfect-hash index and a
+//! BK-tree laid out as flat arrays: node `i` holds `WORDS[i]`, and its
+//! children are the run `BK_EDGES[start..start + count]` given by
+//! `BK_NODES[i]`. Entries are built the first time they are asked for.
+
+use std::sync::OnceLock;
+
+use super::{DictionaryEntry, PartOfSpeech};
+use super::bktree::levenshtein;
+use crate::phf;
+
+include!(concat!(env!("OUT_DIR"), "/dictionary_tables.rs"));
+
+static ENTRIES: [OnceLock<DictionaryEntry>; WORDS.len()] = [const { OnceLock::new() }; WORDS.len()];
+
+/// Index of a lowercase word in `WORDS`
+pub(super) fn index(word: &str) -> Option<usize> {
+    phf::index(word, &WORD_DISPLACEMENTS, &WORD_SLOTS).filter(|&i| WORDS[i] == word)
+}
+
+/// Entry of a word, built on first use
+pub(super) fn entry(index: usize) -> &'static DictionaryEntry {
+    ENTRIES[index].get_or_init(|| DictionaryEntry {
+        word: WORDS[index].to_string(),
+        pos: vec![PartOfSpeech::Unknown],
+        definitions: vec![],
+        frequency: 50,
+    })
+}
+
+/// Words within `max_distance` of the query, excluding the query itself
+pub(super) fn find_within(query: &str, max_distance: usize) -> Vec<(String, usize)> {
+    let mut results = Vec::new();
+    if WORDS.is_empty() {
+        return results;
+    }
+    let mut stack = vec![0usize];
+    while let Some(node) = stack.pop() {
+        let dist = levenshtein(WORDS[node], query);
+        if dist <= max_distance && dist > 0 {
+            results.push((WORDS[node].to_string(), dist));
+        }
+        // Triangle inequality: only children in [dist - max, dist + max] can match
+        let (start, count) = BK_NODES[node];
+        let edges = &BK_EDGES[start as usize..start as usize + count as usize];
+        for &(child_dist, child) in edges {
+            let child_dist = child_dist as usize;
+            if child_dist >= dist.saturating_sub(max_distance) && child_dist <= dist + max_distance {
+                stack.push(child as usize);
+            }
+        }
+    }
+    results
+}
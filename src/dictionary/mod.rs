@@ -7,10 +7,13 @@
 //! - **BK-Tree**: Fuzzy search reduced from O(N*M) to O(log N * M) average
 //! - **Length filtering**: Pre-filter candidates by word length
 //! - **Bounded Levenshtein**: Early termination when distance exceeds threshold
+//! - **Static tables**: The built-in word list and its BK-tree are generated
+//!   at compile time, so creating a dictionary does no work
 
 mod bktree;
+mod builtin;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use bktree::BKTree;
 
 /// Dictionary entry
@@ -42,10 +45,12 @@ pub enum PartOfSpeech {
 }
 
 /// English Dictionary
+///
+/// Built-in words come from static tables; `entries` and `bk_tree` hold
+/// only words added at runtime.
 #[derive(Debug)]
 pub struct EnglishDictionary {
     entries: HashMap<String, DictionaryEntry>,
-    valid_words: HashSet<String>,
     bk_tree: BKTree,
     pub stats: DictionaryStats,
 }
@@ -68,43 +73,39 @@ impl Default for EnglishDictionary {
 
 impl EnglishDictionary {
     pub fn new() -> Self {
-        let mut dict = Self {
+        Self {
             entries: HashMap::new(),
-            valid_words: HashSet::new(),
             bk_tree: BKTree::new(),
-            stats: DictionaryStats::default(),
-        };
-        dict.load_common_words();
-        dict
+            stats: DictionaryStats {
+                total_entries: builtin::WORDS.len(),
+                ..DictionaryStats::default()
+            },
+        }
     }
 
-    fn load_common_words(&mut self) {
-        // Load 5000+ most common English words
-        let common_words = include_str!("common_words.txt");
-        for line in common_words.lines() {
-            let word = line.trim().to_lowercase();
-            if !word.is_empty() && !word.starts_with('#') {
-                self.valid_words.insert(word.clone());
-                self.bk_tree.insert(word.clone());
-                self.entries.insert(word.clone(), DictionaryEntry {
-                    word,
-                    pos: vec![PartOfSpeech::Unknown],
-                    definitions: vec![],
-                    frequency: 50,
-                });
-            }
+    /// Add a word, or replace the entry of an existing one
+    pub fn add(&mut self, mut entry: DictionaryEntry) {
+        entry.word = entry.word.to_lowercase();
+        if entry.word.is_empty() {
+            return;
+        }
+        if builtin::index(&entry.word).is_none() && !self.entries.contains_key(&entry.word) {
+            self.bk_tree.insert(entry.word.clone());
         }
-        self.stats.total_entries = self.entries.len();
+        self.entries.insert(entry.word.clone(), entry);
+        self.stats.total_entries = self.len();
     }
 
     /// Check if a word is valid
     pub fn is_valid(&self, word: &str) -> bool {
-        self.valid_words.contains(&word.to_lowercase())
+        let w = word.to_lowercase();
+        self.entries.contains_key(&w) || builtin::index(&w).is_some()
     }
 
     /// Get entry for a word
     pub fn get(&self, word: &str) -> Option<&DictionaryEntry> {
-        self.entries.get(&word.to_lowercase())
+        let w = word.to_lowercase();
+        self.entries.get(&w).or_else(|| builtin::index(&w).map(builtin::entry))
     }
 
     /// Get frequency of a word (0 if not found)
@@ -117,17 +118,20 @@ impl EnglishDictionary {
     /// Uses BK-Tree for O(log N * M) average complexity instead of O(N * M)
     pub fn find_similar(&self, word: &str, max_distance: usize) -> Vec<(String, usize)> {
         let word_lower = word.to_lowercase();
-        self.bk_tree.find_within(&word_lower, max_distance)
+        let mut results = builtin::find_within(&word_lower, max_distance);
+        results.extend(self.bk_tree.find_within(&word_lower, max_distance));
+        results.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        results
     }
 
     /// Total word count
     pub fn len(&self) -> usize {
-        self.entries.len()
+        builtin::WORDS.len() + self.bk_tree.len()
     }
 
     /// Check if empty
     pub fn is_empty(&self) -> bool {
-        self.entries.is_empty()
+        self.len() == 0
     }
 }
 
@@ -143,6 +147,53 @@ mod tests {
         assert!(dict.len() > 1000);
     }
 
+    #[test]
+    fn test_static_tables_match_word_list() {
+        let dict = EnglishDictionary::new();
+        let mut tree = BKTree::new();
+        let mut count = 0;
+        for line in include_str!("common_words.txt").lines() {
+            let word = line.trim_start_matches('\u{feff}').trim().to_lowercase();
+            if !word.is_empty() && !word.starts_with('#') {
+                assert!(dict.is_valid(&word), "{}", word);
+                assert_eq!(dict.get(&word).map(|e| e.word.as_str()), Some(word.as_str()));
+                tree.insert(word);
+                count += 1;
+            }
+        }
+        assert!(dict.len() <= count);
+        assert!(!dict.is_valid("qwxz"));
+        for query in ["teh", "helo", "recieve", "wrld", "dictionery"] {
+            assert_eq!(dict.find_similar(query, 2), tree.find_within(query, 2));
+        }
+    }
+
+    #[test]
+    fn test_add_word() {
+        let mut dict = EnglishDictionary::new();
+        let before = dict.len();
+        assert!(!dict.is_valid("kubectl"));
+        dict.add(DictionaryEntry {
+            word: "Kubectl".to_string(),
+            pos: vec![PartOfSpeech::Noun],
+            definitions: vec![],
+            frequency: 20,
+        });
+        assert!(dict.is_valid("kubectl"));
+        assert_eq!(dict.len(), before + 1);
+        assert!(dict.find_similar("kubectll", 1).iter().any(|(w, d)| w == "kubectl" && *d == 1));
+
+        // Replacing a built-in word's entry does not add a word
+        dict.add(DictionaryEntry {
+            word: "the".to_string(),
+            pos: vec![PartOfSpeech::Article],
+            definitions: vec![],
+            frequency: 100,
+        });
+        assert_eq!(dict.frequency("the"), 100);
+        assert_eq!(dict.len(), before + 1);
+    }
+
     #[test]
     fn test_levenshtein() {
         use super::bktree::levenshtein;
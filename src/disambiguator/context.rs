@@ -0,0 +1,266 @@
+//! Noun semantics in context
+//!
+//! The semantic lexicon supplies the γ (context) term of a correction score
+//! and picks between the senses of a verb from the nouns that follow it:
+//! "run to the park" is Movement, "run a company" is Control. Both also
+//! weigh the selectional preferences of the verb ("eat" wants food).
+
+use crate::DetectedAction;
+use crate::semantic::{SemanticDB, SemanticEntry, SemanticCategory};
+use crate::srl::SemanticRole;
+use crate::verbs::{FunctionalCategory, VerbDatabase, VerbEntry};
+use super::SemanticDisambiguator;
+
+/// Prepositions introducing a place or direction
+const LOCATIVE: &[&str] = &[
+    "to", "at", "in", "into", "onto", "from", "near", "inside", "outside",
+    "toward", "towards", "through", "across", "around", "behind", "under",
+];
+
+/// Prepositions introducing a time
+const TEMPORAL: &[&str] = &["on", "in", "at", "during", "since", "until", "before", "after", "by"];
+
+/// Tokens after a verb searched for its arguments
+const ARGUMENT_WINDOW: usize = 5;
+
+/// Tokens before a word searched for a governing preposition
+const PREPOSITION_WINDOW: usize = 4;
+
+/// Affinity a later sense needs to replace the first one
+const MIN_SENSE_FIT: f64 = 0.5;
+
+/// Context score of a word without known semantics
+const NEUTRAL: f64 = 0.5;
+
+/// Context bonus of a word satisfying the governing verb's preference
+const PREFERENCE_MET: f64 = 0.15;
+
+/// Context penalty of a word breaking the governing verb's preference
+const PREFERENCE_BROKEN: f64 = 0.3;
+
+/// Change in sense fit from an object satisfying or breaking the sense's preference
+const SENSE_PREFERENCE: f64 = 0.3;
+
+/// How well a noun fits as an argument of a verb category (0.0 - 1.0)
+pub(crate) fn category_affinity(category: FunctionalCategory, noun: &SemanticEntry) -> f64 {
+    use FunctionalCategory as F;
+    let tag = |t: &str| noun.has_tag(t);
+    match category {
+        F::Movement if tag("location") => 1.0,
+        F::Movement if tag("vehicle") => 0.6,
+        F::Movement => 0.0,
+        F::Position if tag("location") => 0.8,
+        F::Position if noun.category.detail() == Some("furniture") => 0.7,
+        F::Position => 0.1,
+        F::Consumption if tag("edible") => 1.0,
+        F::Consumption => 0.0,
+        F::Communication if tag("human") || tag("information") || tag("readable") => 0.8,
+        F::Communication => 0.1,
+        F::Perception if tag("media") => 0.9,
+        F::Perception if tag("concrete") || tag("human") || tag("location") => 0.6,
+        F::Perception => 0.2,
+        F::Cognition if tag("abstract") => 0.7,
+        F::Cognition if tag("readable") => 0.6,
+        F::Cognition => 0.2,
+        F::Emotion if tag("human") => 0.7,
+        F::Emotion if tag("feeling") => 0.6,
+        F::Emotion => 0.3,
+        F::Physical if tag("concrete") || tag("body") => 0.8,
+        F::Physical => 0.1,
+        F::Transfer if tag("money") => 1.0,
+        F::Transfer if tag("concrete") => 0.7,
+        F::Transfer if tag("human") => 0.5,
+        F::Transfer => 0.1,
+        F::Possession if tag("money") => 0.8,
+        F::Possession if tag("concrete") => 0.7,
+        F::Possession => 0.2,
+        F::Creation if tag("media") || tag("readable") => 0.9,
+        F::Creation if tag("artifact") => 0.8,
+        F::Creation if tag("abstract") => 0.4,
+        F::Creation => 0.2,
+        F::Destruction if tag("concrete") => 0.7,
+        F::Destruction if tag("living") => 0.6,
+        F::Destruction => 0.2,
+        F::Control if tag("institution") => 0.9,
+        F::Control if tag("group") => 0.8,
+        F::Control if tag("money") => 0.7,
+        F::Control if tag("vehicle") || tag("electronic") => 0.6,
+        F::Control => 0.2,
+        F::Social if tag("human") => 0.9,
+        F::Social => 0.1,
+        F::Body if tag("body") => 0.8,
+        F::Body => 0.0,
+        F::Weather if tag("natural") => 0.6,
+        F::Weather => 0.0,
+        F::Measurement if noun.category == SemanticCategory::Quantity || tag("measure") => 0.9,
+        F::Measurement => 0.3,
+        F::Aspectual if tag("event") => 0.7,
+        F::Aspectual if tag("abstract") => 0.4,
+        F::Aspectual => 0.2,
+        F::Connection if tag("concrete") || tag("human") => 0.5,
+        _ => 0.3,
+    }
+}
+
+impl SemanticDisambiguator {
+    /// Semantic entry of a token or of its singular ("files" -> "file")
+    pub(super) fn noun_entry<'a>(&self, semantics: &'a SemanticDB, token: &str) -> Option<&'a SemanticEntry> {
+        semantics.get(token).or_else(|| semantics.get(&self.grammar().noun_lemma(token)))
+    }
+
+    /// How well `candidate` fits at `position` given the rest of the sentence
+    ///
+    /// Starts neutral; a matching preposition ("to the ..." for places), the
+    /// governing verb's affinity and selectional preferences, and nouns of
+    /// the same subcategory elsewhere in the sentence move it up or down.
+    pub(super) fn context_score(
+        &self,
+        candidate: &str,
+        tokens: &[String],
+        position: usize,
+        actions: &[DetectedAction],
+    ) -> f64 {
+        let verbs = self.verbs();
+        let semantics = self.semantics();
+        let Some(noun) = self.noun_entry(&semantics, candidate) else { return NEUTRAL };
+        let mut score = NEUTRAL;
+
+        let preposition = tokens[..position].iter().rev()
+            .take(PREPOSITION_WINDOW)
+            .find(|t| self.grammar().is_preposition(t));
+        if let Some(prep) = preposition {
+            if (LOCATIVE.contains(&prep.as_str()) && noun.has_tag("location"))
+                || (TEMPORAL.contains(&prep.as_str()) && noun.has_tag("time")) {
+                score += 0.3;
+            }
+        }
+
+        if let Some(verb) = actions.iter().rfind(|a| a.position < position) {
+            score += 0.3 * (category_affinity(verb.category, noun) - 0.5);
+        }
+
+        if let Some((verb, role)) = self.governing_role(tokens, position, actions) {
+            match verbs.sense_preference(verb.group_key(), verb.group, role) {
+                Some(preference) if preference.accepts(noun) => score += PREFERENCE_MET,
+                Some(_) => score -= PREFERENCE_BROKEN,
+                None => {}
+            }
+        }
+
+        let related = tokens.iter().enumerate()
+            .filter(|(j, _)| *j != position)
+            .filter_map(|(_, t)| self.noun_entry(&semantics, t))
+            .filter(|other| other.category.name() == noun.category.name()
+                && other.subcategory.is_some() && other.subcategory == noun.subcategory)
+            .count();
+        score += 0.1 * related.min(2) as f64;
+
+        score.clamp(0.0, 1.0)
+    }
+
+    /// Verb governing the word at `position` and the role the word plays
+    ///
+    /// A word after a verb with no preposition in between is its Patient;
+    /// a word before the first verb is the Agent of that verb.
+    fn governing_role<'a>(
+        &self,
+        tokens: &[String],
+        position: usize,
+        actions: &'a [DetectedAction],
+    ) -> Option<(&'a DetectedAction, SemanticRole)> {
+        let actions = actions.iter().filter(|a| !a.nominal);
+        match actions.clone().rfind(|a| a.position < position) {
+            Some(verb) => tokens[verb.position + 1..position].iter()
+                .all(|t| !self.grammar().is_preposition(t) && !self.grammar().is_conjunction(t))
+                .then_some((verb, SemanticRole::Patient)),
+            None => actions.clone().find(|a| a.position > position).map(|verb| (verb, SemanticRole::Agent)),
+        }
+    }
+
+    /// Sense of a verb best fitting the nouns after it
+    ///
+    /// Returns `None` when no sense fits clearly better than the first one.
+    pub(super) fn choose_sense<'a>(
+        &self,
+        verbs: &VerbDatabase,
+        senses: &'a [VerbEntry],
+        tokens: &[String],
+        position: usize,
+    ) -> Option<&'a VerbEntry> {
+        if senses.len() < 2 {
+            return None;
+        }
+        let semantics = self.semantics();
+        // (noun, is the direct object)
+        let mut direct = true;
+        let mut arguments: Vec<(&SemanticEntry, bool)> = Vec::new();
+        for token in tokens.iter().skip(position + 1).take(ARGUMENT_WINDOW) {
+            if self.grammar().is_conjunction(token) {
+                break;
+            }
+            if self.grammar().is_preposition(token) {
+                direct = false;
+            } else if let Some(noun) = self.noun_entry(&semantics, token) {
+                arguments.push((noun, direct));
+            }
+        }
+        if arguments.is_empty() {
+            return None;
+        }
+        let fit = |sense: &VerbEntry| {
+            let object = verbs.sense_preference(sense.group_key(), sense.group, SemanticRole::Patient);
+            arguments.iter()
+                .map(|&(noun, direct)| {
+                    let affinity = category_affinity(sense.category, noun);
+                    match &object {
+                        Some(preference) if direct && preference.accepts(noun) => (affinity + SENSE_PREFERENCE).min(1.0),
+                        Some(_) if direct => (affinity - SENSE_PREFERENCE).max(0.0),
+                        _ => affinity,
+                    }
+                })
+                .fold(0.0, f64::max)
+        };
+
+        let first = fit(&senses[0]);
+        let (best, score) = senses[1..].iter()
+            .map(|sense| (sense, fit(sense)))
+            .fold((&senses[0], first), |best, cur| if cur.1 > best.1 { cur } else { best });
+        (score > first && score >= MIN_SENSE_FIT).then_some(best)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SemanticDisambiguator;
+    use crate::verbs::FunctionalCategory;
+
+    #[test]
+    fn test_verb_sense_from_object() {
+        let dis = SemanticDisambiguator::new();
+        let run = |s: &str| dis.detect_actions(s).into_iter().find(|a| a.base_form == "run").unwrap().category;
+        assert_eq!(run("she runs the company"), FunctionalCategory::Control);
+        assert_eq!(run("she runs to the park"), FunctionalCategory::Movement);
+        assert_eq!(run("she runs every day"), FunctionalCategory::Movement);
+    }
+
+    #[test]
+    fn test_preference_ranks_candidates() {
+        let dis = SemanticDisambiguator::new();
+        let sandwich = dis.process("the boy ate the sandwitch");
+        assert_eq!(sandwich.corrections[0].corrected, "sandwich");
+        // An edible object outscores the same word where eating it breaks no rule
+        let edible = dis.process("he ate the sandwitch").corrections[0].explanation.context_score;
+        let neutral = dis.process("he saw the sandwitch").corrections[0].explanation.context_score;
+        assert!(edible > neutral);
+    }
+
+    #[test]
+    fn test_context_score_prefers_fitting_noun() {
+        let dis = SemanticDisambiguator::new();
+        let result = dis.process("they walked to the parx");
+        let correction = &result.corrections[0];
+        assert_eq!(correction.corrected, "park");
+        assert!(correction.explanation.context_score > 0.5);
+        assert!(correction.explanation.candidates.len() > 1);
+    }
+}
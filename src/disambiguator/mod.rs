@@ -2,18 +2,30 @@
 //!
 //! Main engine for semantic disambiguation.
 
+mod context;
+mod selection;
+
+use std::sync::{Arc, RwLockReadGuard};
+
 use crate::{Config, ProcessedSentence, Correction, CorrectionExplanation, DetectedAction};
-use crate::verbs::{VerbDatabase, FunctionalCategory, VerbGroup};
+use crate::verbs::{VerbDatabase, DerivationKind, CategoryKey, GroupKey};
 use crate::grammar::EnglishGrammar;
 use crate::dictionary::EnglishDictionary;
+use crate::lexicon::Lexicon;
+use crate::semantic::SemanticDB;
+use crate::ner::Entity;
+use crate::applog::SharedContext;
+use crate::domain::DomainRegistry;
+use crate::topic::{TopicKind, TopicProfile};
+use crate::srl::{SemanticFrame, SemanticRoleLabeler};
+
+/// Grammar score of a correction candidate (no grammar check yet)
+const GRAMMAR_SCORE: f64 = 0.7;
 
 /// Main semantic disambiguator
 pub struct SemanticDisambiguator {
-    #[allow(dead_code)]
     config: Config,
-    verbs: VerbDatabase,
-    grammar: EnglishGrammar,
-    dictionary: EnglishDictionary,
+    lexicon: Arc<Lexicon>,
 }
 
 impl Default for SemanticDisambiguator {
@@ -24,60 +36,45 @@ impl Default for SemanticDisambiguator {
 
 impl SemanticDisambiguator {
     pub fn new() -> Self {
-        Self {
-            config: Config::default(),
-            verbs: VerbDatabase::with_builtin(),
-            grammar: EnglishGrammar::new(),
-            dictionary: EnglishDictionary::new(),
-        }
+        Self::with_config(Config::default())
     }
 
     pub fn with_config(config: Config) -> Self {
-        Self {
-            config,
-            verbs: VerbDatabase::with_builtin(),
-            grammar: EnglishGrammar::new(),
-            dictionary: EnglishDictionary::new(),
-        }
+        Self::with_lexicon_and_config(Arc::new(Lexicon::new()), config)
+    }
+
+    /// Create a disambiguator on a shared lexicon
+    pub fn with_lexicon(lexicon: Arc<Lexicon>) -> Self {
+        Self::with_lexicon_and_config(lexicon, Config::default())
+    }
+
+    /// Create a disambiguator on a shared lexicon with a custom config
+    pub fn with_lexicon_and_config(lexicon: Arc<Lexicon>, config: Config) -> Self {
+        Self { config, lexicon }
     }
 
     /// Process a sentence
     pub fn process(&self, sentence: &str) -> ProcessedSentence {
-        let tokens = self.grammar.tokenize(sentence);
+        let tokens = self.grammar().tokenize(sentence);
         let mut corrections = Vec::new();
-        let mut detected_actions = Vec::new();
         let mut corrected_tokens = tokens.clone();
 
+        let detected_actions = self.find_actions(&tokens);
+        let entities = self.entities(sentence);
+
         for (i, token) in tokens.iter().enumerate() {
-            // Check for verb actions
-            if let Some(entry) = self.verbs.lookup(token) {
-                detected_actions.push(DetectedAction {
-                    verb: token.clone(),
-                    base_form: entry.base.clone(),
-                    category: entry.category,
-                    group: entry.group,
-                    confidence: 0.95,
-                    position: i,
-                });
+            // Names keep their spelling
+            if let Some(entity) = entities.iter().find(|e| e.contains(i)) {
+                corrected_tokens[i] = entity.tokens[i - entity.start].clone();
+                continue;
             }
 
             // Check for spelling errors
-            if !self.dictionary.is_valid(token) && !self.verbs.is_verb(token) {
-                if let Some((corrected, _)) = self.suggest_correction(token) {
-                    corrections.push(Correction {
-                        position: i,
-                        original: token.clone(),
-                        corrected: corrected.clone(),
-                        confidence: 0.8,
-                        explanation: CorrectionExplanation {
-                            char_score: 0.8,
-                            grammar_score: 0.7,
-                            context_score: 0.75,
-                            candidates: vec![(corrected.clone(), 0.8)],
-                            reason: format!("Spelling correction: {} -> {}", token, corrected),
-                        },
-                    });
-                    corrected_tokens[i] = corrected;
+            let known = self.dictionary().is_valid(token) || self.verbs().is_verb(token);
+            if !known {
+                if let Some(correction) = self.correct(&tokens, i, &detected_actions) {
+                    corrected_tokens[i] = correction.corrected.clone();
+                    corrections.push(correction);
                 }
             }
         }
@@ -90,39 +87,168 @@ impl SemanticDisambiguator {
             confidence,
             corrections,
             detected_actions,
+            entities,
         }
     }
 
-    /// Suggest a spelling correction
-    fn suggest_correction(&self, word: &str) -> Option<(String, f64)> {
-        let similar = self.dictionary.find_similar(word, 2);
-        similar.into_iter()
-            .min_by_key(|(_, dist)| *dist)
-            .map(|(w, dist)| (w, 1.0 - (dist as f64 * 0.2)))
+    /// Choose a spelling correction for the token at `position`
+    ///
+    /// Candidates are scored α·characters + β·grammar + γ·context, where the
+    /// context term comes from the semantic lexicon.
+    fn correct(&self, tokens: &[String], position: usize, actions: &[DetectedAction]) -> Option<Correction> {
+        let word = &tokens[position];
+        let mut similar = self.dictionary().find_similar(word, 2);
+        similar.truncate(self.config.max_candidates);
+
+        let mut best: Option<(String, f64, f64, f64)> = None;
+        let mut candidates = Vec::new();
+        for (candidate, dist) in similar {
+            let char_score = 1.0 - (dist as f64 * 0.2);
+            let context_score = self.context_score(&candidate, tokens, position, actions);
+            let total = self.config.alpha * char_score
+                + self.config.beta * GRAMMAR_SCORE
+                + self.config.gamma * context_score;
+            if best.as_ref().is_none_or(|b| total > b.1) {
+                best = Some((candidate.clone(), total, char_score, context_score));
+            }
+            candidates.push((candidate, total));
+        }
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let (corrected, confidence, char_score, context_score) = best?;
+        Some(Correction {
+            position,
+            original: word.clone(),
+            corrected: corrected.clone(),
+            confidence,
+            explanation: CorrectionExplanation {
+                char_score,
+                grammar_score: GRAMMAR_SCORE,
+                context_score,
+                candidates,
+                reason: format!("Spelling correction: {} -> {}", word, corrected),
+            },
+        })
+    }
+
+    /// Get the shared lexicon
+    pub fn lexicon(&self) -> &Arc<Lexicon> {
+        &self.lexicon
+    }
+
+    /// Read the verb database
+    pub fn with_verbs<R>(&self, f: impl FnOnce(&VerbDatabase) -> R) -> R {
+        f(&self.verbs())
     }
 
-    /// Get verb database reference
-    pub fn verbs(&self) -> &VerbDatabase {
-        &self.verbs
+    /// Change the verb database (to add custom verbs)
+    ///
+    /// Changes are seen by every engine sharing the lexicon; `f` must not
+    /// call back into them.
+    pub fn with_verbs_mut<R>(&self, f: impl FnOnce(&mut VerbDatabase) -> R) -> R {
+        f(&mut self.lexicon.verbs_mut())
     }
 
     /// Get grammar reference
     pub fn grammar(&self) -> &EnglishGrammar {
-        &self.grammar
+        self.lexicon.grammar()
+    }
+
+    /// Read the dictionary
+    pub fn with_dictionary<R>(&self, f: impl FnOnce(&EnglishDictionary) -> R) -> R {
+        f(&self.dictionary())
+    }
+
+    /// Read the semantic noun lexicon
+    pub fn with_semantics<R>(&self, f: impl FnOnce(&SemanticDB) -> R) -> R {
+        f(&self.semantics())
     }
 
-    /// Get dictionary reference
-    pub fn dictionary(&self) -> &EnglishDictionary {
-        &self.dictionary
+    pub(crate) fn verbs(&self) -> RwLockReadGuard<'_, VerbDatabase> {
+        self.lexicon.verbs()
+    }
+
+    pub(crate) fn dictionary(&self) -> RwLockReadGuard<'_, EnglishDictionary> {
+        self.lexicon.dictionary()
+    }
+
+    pub(crate) fn semantics(&self) -> RwLockReadGuard<'_, SemanticDB> {
+        self.lexicon.semantics()
     }
 
     /// Detect actions in a sentence
     pub fn detect_actions(&self, sentence: &str) -> Vec<DetectedAction> {
-        let tokens = self.grammar.tokenize(sentence);
+        let tokens = self.grammar().tokenize(sentence);
+        self.find_actions(&tokens)
+    }
+
+    /// Named entities of a sentence (people, organisations, places, dates, products)
+    pub fn entities(&self, sentence: &str) -> Vec<Entity> {
+        self.lexicon.recognize_entities(sentence)
+    }
+
+    /// Update a conversation with a turn: its topic, active domain and named entities
+    ///
+    /// A turn without actions or domain keywords keeps the previous topic.
+    pub fn update_context(&self, text: &str, domains: &DomainRegistry, context: &mut SharedContext) -> TopicProfile {
+        let profile = self.topics(text, domains);
+        if let Some(top) = profile.top() {
+            context.set_topic(&top.name);
+        }
+        if let Some(domain) = profile.topics.iter().find(|t| matches!(t.kind, TopicKind::Domain(_))) {
+            context.set_domain(&domain.name);
+        }
+        for sentence in self.grammar().split_sentences(text) {
+            for entity in self.entities(sentence) {
+                context.add_entity(entity);
+            }
+        }
+        profile
+    }
+
+    /// Detect actions with their semantic roles (Agent, Patient, Goal...)
+    pub fn semantic_frames(&self, sentence: &str) -> Vec<SemanticFrame> {
+        let tokens = self.grammar().tokenize(sentence);
+        let actions: Vec<DetectedAction> = self.find_actions(&tokens)
+            .into_iter()
+            .filter(|a| !a.nominal)
+            .collect();
+        SemanticRoleLabeler::new(&self.verbs(), self.grammar()).label(&tokens, &actions)
+    }
+
+    /// Find verb actions in tokens, preferring phrasal verbs ("turn off")
+    ///
+    /// With `Config::detect_nominals`, action nouns ("creation") are reported
+    /// as nominal actions of their verb.
+    fn find_actions(&self, tokens: &[String]) -> Vec<DetectedAction> {
+        let verbs = self.verbs();
         let mut actions = Vec::new();
+        let mut particles: Vec<usize> = Vec::new();
 
         for (i, token) in tokens.iter().enumerate() {
-            if let Some(entry) = self.verbs.lookup(token) {
+            if particles.contains(&i) {
+                continue;
+            }
+
+            if let Some(m) = verbs.match_phrasal(tokens, i) {
+                particles.extend(m.particle_positions.iter().copied());
+                actions.push(DetectedAction {
+                    verb: m.surface(tokens),
+                    base_form: m.entry.base(),
+                    category: m.entry.category,
+                    group: m.entry.group,
+                    confidence: 0.95,
+                    position: i,
+                    particle: Some(m.entry.particle.clone()),
+                    nominal: false,
+                    custom_category: None,
+                    custom_group: None,
+                    form_roles: verbs.form_roles(token, &m.entry.verb),
+                });
+            } else if let Some((entry, roles)) = verbs.lookup_with_roles(token) {
+                let entry = verbs.lookup_all(token)
+                    .and_then(|senses| self.choose_sense(&verbs, senses, tokens, i))
+                    .unwrap_or(entry);
                 actions.push(DetectedAction {
                     verb: token.clone(),
                     base_form: entry.base.clone(),
@@ -130,24 +256,47 @@ impl SemanticDisambiguator {
                     group: entry.group,
                     confidence: 0.95,
                     position: i,
+                    particle: None,
+                    nominal: false,
+                    custom_category: entry.custom_category,
+                    custom_group: entry.custom_group,
+                    form_roles: roles,
                 });
+            } else if self.config.detect_nominals {
+                if let Some((entry, derived)) = verbs.derivation_of(token) {
+                    if derived.kind == DerivationKind::ActionNoun {
+                        actions.push(DetectedAction {
+                            verb: token.clone(),
+                            base_form: entry.base.clone(),
+                            category: entry.category,
+                            group: entry.group,
+                            confidence: 0.7,
+                            position: i,
+                            particle: None,
+                            nominal: true,
+                            custom_category: entry.custom_category,
+                            custom_group: entry.custom_group,
+                            form_roles: Vec::new(),
+                        });
+                    }
+                }
             }
         }
 
         actions
     }
 
-    /// Get all verbs by category
-    pub fn verbs_by_category(&self, category: FunctionalCategory) -> Vec<String> {
-        self.verbs.by_category(category)
+    /// Get all verbs by category (built-in or custom)
+    pub fn verbs_by_category(&self, category: impl Into<CategoryKey>) -> Vec<String> {
+        self.verbs().by_category(category)
             .iter()
             .map(|e| e.base.clone())
             .collect()
     }
 
-    /// Get all verbs by group
-    pub fn verbs_by_group(&self, group: VerbGroup) -> Vec<String> {
-        self.verbs.by_group(group)
+    /// Get all verbs by group (built-in or custom)
+    pub fn verbs_by_group(&self, group: impl Into<GroupKey>) -> Vec<String> {
+        self.verbs().by_group(group)
             .iter()
             .map(|e| e.base.clone())
             .collect()
@@ -157,6 +306,8 @@ impl SemanticDisambiguator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ner::EntityType;
+    use crate::verbs::{FunctionalCategory, VerbGroup};
 
     #[test]
     fn test_disambiguator() {
@@ -180,4 +331,80 @@ mod tests {
         assert!(movement.contains(&"walk".to_string()));
         assert!(movement.contains(&"run".to_string()));
     }
+
+    #[test]
+    fn test_phrasal_detection() {
+        let dis = SemanticDisambiguator::new();
+        let actions = dis.detect_actions("Please turn the lights off");
+        let turn_off = actions.iter().find(|a| a.base_form == "turn off").unwrap();
+        assert_eq!(turn_off.category, FunctionalCategory::Aspectual);
+        assert_eq!(turn_off.particle.as_deref(), Some("off"));
+        assert!(!actions.iter().any(|a| a.base_form == "turn"));
+    }
+
+    #[test]
+    fn test_nominal_actions() {
+        let plain = SemanticDisambiguator::new();
+        assert!(plain.detect_actions("The deletion of files").iter().all(|a| !a.nominal));
+
+        let dis = SemanticDisambiguator::with_config(Config { detect_nominals: true, ..Config::default() });
+        let actions = dis.detect_actions("The creation of the report");
+        let creation = actions.iter().find(|a| a.verb == "creation").unwrap();
+        assert!(creation.nominal);
+        assert_eq!(creation.base_form, "create");
+
+        let actions = dis.detect_actions("the deletion of files");
+        let deletion = actions.iter().find(|a| a.nominal).unwrap();
+        assert_eq!(deletion.base_form, "delete");
+        assert_eq!(deletion.position, 1);
+    }
+
+    #[test]
+    fn test_action_form_roles() {
+        use crate::verbs::FormRole;
+
+        let dis = SemanticDisambiguator::new();
+        let actions = dis.detect_actions("she walked home and he turns the lights off");
+        assert!(actions[0].has_role(FormRole::Past) && actions[0].has_role(FormRole::PastParticiple));
+        assert_eq!(actions[1].base_form, "turn off");
+        assert_eq!(actions[1].form_roles, vec![FormRole::ThirdPerson]);
+    }
+
+    #[test]
+    fn test_custom_category_actions() {
+        use crate::verbs::VerbEntry;
+
+        let dis = SemanticDisambiguator::new();
+        let (devops, release) = dis.with_verbs_mut(|db| {
+            let devops = db.register_category("DevOps", "Deployment and operations").unwrap();
+            let release = db.register_group("Release", devops).unwrap();
+            db.add(VerbEntry::regular("deploy", FunctionalCategory::Transfer, VerbGroup::Send, Some(true), 40)
+                .in_custom_group(release));
+            (devops, release)
+        });
+
+        let actions = dis.detect_actions("deploy the service");
+        assert_eq!(actions[0].custom_category, Some(devops));
+        assert_eq!(actions[0].group_key(), release.into());
+        assert_eq!(dis.verbs_by_category(devops), vec!["deploy".to_string()]);
+        assert_eq!(dis.verbs_by_group(release), vec!["deploy".to_string()]);
+    }
+
+    #[test]
+    fn test_entities_exempt_from_correction() {
+        let dis = SemanticDisambiguator::new();
+        let result = dis.process("I met Dr. Zorn at Avermex Research Inc");
+        assert!(result.corrections.is_empty());
+        assert_eq!(result.corrected, "i met Dr Zorn at Avermex Research Inc");
+        let kinds: Vec<EntityType> = result.entities.iter().map(|e| e.kind).collect();
+        assert_eq!(kinds, vec![EntityType::Person, EntityType::Organization]);
+        assert_eq!((result.entities[1].start, result.entities[1].end), (5, 8));
+        assert!(dis.process("We flew to the U.S. last week").corrections.is_empty());
+        assert_eq!(dis.process("Troops left the U.S. in May").corrected, "troop left the U. S. in may");
+
+        // An unknown word opening a sentence is a name; the gazetteer types it
+        assert_eq!(dis.entities("Avermex builds tools")[0].kind, EntityType::Other);
+        dis.lexicon().add_entity("Avermex", EntityType::Organization);
+        assert_eq!(dis.entities("Avermex builds tools")[0].kind, EntityType::Organization);
+    }
 }
@@ -0,0 +1,116 @@
+//! Selectional restrictions
+//!
+//! Checks the arguments found by role labelling against the selectional
+//! preferences of their verb: "The rock ate the sandwich" has an inanimate
+//! Agent, "Drink the chair" a Patient that is not a liquid.
+
+use crate::SelectionViolation;
+use crate::srl::RoleFiller;
+use super::SemanticDisambiguator;
+
+impl SemanticDisambiguator {
+    /// Arguments of a sentence that break their verb's selectional preferences
+    ///
+    /// Arguments whose head noun is not in the semantic lexicon are not
+    /// reported.
+    pub fn selection_violations(&self, sentence: &str) -> Vec<SelectionViolation> {
+        self.check_selection(sentence).0
+    }
+
+    /// How plausible the verb-argument combinations of a sentence are
+    ///
+    /// The share of checked arguments that satisfy their verb's preferences:
+    /// 1.0 when nothing breaks them, 0.0 for "Drink the chair".
+    pub fn plausibility(&self, sentence: &str) -> f64 {
+        let (violations, checked) = self.check_selection(sentence);
+        if checked == 0 {
+            1.0
+        } else {
+            1.0 - violations.len() as f64 / checked as f64
+        }
+    }
+
+    /// Violations and number of arguments checked
+    fn check_selection(&self, sentence: &str) -> (Vec<SelectionViolation>, usize) {
+        let frames = self.semantic_frames(sentence);
+        let tokens = self.grammar().tokenize(sentence);
+        let verbs = self.verbs();
+        let semantics = self.semantics();
+
+        let mut violations = Vec::new();
+        let mut checked = 0;
+        for frame in &frames {
+            let action = &frame.action;
+            for argument in &frame.arguments {
+                let Some(preference) = verbs.sense_preference(action.group_key(), action.group, argument.role) else {
+                    continue;
+                };
+                let Some(position) = head_position(argument, &tokens) else { continue };
+                let Some(noun) = self.noun_entry(&semantics, &tokens[position]) else { continue };
+                checked += 1;
+                if !preference.accepts(noun) {
+                    violations.push(SelectionViolation {
+                        verb: action.base_form.clone(),
+                        role: argument.role,
+                        argument: argument.text.clone(),
+                        head: tokens[position].clone(),
+                        expected: preference.types,
+                        found: noun.category.clone(),
+                        position,
+                    });
+                }
+            }
+        }
+        (violations, checked)
+    }
+}
+
+/// Token index of the head noun of an argument (its last word)
+fn head_position(argument: &RoleFiller, tokens: &[String]) -> Option<usize> {
+    (argument.start..argument.end.min(tokens.len()))
+        .rev()
+        .find(|&i| tokens[i].chars().any(|c| c.is_alphabetic()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SemanticDisambiguator, SemanticRole, SemanticType, SelectionalPreference, VerbGroup};
+
+    #[test]
+    fn test_selection_violations() {
+        let dis = SemanticDisambiguator::new();
+
+        let violations = dis.selection_violations("The rock ate the sandwich");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].role, SemanticRole::Agent);
+        assert_eq!(violations[0].head, "rock");
+        assert_eq!(violations[0].expected, vec![SemanticType::Animate]);
+
+        let violations = dis.selection_violations("Drink the chair");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].role, SemanticRole::Patient);
+        assert!(violations[0].message().contains("chair"));
+
+        assert!(dis.selection_violations("The boy ate the sandwich").is_empty());
+        assert!(dis.selection_violations("She drank the water").is_empty());
+        assert!(dis.selection_violations("The cat ate the fish").is_empty());
+        let rock = &dis.selection_violations("The rock ate the sandwich")[0];
+        assert_eq!(rock.message(), "'eat' expects an animate Agent, but 'the rock' is an object");
+        assert_eq!(dis.plausibility("Drink the chair"), 0.0);
+        assert!(dis.plausibility("The rock ate the sandwich") < dis.plausibility("The dog ate the sandwich"));
+    }
+
+    #[test]
+    fn test_runtime_preferences() {
+        let dis = SemanticDisambiguator::new();
+        assert!(dis.selection_violations("The teacher pushed the idea").is_empty());
+        dis.with_verbs_mut(|db| db.set_preferences(VerbGroup::Push,
+            vec![SelectionalPreference::new(SemanticRole::Patient, &[SemanticType::Concrete])]));
+        assert_eq!(dis.selection_violations("The teacher pushed the idea").len(), 1);
+
+        // An empty list clears the built-in preferences
+        assert_eq!(dis.selection_violations("The rock ate the sandwich").len(), 1);
+        dis.with_verbs_mut(|db| db.set_preferences(VerbGroup::Eat, vec![]));
+        assert!(dis.selection_violations("The rock ate the sandwich").is_empty());
+    }
+}
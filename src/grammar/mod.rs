@@ -8,6 +8,13 @@
 //! - **Improved tokenization**: Better handling of punctuation and special cases
 
 use std::collections::{HashSet, HashMap};
+use crate::morphology::Morphology;
+
+/// Abbreviations whose period does not end a sentence ("Dr. Smith")
+const ABBREVIATIONS: &[&str] = &[
+    "mr", "mrs", "ms", "mx", "dr", "prof", "rev", "fr", "sr", "jr", "st", "mt", "vs",
+    "gen", "col", "capt", "lt", "sgt", "gov", "sen",
+];
 
 /// English grammar analyzer
 #[derive(Debug)]
@@ -27,6 +34,8 @@ pub struct EnglishGrammar {
     auxiliaries: HashSet<String>,
     /// Contraction expansions
     contractions: HashMap<String, Vec<String>>,
+    /// Noun, adjective and adverb morphology
+    morphology: Morphology,
 }
 
 impl Default for EnglishGrammar {
@@ -45,6 +54,7 @@ impl EnglishGrammar {
             conjunctions: Self::load_conjunctions(),
             auxiliaries: Self::load_auxiliaries(),
             contractions: Self::load_contractions(),
+            morphology: Morphology::new(),
         }
     }
 
@@ -63,7 +73,9 @@ impl EnglishGrammar {
         ["in", "on", "at", "to", "for", "with", "by", "from", "up", "about",
          "into", "over", "after", "beneath", "under", "above", "below",
          "between", "through", "during", "before", "behind", "beyond",
-         "without", "within", "along", "among", "around", "against", "across"]
+         "without", "within", "along", "among", "around", "against", "across",
+         "onto", "toward", "towards", "near", "until", "off", "out", "inside",
+         "outside", "beside", "upon", "via"]
             .iter().map(|s| s.to_string()).collect()
     }
 
@@ -207,6 +219,43 @@ impl EnglishGrammar {
         self.auxiliaries.contains(&word.to_lowercase())
     }
 
+    /// Get the noun/adjective/adverb morphology engine
+    pub fn morphology(&self) -> &Morphology {
+        &self.morphology
+    }
+
+    /// Get the singular lemma of a noun ("children" -> "child")
+    pub fn noun_lemma(&self, word: &str) -> String {
+        self.morphology.noun_lemma(word)
+    }
+
+    /// Split a text into sentences at `.`, `!` and `?`
+    ///
+    /// A period ends a sentence only before whitespace or the end of the text,
+    /// and not after a title ("Dr."), an initial ("J.") or an initialism ("U.S.").
+    pub fn split_sentences<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        let mut sentences = Vec::new();
+        let mut start = 0;
+        for (i, c) in text.char_indices() {
+            let ends = match c {
+                '!' | '?' => true,
+                '.' => {
+                    let before_blank = text[i + 1..].chars().next().is_none_or(char::is_whitespace);
+                    let word = text[start..i].rsplit(char::is_whitespace).next().unwrap_or_default();
+                    before_blank && !is_abbreviation(word)
+                }
+                _ => false,
+            };
+            if ends {
+                sentences.push(text[start..i].trim());
+                start = i + 1;
+            }
+        }
+        sentences.push(text[start..].trim());
+        sentences.retain(|s| !s.is_empty());
+        sentences
+    }
+
     /// Tokenize a sentence with contraction expansion
     ///
     /// Expands contractions like "don't" -> ["do", "not"], "I'm" -> ["i", "am"]
@@ -283,6 +332,15 @@ pub enum POS {
     Unknown,
 }
 
+/// A word written before an abbreviation's period: a title, a single
+/// capital other than "I", or a word with periods inside ("U.S", "e.g")
+fn is_abbreviation(word: &str) -> bool {
+    let word = word.trim_start_matches(['(', '"', '\'']);
+    let mut chars = word.chars();
+    let initial = matches!((chars.next(), chars.next()), (Some(c), None) if c.is_uppercase() && c != 'I');
+    initial || word.contains('.') || ABBREVIATIONS.contains(&word.to_lowercase().as_str())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,6 +350,7 @@ mod tests {
         let grammar = EnglishGrammar::new();
         assert!(grammar.is_article("the"));
         assert!(grammar.is_preposition("in"));
+        assert!(grammar.is_preposition("onto"));
         assert!(grammar.is_pronoun("they"));
         assert!(grammar.is_conjunction("and"));
         assert!(grammar.is_auxiliary("will"));
@@ -305,6 +364,15 @@ mod tests {
         assert_eq!(tokens[0], "the");
     }
 
+    #[test]
+    fn test_split_sentences() {
+        let grammar = EnglishGrammar::new();
+        assert_eq!(grammar.split_sentences("Dr. Smith called. Mr. Jones left."), vec!["Dr. Smith called", "Mr. Jones left"]);
+        assert_eq!(grammar.split_sentences("John F. Kennedy visited the U.S. in May! Did he?"),
+            vec!["John F. Kennedy visited the U.S. in May", "Did he"]);
+        assert_eq!(grammar.split_sentences("It costs 3.50 today. So did I."), vec!["It costs 3.50 today", "So did I"]);
+    }
+
     #[test]
     fn test_contractions() {
         let grammar = EnglishGrammar::new();
@@ -334,6 +402,13 @@ mod tests {
         assert_eq!(tokens, vec!["let", "us", "go"]);
     }
 
+    #[test]
+    fn test_morphology_access() {
+        let grammar = EnglishGrammar::new();
+        assert_eq!(grammar.noun_lemma("mice"), "mouse");
+        assert_eq!(grammar.morphology().comparative("big"), "bigger");
+    }
+
     #[test]
     fn test_tokenize_raw() {
         let grammar = EnglishGrammar::new();
@@ -0,0 +1,163 @@
+//! # Shared Linguistic Resources
+//!
+//! A `Lexicon` bundles the verb database, the dictionary, the semantic noun
+//! lexicon, the gazetteer of named entities and the grammar. Build it once, wrap it in an `Arc`, and construct
+//! every engine from it: the engines then share one copy of the data, and a
+//! verb or word added through any of them is seen by all of them at once.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use nl_sre_english::{Lexicon, CommandParser, SemanticDisambiguator, VerbEntry, FunctionalCategory, VerbGroup};
+//!
+//! let lexicon = Arc::new(Lexicon::new());
+//! let mut parser = CommandParser::with_lexicon(Arc::clone(&lexicon));
+//! let disambiguator = SemanticDisambiguator::with_lexicon(Arc::clone(&lexicon));
+//!
+//! lexicon.add_verb(VerbEntry::regular("deploy", FunctionalCategory::Control, VerbGroup::Generic, Some(true), 30));
+//! assert_eq!(parser.parse("deploy the service").unwrap().action, "deploy");
+//! assert_eq!(disambiguator.detect_actions("we deploy daily")[0].base_form, "deploy");
+//! ```
+
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::dictionary::{DictionaryEntry, EnglishDictionary};
+use crate::grammar::EnglishGrammar;
+use crate::ner::{EntityType, Gazetteer};
+use crate::semantic::SemanticDB;
+use crate::verbs::{VerbDatabase, VerbEntry};
+
+/// Verb database, dictionary, semantic lexicon, gazetteer and grammar shared by the engines
+///
+/// All but the grammar sit behind read-write locks so that user extensions
+/// can be made through a shared reference. Release a guard returned by one of
+/// the `*_mut` accessors before using an engine built on the same lexicon.
+#[derive(Debug)]
+pub struct Lexicon {
+    verbs: RwLock<VerbDatabase>,
+    dictionary: RwLock<EnglishDictionary>,
+    semantics: RwLock<SemanticDB>,
+    gazetteer: RwLock<Gazetteer>,
+    grammar: EnglishGrammar,
+}
+
+impl Default for Lexicon {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Lexicon {
+    /// Lexicon with all built-in data
+    pub fn new() -> Self {
+        Self::from_parts(VerbDatabase::with_builtin(), EnglishDictionary::new(), SemanticDB::with_builtin(), EnglishGrammar::new())
+    }
+
+    /// Lexicon from existing resources, with the built-in gazetteer
+    pub fn from_parts(
+        verbs: VerbDatabase,
+        dictionary: EnglishDictionary,
+        semantics: SemanticDB,
+        grammar: EnglishGrammar,
+    ) -> Self {
+        Self {
+            verbs: RwLock::new(verbs),
+            dictionary: RwLock::new(dictionary),
+            semantics: RwLock::new(semantics),
+            gazetteer: RwLock::new(Gazetteer::with_builtin()),
+            grammar,
+        }
+    }
+
+    /// Read access to the verb database
+    pub fn verbs(&self) -> RwLockReadGuard<'_, VerbDatabase> {
+        self.verbs.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Write access to the verb database (to add custom verbs)
+    pub fn verbs_mut(&self) -> RwLockWriteGuard<'_, VerbDatabase> {
+        self.verbs.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Read access to the dictionary
+    pub fn dictionary(&self) -> RwLockReadGuard<'_, EnglishDictionary> {
+        self.dictionary.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Write access to the dictionary (to add custom words)
+    pub fn dictionary_mut(&self) -> RwLockWriteGuard<'_, EnglishDictionary> {
+        self.dictionary.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Read access to the semantic noun lexicon
+    pub fn semantics(&self) -> RwLockReadGuard<'_, SemanticDB> {
+        self.semantics.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Write access to the semantic noun lexicon (to add custom nouns)
+    pub fn semantics_mut(&self) -> RwLockWriteGuard<'_, SemanticDB> {
+        self.semantics.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Read access to the gazetteer of named entities
+    pub fn gazetteer(&self) -> RwLockReadGuard<'_, Gazetteer> {
+        self.gazetteer.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Write access to the gazetteer (to add custom names)
+    pub fn gazetteer_mut(&self) -> RwLockWriteGuard<'_, Gazetteer> {
+        self.gazetteer.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Grammar reference
+    pub fn grammar(&self) -> &EnglishGrammar {
+        &self.grammar
+    }
+
+    /// Add a verb entry
+    pub fn add_verb(&self, entry: VerbEntry) {
+        self.verbs_mut().add(entry);
+    }
+
+    /// Add a dictionary word
+    pub fn add_word(&self, entry: DictionaryEntry) {
+        self.dictionary_mut().add(entry);
+    }
+
+    /// Add a named entity to the gazetteer
+    pub fn add_entity(&self, name: &str, kind: EntityType) {
+        self.gazetteer_mut().add(name, kind);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use crate::{CommandParser, SemanticDisambiguator, FunctionalCategory, VerbGroup};
+    use crate::dictionary::PartOfSpeech;
+    use crate::transpiler::Transpiler;
+
+    #[test]
+    fn test_extensions_visible_to_all_engines() {
+        let lexicon = Arc::new(Lexicon::new());
+        let mut parser = CommandParser::with_lexicon(Arc::clone(&lexicon));
+        let disambiguator = SemanticDisambiguator::with_lexicon(Arc::clone(&lexicon));
+        let transpiler = Transpiler::with_lexicon(Arc::clone(&lexicon));
+        assert!(parser.parse("frobnicate the widget").is_none());
+
+        // Added through one engine, seen by the others
+        disambiguator.lexicon().add_verb(
+            VerbEntry::regular("frobnicate", FunctionalCategory::Physical, VerbGroup::Generic, Some(true), 10));
+        assert_eq!(parser.parse("frobnicate the widget").unwrap().action, "frobnicate");
+        assert_eq!(transpiler.to_pirs("Frobnicate the widget!")[0].head, "frobnicate");
+        assert!(Arc::ptr_eq(parser.lexicon(), transpiler.lexicon()));
+
+        lexicon.add_word(DictionaryEntry {
+            word: "widgetry".to_string(),
+            pos: vec![PartOfSpeech::Noun],
+            definitions: vec![],
+            frequency: 10,
+        });
+        assert!(disambiguator.process("the widgetry works").corrections.is_empty());
+    }
+}
@@ -28,6 +28,7 @@ pub mod uniform;
 pub mod applog;
 pub mod tao;
 pub mod grammar;
+pub mod morphology;
 pub mod semantic;
 pub mod disambiguator;
 pub mod chars;
@@ -37,16 +38,36 @@ pub mod command_parser;
 pub mod domain;
 pub mod quantitative;
 pub mod transpiler;
+pub mod srl;
+pub mod similarity;
+pub mod lexicon;
+pub mod sentiment;
+pub mod ner;
+pub mod temporal;
+pub mod coreference;
+pub mod topic;
+mod phf;
 
 // Main re-exports
 pub use disambiguator::SemanticDisambiguator;
 pub use uniform::UnifyContext;
-pub use applog::SharedContext;
+pub use applog::{SharedContext, ContextValue, ContextEvent, ContextError, Scope, Snapshot};
 pub use grammar::EnglishGrammar;
+pub use lexicon::Lexicon;
+pub use morphology::{Morphology, Degree};
 pub use semantic::{SemanticDB, SemanticCategory};
 pub use dictionary::{EnglishDictionary, DictionaryEntry};
-pub use verbs::{VerbDatabase, VerbEntry, VerbGroup, FunctionalCategory};
+pub use verbs::{VerbDatabase, VerbEntry, VerbGroup, FunctionalCategory, FormRole};
+pub use verbs::{CategoryId, GroupId, CategoryKey, GroupKey, Taxon, ClassId};
+pub use verbs::{SemanticType, SelectionalPreference};
 pub use command_parser::{CommandParser, ParsedCommand, ParserStats};
+pub use srl::{SemanticFrame, SemanticRole, RoleFiller};
+pub use similarity::{SentenceSimilarity, ActionAlignment};
+pub use ner::{Entity, EntityType, Gazetteer};
+pub use temporal::{TemporalTagger, TemporalExpression, TemporalValue};
+pub use coreference::{Discourse, Mention, Reference, ReferenceKind};
+pub use topic::{TopicProfile, Topic, TopicKind, Keyword};
+pub use sentiment::{DocumentSentiment, SentenceSentiment, SentimentWord, SentimentSource};
 
 // Domain plugin exports (for NL-SRE-Domains integration)
 pub use domain::{DomainPlugin, DomainRegistry, DomainConstraint, ValidatedQuantity, GenericSIDomain};
@@ -67,6 +88,8 @@ pub struct ProcessedSentence {
     pub corrections: Vec<Correction>,
     /// Detected verb actions
     pub detected_actions: Vec<DetectedAction>,
+    /// Named entities (exempt from correction)
+    pub entities: Vec<Entity>,
 }
 
 /// An individual correction
@@ -99,6 +122,70 @@ pub struct DetectedAction {
     pub confidence: f64,
     /// Position in sentence
     pub position: usize,
+    /// Particle of a phrasal verb ("off" in "turn the lights off")
+    pub particle: Option<String>,
+    /// Action expressed by a derived noun ("creation" in "the creation of the report")
+    pub nominal: bool,
+    /// Custom category of the verb, if registered with one
+    pub custom_category: Option<CategoryId>,
+    /// Custom group of the verb, if registered with one
+    pub custom_group: Option<GroupId>,
+    /// Roles the verb token fills ("walked": Past and PastParticiple);
+    /// empty for nominal actions
+    pub form_roles: Vec<FormRole>,
+}
+
+impl DetectedAction {
+    /// Check if the verb token can fill a role
+    pub fn has_role(&self, role: FormRole) -> bool {
+        self.form_roles.contains(&role)
+    }
+
+    /// Custom category if set, otherwise the built-in one
+    pub fn category_key(&self) -> CategoryKey {
+        self.custom_category.map(CategoryKey::Custom).unwrap_or(CategoryKey::Builtin(self.category))
+    }
+
+    /// Custom group if set, otherwise the built-in one
+    pub fn group_key(&self) -> GroupKey {
+        self.custom_group.map(GroupKey::Custom).unwrap_or(GroupKey::Builtin(self.group))
+    }
+}
+
+/// An argument that breaks its verb's selectional preferences
+///
+/// "The rock ate the sandwich": `eat` wants an animate Agent.
+#[derive(Debug, Clone)]
+pub struct SelectionViolation {
+    /// Base form of the verb
+    pub verb: String,
+    /// Role of the argument
+    pub role: SemanticRole,
+    /// Argument phrase ("the rock")
+    pub argument: String,
+    /// Head noun checked ("rock")
+    pub head: String,
+    /// Types the role accepts
+    pub expected: Vec<SemanticType>,
+    /// Category of the head noun
+    pub found: SemanticCategory,
+    /// Position of the head noun (token index)
+    pub position: usize,
+}
+
+impl SelectionViolation {
+    /// Readable description
+    pub fn message(&self) -> String {
+        let expected: Vec<&str> = self.expected.iter().map(|t| t.name()).collect();
+        format!("'{}' expects {} {}, but '{}' is {}",
+            self.verb, with_article(&expected.join(" or ")), self.role.name(), self.argument, with_article(self.found.name()))
+    }
+}
+
+/// A word with "a" or "an" before it, by its first letter
+fn with_article(word: &str) -> String {
+    let article = if word.starts_with(['a', 'e', 'i', 'o', 'u', 'A', 'E', 'I', 'O', 'U']) { "an" } else { "a" };
+    format!("{} {}", article, word)
 }
 
 /// Detailed explanation of a correction
@@ -131,6 +218,8 @@ pub struct Config {
     pub max_candidates: usize,
     /// Enable verb action detection
     pub detect_actions: bool,
+    /// Also report actions expressed by nouns ("the deletion of files")
+    pub detect_nominals: bool,
 }
 
 impl Default for Config {
@@ -142,6 +231,7 @@ impl Default for Config {
             min_confidence: 0.60,
             max_candidates: 10,
             detect_actions: true,
+            detect_nominals: false,
         }
     }
 }
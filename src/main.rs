@@ -13,13 +13,15 @@ fn main() {
 
     // Show verb database stats
     println!("=== Verb Database Statistics ===");
-    let verb_db = disambiguator.verbs();
-    println!("Total verbs: {}", verb_db.stats.total_verbs);
-    println!("Irregular verbs: {}", verb_db.stats.irregular_verbs);
-    println!("Regular verbs: {}", verb_db.stats.regular_verbs);
-    println!("Total forms indexed: {}", verb_db.stats.total_forms);
-    println!("Categories: {}", verb_db.stats.categories_used);
-    println!("Groups: {}", verb_db.stats.groups_used);
+    disambiguator.with_verbs(|verb_db| {
+        println!("Total verbs: {}", verb_db.stats.total_verbs);
+        println!("Irregular verbs: {}", verb_db.stats.irregular_verbs);
+        println!("Regular verbs: {}", verb_db.stats.regular_verbs);
+        println!("Total forms indexed: {}", verb_db.stats.total_forms);
+        println!("Forms shared by several verbs: {}", verb_db.stats.colliding_forms);
+        println!("Categories: {}", verb_db.stats.categories_used);
+        println!("Groups: {}", verb_db.stats.groups_used);
+    });
     println!();
 
     // Show verbs by category
@@ -61,6 +63,13 @@ fn main() {
             }
         }
 
+        if !result.entities.is_empty() {
+            println!("Entities:");
+            for entity in &result.entities {
+                println!("  - {} ({})", entity.text, entity.kind.name());
+            }
+        }
+
         if !result.corrections.is_empty() {
             println!("Corrections:");
             for corr in &result.corrections {
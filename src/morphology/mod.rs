@@ -0,0 +1,560 @@
+//! # Morphology Module
+//!
+//! Lemmatization and inflection for nouns, adjectives and adverbs.
+//! Verbs keep their own forms in [`crate::verbs::VerbEntry`].
+//!
+//! - **Nouns**: irregular plurals (mice, children), -ies/-es/-s plurals, invariant nouns
+//! - **Adjectives**: comparatives and superlatives (better, biggest, happier)
+//! - **Adverbs**: -ly adverbs and their adjective bases (quickly, happily, gently)
+
+use std::collections::{HashMap, HashSet};
+
+/// Degree of comparison for adjectives
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Degree {
+    /// Base form (big)
+    Positive,
+    /// Comparative (bigger)
+    Comparative,
+    /// Superlative (biggest)
+    Superlative,
+}
+
+/// Noun, adjective and adverb morphology
+#[derive(Debug, Clone)]
+pub struct Morphology {
+    /// Irregular plurals: singular -> plural
+    irregular_plurals: HashMap<String, String>,
+    /// Irregular plurals: plural -> singular
+    irregular_singulars: HashMap<String, String>,
+    /// Nouns with identical singular and plural
+    invariant_nouns: HashSet<String>,
+    /// Words ending in -s that are not plurals: singular nouns taking -es
+    /// (virus, lens) and function words (always, this)
+    non_plurals: HashSet<String>,
+    /// Nouns in -ie, whose -ies plural does not come from -y (movie, cookie)
+    ie_nouns: HashSet<String>,
+    /// Adjectives with -er/-est comparison, the bases `adjective_lemma` accepts
+    gradable_adjectives: HashSet<String>,
+    /// Irregular comparison: base -> (comparative, superlative)
+    irregular_degrees: HashMap<String, (String, String)>,
+    /// Irregular comparison: inflected form -> (base, degree)
+    irregular_degree_lemmas: HashMap<String, (String, Degree)>,
+    /// Irregular adverbs: adverb -> adjective
+    irregular_adverbs: HashMap<String, String>,
+}
+
+impl Default for Morphology {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Morphology {
+    pub fn new() -> Self {
+        let irregular_plurals = Self::load_irregular_plurals();
+        let irregular_singulars = irregular_plurals.iter()
+            .map(|(s, p)| (p.clone(), s.clone()))
+            .collect();
+
+        let mut irregular_degrees = HashMap::new();
+        let mut irregular_degree_lemmas = HashMap::new();
+        for (base, comp, sup) in Self::load_irregular_degrees() {
+            irregular_degrees.insert(base.to_string(), (comp.to_string(), sup.to_string()));
+            // First listed base wins: "better" -> "good" rather than "well"
+            irregular_degree_lemmas.entry(comp.to_string())
+                .or_insert((base.to_string(), Degree::Comparative));
+            irregular_degree_lemmas.entry(sup.to_string())
+                .or_insert((base.to_string(), Degree::Superlative));
+        }
+
+        Self {
+            irregular_plurals,
+            irregular_singulars,
+            invariant_nouns: Self::load_invariant_nouns(),
+            non_plurals: Self::load_non_plurals(),
+            ie_nouns: Self::load_ie_nouns(),
+            gradable_adjectives: Self::load_gradable_adjectives(),
+            irregular_degrees,
+            irregular_degree_lemmas,
+            irregular_adverbs: Self::load_irregular_adverbs(),
+        }
+    }
+
+    fn load_irregular_plurals() -> HashMap<String, String> {
+        [
+            ("man", "men"), ("woman", "women"), ("child", "children"),
+            ("person", "people"), ("mouse", "mice"), ("louse", "lice"),
+            ("goose", "geese"), ("tooth", "teeth"), ("foot", "feet"),
+            ("ox", "oxen"), ("die", "dice"), ("penny", "pence"),
+            ("leaf", "leaves"), ("life", "lives"), ("knife", "knives"),
+            ("wife", "wives"), ("wolf", "wolves"), ("half", "halves"),
+            ("calf", "calves"), ("shelf", "shelves"), ("self", "selves"),
+            ("thief", "thieves"), ("loaf", "loaves"), ("elf", "elves"),
+            ("sheaf", "sheaves"), ("scarf", "scarves"), ("wharf", "wharves"),
+            ("hero", "heroes"), ("potato", "potatoes"), ("tomato", "tomatoes"),
+            ("echo", "echoes"), ("veto", "vetoes"), ("torpedo", "torpedoes"),
+            ("analysis", "analyses"), ("crisis", "crises"), ("thesis", "theses"),
+            ("hypothesis", "hypotheses"), ("diagnosis", "diagnoses"),
+            ("axis", "axes"), ("parenthesis", "parentheses"), ("synopsis", "synopses"),
+            ("phenomenon", "phenomena"), ("criterion", "criteria"),
+            ("medium", "media"), ("curriculum", "curricula"),
+            ("bacterium", "bacteria"), ("memorandum", "memoranda"),
+            ("cactus", "cacti"), ("fungus", "fungi"), ("nucleus", "nuclei"),
+            ("stimulus", "stimuli"), ("radius", "radii"), ("syllabus", "syllabi"),
+            ("alumnus", "alumni"), ("focus", "foci"), ("corpus", "corpora"),
+            ("index", "indices"), ("matrix", "matrices"), ("vertex", "vertices"),
+            ("appendix", "appendices"), ("formula", "formulae"), ("antenna", "antennae"),
+            ("quiz", "quizzes"), ("bus", "buses"), ("gas", "gases"),
+        ]
+        .iter()
+        .map(|(s, p)| (s.to_string(), p.to_string()))
+        .collect()
+    }
+
+    fn load_invariant_nouns() -> HashSet<String> {
+        ["sheep", "fish", "deer", "moose", "series", "species", "aircraft",
+         "spacecraft", "salmon", "trout", "swine", "bison", "offspring",
+         "news", "means", "headquarters", "crossroads", "barracks",
+         "physics", "mathematics", "economics", "politics", "statistics",
+         "ethics", "athletics", "gymnastics", "linguistics", "electronics",
+         "logistics", "genetics", "robotics", "semantics", "acoustics",
+         "scissors", "trousers", "pants", "jeans", "glasses", "clothes",
+         "police", "cattle", "information", "equipment", "furniture",
+         "advice", "software", "hardware", "data", "chaos"]
+            .iter().map(|s| s.to_string()).collect()
+    }
+
+    fn load_non_plurals() -> HashSet<String> {
+        ["status", "lens", "virus", "campus", "census", "bonus", "genus", "atlas",
+         "canvas", "bias", "circus", "chorus", "walrus", "surplus", "apparatus",
+         "plus", "us", "this", "his", "is", "was", "yes", "thus", "always", "perhaps"]
+            .iter().map(|s| s.to_string()).collect()
+    }
+
+    fn load_ie_nouns() -> HashSet<String> {
+        ["movie", "cookie", "pie", "tie", "lie", "zombie", "rookie", "calorie",
+         "prairie", "smoothie", "brownie", "genie", "goalie", "selfie", "hippie",
+         "newbie", "freebie", "budgie", "magpie", "necktie", "hoodie", "sortie"]
+            .iter().map(|s| s.to_string()).collect()
+    }
+
+    fn load_gradable_adjectives() -> HashSet<String> {
+        ["big", "small", "large", "tall", "short", "long", "wide", "narrow", "deep",
+         "shallow", "high", "low", "old", "young", "new", "fast", "slow", "quick",
+         "hot", "cold", "warm", "cool", "strong", "weak", "easy", "hard", "simple",
+         "early", "late", "close", "near", "cheap", "dark", "light", "bright",
+         "clean", "dirty", "safe", "rich", "poor", "busy", "heavy", "quiet", "loud",
+         "soft", "smart", "thin", "thick", "fat", "sad", "wet", "dry", "great",
+         "kind", "calm", "clear", "fresh", "full", "gentle", "happy", "lucky",
+         "pretty", "proud", "rare", "rough", "sharp", "sweet", "tough", "ugly",
+         "wise", "brave", "funny", "angry", "hungry", "tiny", "huge", "nice",
+         "fine", "noble", "humble", "able", "green", "red", "white", "black",
+         "flat", "mild", "wild", "cute", "lazy", "silly", "tidy",
+         "healthy", "wealthy", "friendly", "costly", "deadly", "likely", "strange",
+         "true", "pure", "sure", "firm", "loose", "tight", "steep", "plain", "grand"]
+            .iter().map(|s| s.to_string()).collect()
+    }
+
+    fn load_irregular_degrees() -> Vec<(&'static str, &'static str, &'static str)> {
+        vec![
+            ("good", "better", "best"), ("well", "better", "best"),
+            ("bad", "worse", "worst"), ("badly", "worse", "worst"),
+            ("ill", "worse", "worst"), ("far", "farther", "farthest"),
+            ("little", "less", "least"), ("much", "more", "most"),
+            ("many", "more", "most"),
+        ]
+    }
+
+    fn load_irregular_adverbs() -> HashMap<String, String> {
+        [
+            ("well", "good"), ("fast", "fast"), ("hard", "hard"),
+            ("late", "late"), ("early", "early"), ("daily", "daily"),
+            ("fully", "full"), ("truly", "true"), ("duly", "due"),
+            ("wholly", "whole"), ("publicly", "public"), ("shyly", "shy"),
+            ("slyly", "sly"), ("dryly", "dry"), ("gently", "gentle"),
+            ("subtly", "subtle"), ("idly", "idle"),
+            ("basically", "basic"), ("automatically", "automatic"),
+            ("dramatically", "dramatic"), ("scientifically", "scientific"),
+            ("specifically", "specific"), ("drastically", "drastic"),
+            ("realistically", "realistic"), ("systematically", "systematic"),
+            ("tragically", "tragic"), ("romantically", "romantic"),
+            ("enthusiastically", "enthusiastic"), ("frantically", "frantic"),
+        ]
+        .iter()
+        .map(|(adv, adj)| (adv.to_string(), adj.to_string()))
+        .collect()
+    }
+
+    // ========================================================================
+    // Nouns
+    // ========================================================================
+
+    /// Get the singular lemma of a noun ("mice" -> "mouse", "cities" -> "city")
+    ///
+    /// Words that do not look plural are returned unchanged.
+    pub fn noun_lemma(&self, word: &str) -> String {
+        let w = word.to_lowercase();
+
+        if let Some(singular) = self.irregular_singulars.get(&w) {
+            return singular.clone();
+        }
+        if self.invariant_nouns.contains(&w) || self.non_plurals.contains(&w)
+            || self.irregular_plurals.contains_key(&w) {
+            return w;
+        }
+        if w.len() <= 3 || !w.ends_with('s') {
+            return w;
+        }
+        // Latin/Greek singulars and double-s words are never plural here
+        if w.ends_with("ss") || w.ends_with("us") || w.ends_with("is") {
+            return w;
+        }
+
+        if let Some(stem) = w.strip_suffix("ies") {
+            let ie = format!("{}ie", stem);
+            if self.ie_nouns.contains(&ie) {
+                return ie;
+            }
+            if stem.len() > 1 {
+                return format!("{}y", stem);
+            }
+        }
+        if let Some(stem) = w.strip_suffix("es") {
+            if stem.ends_with("ch") || stem.ends_with("sh") || stem.ends_with('x')
+                || stem.ends_with("ss") || stem.ends_with('z') || self.is_s_singular(stem) {
+                return stem.to_string();
+            }
+        }
+        w[..w.len() - 1].to_string()
+    }
+
+    /// Singular noun in -s whose plural adds -es: a listed one, or an -us
+    /// noun such as "virus" or "status" ("causes" and "excuses" come from -use)
+    fn is_s_singular(&self, stem: &str) -> bool {
+        self.non_plurals.contains(stem) || self.irregular_plurals.contains_key(stem)
+            || (stem.len() >= 5 && ["rus", "nus", "tus", "pus"].iter().any(|e| stem.ends_with(e)))
+    }
+
+    /// Get the plural of a noun ("mouse" -> "mice", "city" -> "cities")
+    pub fn noun_plural(&self, noun: &str) -> String {
+        let n = noun.to_lowercase();
+
+        if let Some(plural) = self.irregular_plurals.get(&n) {
+            return plural.clone();
+        }
+        if self.invariant_nouns.contains(&n) {
+            return n;
+        }
+
+        if n.ends_with('s') || n.ends_with('x') || n.ends_with('z')
+            || n.ends_with("ch") || n.ends_with("sh") {
+            format!("{}es", n)
+        } else if ends_with_consonant_y(&n) {
+            format!("{}ies", &n[..n.len() - 1])
+        } else {
+            format!("{}s", n)
+        }
+    }
+
+    /// Check if a noun is (probably) plural
+    pub fn is_plural_noun(&self, word: &str) -> bool {
+        let w = word.to_lowercase();
+        if self.irregular_singulars.contains_key(&w) {
+            return true;
+        }
+        self.noun_lemma(&w) != w
+    }
+
+    // ========================================================================
+    // Adjectives
+    // ========================================================================
+
+    /// Get the base form and degree of an adjective ("biggest" -> ("big", Superlative))
+    ///
+    /// The base must be a known gradable adjective, so "number" and "owner"
+    /// stay positive; see [`Morphology::adjective_lemma_with`] to accept
+    /// more bases.
+    pub fn adjective_lemma(&self, word: &str) -> (String, Degree) {
+        self.adjective_lemma_with(word, |_| false)
+    }
+
+    /// Get the base form and degree of an adjective, accepting bases that are
+    /// built-in gradable adjectives or accepted by `is_known`
+    pub fn adjective_lemma_with<F: Fn(&str) -> bool>(&self, word: &str, is_known: F) -> (String, Degree) {
+        let w = word.to_lowercase();
+        let is_known = |w: &str| self.gradable_adjectives.contains(w) || is_known(w);
+
+        if let Some((base, degree)) = self.irregular_degree_lemmas.get(&w) {
+            return (base.clone(), *degree);
+        }
+        if is_known(&w) {
+            return (w, Degree::Positive);
+        }
+
+        let (stem, degree) = if let Some(stem) = w.strip_suffix("est") {
+            (stem, Degree::Superlative)
+        } else if let Some(stem) = w.strip_suffix("er") {
+            (stem, Degree::Comparative)
+        } else {
+            return (w, Degree::Positive);
+        };
+        if stem.len() < 2 {
+            return (w, Degree::Positive);
+        }
+
+        match Self::degree_stem_candidates(stem).into_iter().find(|c| is_known(c)) {
+            Some(found) => (found, degree),
+            None => (w, Degree::Positive),
+        }
+    }
+
+    /// Candidate bases for a stem with -er/-est removed, best guess first
+    fn degree_stem_candidates(stem: &str) -> Vec<String> {
+        let chars: Vec<char> = stem.chars().collect();
+        let n = chars.len();
+        let mut candidates = Vec::new();
+
+        // happi(er) -> happy
+        if let Some(base) = stem.strip_suffix('i') {
+            candidates.push(format!("{}y", base));
+        }
+        // bigg(er) -> big
+        if n >= 3 && chars[n - 1] == chars[n - 2] && !is_vowel(chars[n - 1])
+            && !matches!(chars[n - 1], 'l' | 's') {
+            candidates.push(without_last(stem).to_string());
+        }
+        // nic(er) -> nice, clos(er) -> close, larg(er) -> large
+        let last = chars[n - 1];
+        let single_vowel_before = n >= 2 && is_vowel(chars[n - 2]) && (n < 3 || !is_vowel(chars[n - 3]));
+        if !is_vowel(last)
+            && (matches!(last, 'c' | 'v' | 'z')
+                || (last == 'g' && n >= 2 && chars[n - 2] == 'r')
+                || (single_vowel_before && matches!(last, 'g' | 's' | 'r' | 't' | 'f' | 'p' | 'n' | 'm' | 'k'))) {
+            candidates.push(format!("{}e", stem));
+        }
+        // simpl(er) -> simple, gentl(er) -> gentle
+        if n >= 2 && chars[n - 1] == 'l' && !is_vowel(chars[n - 2]) && chars[n - 2] != 'l' {
+            candidates.insert(0, format!("{}e", stem));
+        }
+        candidates.push(stem.to_string());
+        candidates
+    }
+
+    /// Get the comparative of an adjective ("big" -> "bigger", "careful" -> "more careful")
+    pub fn comparative(&self, adjective: &str) -> String {
+        self.inflect_degree(adjective, Degree::Comparative)
+    }
+
+    /// Get the superlative of an adjective ("happy" -> "happiest", "famous" -> "most famous")
+    pub fn superlative(&self, adjective: &str) -> String {
+        self.inflect_degree(adjective, Degree::Superlative)
+    }
+
+    fn inflect_degree(&self, adjective: &str, degree: Degree) -> String {
+        let a = adjective.to_lowercase();
+        let (suffix, periphrastic) = match degree {
+            Degree::Positive => return a,
+            Degree::Comparative => ("er", "more"),
+            Degree::Superlative => ("est", "most"),
+        };
+
+        if let Some((comp, sup)) = self.irregular_degrees.get(&a) {
+            return if degree == Degree::Comparative { comp.clone() } else { sup.clone() };
+        }
+
+        let syllables = count_syllables(&a);
+        let inflects = syllables == 1
+            || (syllables == 2 && (a.ends_with('y') || a.ends_with("le") || a.ends_with("ow")));
+        if !inflects || a.ends_with("ed") {
+            return format!("{} {}", periphrastic, a);
+        }
+
+        if a.ends_with('e') {
+            format!("{}{}", a, &suffix[1..])
+        } else if ends_with_consonant_y(&a) {
+            format!("{}i{}", &a[..a.len() - 1], suffix)
+        } else if ends_with_cvc(&a) && syllables == 1 {
+            format!("{}{}{}", a, a.chars().last().unwrap_or_default(), suffix)
+        } else {
+            format!("{}{}", a, suffix)
+        }
+    }
+
+    // ========================================================================
+    // Adverbs
+    // ========================================================================
+
+    /// Get the adjective an -ly adverb derives from ("happily" -> "happy")
+    ///
+    /// Returns `None` if the word is not a derivable adverb.
+    pub fn adverb_lemma(&self, word: &str) -> Option<String> {
+        let w = word.to_lowercase();
+
+        if let Some(adj) = self.irregular_adverbs.get(&w) {
+            return Some(adj.clone());
+        }
+        let stem = w.strip_suffix("ly")?;
+        if stem.len() < 3 {
+            return None;
+        }
+
+        if let Some(base) = stem.strip_suffix('i') {
+            // happily -> happy
+            return Some(format!("{}y", base));
+        }
+        if stem.ends_with('b') || stem.ends_with("mp") {
+            // possibly -> possible, simply -> simple
+            return Some(format!("{}le", stem));
+        }
+        Some(stem.to_string())
+    }
+
+    /// Get the -ly adverb for an adjective ("happy" -> "happily", "basic" -> "basically")
+    pub fn adverb_from_adjective(&self, adjective: &str) -> String {
+        let a = adjective.to_lowercase();
+
+        if let Some((adv, _)) = self.irregular_adverbs.iter().find(|(_, adj)| **adj == a) {
+            return adv.clone();
+        }
+
+        if a.ends_with("ic") {
+            format!("{}ally", a)
+        } else if a.ends_with("le") && a.len() > 3 {
+            format!("{}y", &a[..a.len() - 1])
+        } else if ends_with_consonant_y(&a) {
+            format!("{}ily", &a[..a.len() - 1])
+        } else if a.ends_with("ll") {
+            format!("{}y", a)
+        } else {
+            format!("{}ly", a)
+        }
+    }
+}
+
+/// A word without its final character
+fn without_last(word: &str) -> &str {
+    word.char_indices().next_back().map_or(word, |(i, _)| &word[..i])
+}
+
+fn is_vowel(c: char) -> bool {
+    "aeiou".contains(c)
+}
+
+/// Ends in consonant + y ("city", "happy" but not "day", "boy")
+fn ends_with_consonant_y(word: &str) -> bool {
+    let chars: Vec<char> = word.chars().collect();
+    chars.len() > 1 && chars[chars.len() - 1] == 'y' && !is_vowel(chars[chars.len() - 2])
+}
+
+/// Ends in consonant-vowel-consonant, final consonant not w/x/y ("big", "hot")
+fn ends_with_cvc(word: &str) -> bool {
+    let chars: Vec<char> = word.chars().collect();
+    let n = chars.len();
+    n >= 3
+        && !is_vowel(chars[n - 1]) && !['w', 'x', 'y'].contains(&chars[n - 1])
+        && is_vowel(chars[n - 2])
+        && !is_vowel(chars[n - 3])
+}
+
+/// Rough syllable count by vowel groups, with silent final -e
+fn count_syllables(word: &str) -> usize {
+    let chars: Vec<char> = word.chars().collect();
+    let mut count = 0;
+    let mut prev_vowel = false;
+    for &c in &chars {
+        let v = is_vowel(c) || c == 'y';
+        if v && !prev_vowel {
+            count += 1;
+        }
+        prev_vowel = v;
+    }
+    if word.ends_with('e') && !word.ends_with("le") && count > 1 {
+        count -= 1;
+    }
+    count.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_noun_lemma() {
+        let m = Morphology::new();
+        assert_eq!(m.noun_lemma("cats"), "cat");
+        assert_eq!(m.noun_lemma("mice"), "mouse");
+        assert_eq!(m.noun_lemma("children"), "child");
+        assert_eq!(m.noun_lemma("cities"), "city");
+        assert_eq!(m.noun_lemma("boxes"), "box");
+        assert_eq!(m.noun_lemma("churches"), "church");
+        assert_eq!(m.noun_lemma("fish"), "fish");
+        assert_eq!(m.noun_lemma("glass"), "glass");
+        assert_eq!(m.noun_lemma("news"), "news");
+        assert_eq!(m.noun_lemma("buses"), "bus");
+        assert_eq!(m.noun_plural("gas"), "gases");
+        assert_eq!(m.noun_lemma("topics"), "topic");
+        assert_eq!(m.noun_lemma("comics"), "comic");
+        assert_eq!(m.noun_lemma("physics"), "physics");
+        assert_eq!(m.noun_lemma("movies"), "movie");
+        assert_eq!(m.noun_lemma("cookies"), "cookie");
+        assert_eq!(m.noun_lemma("viruses"), "virus");
+        assert_eq!(m.noun_lemma("statuses"), "status");
+        assert_eq!(m.noun_lemma("lenses"), "lens");
+        assert_eq!(m.noun_lemma("causes"), "cause");
+        assert_eq!(m.noun_lemma("virus"), "virus");
+    }
+
+    #[test]
+    fn test_noun_plural() {
+        let m = Morphology::new();
+        assert_eq!(m.noun_plural("cat"), "cats");
+        assert_eq!(m.noun_plural("mouse"), "mice");
+        assert_eq!(m.noun_plural("city"), "cities");
+        assert_eq!(m.noun_plural("day"), "days");
+        assert_eq!(m.noun_plural("box"), "boxes");
+        assert_eq!(m.noun_plural("virus"), "viruses");
+        assert_eq!(m.noun_plural("lens"), "lenses");
+        assert_eq!(m.noun_plural("movie"), "movies");
+        assert!(m.is_plural_noun("children"));
+        assert!(!m.is_plural_noun("child"));
+    }
+
+    #[test]
+    fn test_adjective_degrees() {
+        let m = Morphology::new();
+        assert_eq!(m.adjective_lemma("better"), ("good".to_string(), Degree::Comparative));
+        assert_eq!(m.adjective_lemma("biggest"), ("big".to_string(), Degree::Superlative));
+        assert_eq!(m.adjective_lemma("happier"), ("happy".to_string(), Degree::Comparative));
+        assert_eq!(m.adjective_lemma("nicest"), ("nice".to_string(), Degree::Superlative));
+        // Only known adjective bases are stripped
+        assert_eq!(m.adjective_lemma("number"), ("number".to_string(), Degree::Positive));
+        assert_eq!(m.adjective_lemma("owner"), ("owner".to_string(), Degree::Positive));
+
+        let known = ["tall", "nice"];
+        let (lemma, _) = m.adjective_lemma_with("taller", |w| known.contains(&w));
+        assert_eq!(lemma, "tall");
+        // Stems are cut at character boundaries
+        let (lemma, _) = m.adjective_lemma_with("aŝŝer", |w| w == "aŝ");
+        assert_eq!(lemma, "aŝ");
+
+        assert_eq!(m.comparative("big"), "bigger");
+        assert_eq!(m.superlative("happy"), "happiest");
+        assert_eq!(m.comparative("nice"), "nicer");
+        assert_eq!(m.comparative("good"), "better");
+        assert_eq!(m.superlative("beautiful"), "most beautiful");
+    }
+
+    #[test]
+    fn test_adverbs() {
+        let m = Morphology::new();
+        assert_eq!(m.adverb_lemma("quickly").as_deref(), Some("quick"));
+        assert_eq!(m.adverb_lemma("happily").as_deref(), Some("happy"));
+        assert_eq!(m.adverb_lemma("gently").as_deref(), Some("gentle"));
+        assert_eq!(m.adverb_lemma("basically").as_deref(), Some("basic"));
+        assert_eq!(m.adverb_lemma("well").as_deref(), Some("good"));
+        assert_eq!(m.adverb_from_adjective("happy"), "happily");
+        assert_eq!(m.adverb_from_adjective("basic"), "basically");
+        assert_eq!(m.adverb_from_adjective("gentle"), "gently");
+    }
+}
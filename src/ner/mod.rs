@@ -0,0 +1,576 @@
+//! # Named-Entity Recognition
+//!
+//! Rule- and gazetteer-based recognition of people, organisations, places,
+//! dates and products. Evidence, strongest first:
+//!
+//! - dates: month and weekday names with day numbers and years
+//! - titles before a name ("Dr. Smith", "Mrs Jones") → person
+//! - organisation suffixes ("Avermex Inc.", "Acme Ltd") → organisation
+//! - the gazetteer in `gazetteer.tsv` plus names added at runtime
+//! - `SemanticDB` nouns inside a capitalised name: "Central Park" is a place,
+//!   "President Lincoln" a person
+//! - a run of capitalised words; a known word opening a sentence ("Tell",
+//!   "Yesterday") is left out
+//!
+//! Entities are reported with token spans over `EnglishGrammar::tokenize`,
+//! so they line up with the tokens of `ProcessedSentence`.
+
+use std::collections::HashMap;
+
+use crate::dictionary::EnglishDictionary;
+use crate::grammar::EnglishGrammar;
+use crate::lexicon::Lexicon;
+use crate::phf;
+use crate::semantic::SemanticDB;
+use crate::temporal::Weekday;
+use crate::temporal::names::{MONTHS, day_number, month_number, numeric_date, year_number};
+use crate::verbs::VerbDatabase;
+
+include!(concat!(env!("OUT_DIR"), "/gazetteer_tables.rs"));
+
+/// Titles that introduce a person's name
+const TITLES: &[&str] = &[
+    "mr", "mrs", "ms", "mx", "dr", "prof", "sir", "dame", "lord", "lady", "rev", "fr", "sr", "jr",
+];
+
+/// Suffixes that close an organisation's name
+const ORGANIZATION_SUFFIXES: &[&str] = &[
+    "inc", "ltd", "llc", "corp", "co", "plc", "gmbh", "ag", "sa", "lp", "llp",
+];
+
+/// Nouns that make a capitalised name an organisation
+const ORGANIZATION_WORDS: &[&str] = &[
+    "university", "college", "institute", "school", "academy", "bank", "company",
+    "corporation", "group", "foundation", "association", "agency", "ministry",
+    "department", "division", "council", "committee", "society", "club", "party",
+    "union", "federation", "laboratories", "labs", "systems", "technologies",
+    "industries", "research", "press", "airlines", "hospital",
+];
+
+/// Small words allowed inside a name ("Bank of America")
+const CONNECTORS: &[&str] = &["of", "the", "and", "&", "de", "del", "la", "von", "van"];
+
+/// Prepositions that place the following name
+const LOCATIVES: &[&str] = &["in", "at", "from", "to", "near", "into", "across", "around", "visit", "visited"];
+
+/// Longest gazetteer name in words
+const MAX_NAME_WORDS: usize = 4;
+
+/// Type of a named entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntityType {
+    Person,
+    Organization,
+    Place,
+    Date,
+    Product,
+    /// A proper name of unknown type
+    Other,
+}
+
+impl EntityType {
+    /// Lowercase name
+    pub fn name(&self) -> &'static str {
+        match self {
+            EntityType::Person => "person",
+            EntityType::Organization => "organization",
+            EntityType::Place => "place",
+            EntityType::Date => "date",
+            EntityType::Product => "product",
+            EntityType::Other => "other",
+        }
+    }
+
+    /// Parse a lowercase name as written in `gazetteer.tsv`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "person" => Some(EntityType::Person),
+            "organization" => Some(EntityType::Organization),
+            "place" => Some(EntityType::Place),
+            "date" => Some(EntityType::Date),
+            "product" => Some(EntityType::Product),
+            "other" => Some(EntityType::Other),
+            _ => None,
+        }
+    }
+}
+
+/// A recognised entity
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entity {
+    /// Text as written ("Dr Smith")
+    pub text: String,
+    /// Each token as written, one per index of `start..end` ("U.", "S.")
+    pub tokens: Vec<String>,
+    /// Entity type
+    pub kind: EntityType,
+    /// First token index
+    pub start: usize,
+    /// One past the last token index
+    pub end: usize,
+    /// Confidence of the type (0.0 - 1.0)
+    pub confidence: f64,
+}
+
+impl Entity {
+    /// Check if a token index lies inside the entity
+    pub fn contains(&self, position: usize) -> bool {
+        (self.start..self.end).contains(&position)
+    }
+}
+
+/// Known names and their types
+#[derive(Debug, Clone, Default)]
+pub struct Gazetteer {
+    /// Consult the built-in list
+    builtin: bool,
+    /// Names added at runtime, lowercased (take precedence over built-in ones)
+    names: HashMap<String, EntityType>,
+}
+
+impl Gazetteer {
+    /// Empty gazetteer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gazetteer with the built-in names
+    pub fn with_builtin() -> Self {
+        Self { builtin: true, names: HashMap::new() }
+    }
+
+    /// Type of a name ("new york" or "New York")
+    pub fn get(&self, name: &str) -> Option<EntityType> {
+        let name = name.to_lowercase();
+        self.names.get(&name).copied()
+            .or_else(|| if self.builtin { builtin_name(&name).map(|(_, kind, _)| kind) } else { None })
+    }
+
+    /// Check if a built-in name carries a tag ("male", "female")
+    pub fn has_tag(&self, name: &str, tag: &str) -> bool {
+        self.builtin && builtin_name(&name.to_lowercase()).is_some_and(|(.., t)| t == Some(tag))
+    }
+
+    /// Add a name, replacing any earlier type
+    pub fn add(&mut self, name: &str, kind: EntityType) {
+        self.names.insert(name.to_lowercase(), kind);
+    }
+
+    /// Number of known names
+    pub fn len(&self) -> usize {
+        if self.builtin {
+            NAMES.len() + self.names.keys().filter(|n| builtin_name(n).is_none()).count()
+        } else {
+            self.names.len()
+        }
+    }
+
+    /// Check if empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Built-in name with its type and tag ("male", "female" for given names)
+fn builtin_name(name: &str) -> Option<(&'static str, EntityType, Option<&'static str>)> {
+    phf::index(name, &NAMES_DISPLACEMENTS, &NAMES_SLOTS)
+        .map(|i| NAMES[i])
+        .filter(|(n, ..)| *n == name)
+}
+
+/// A token with its original spelling
+#[derive(Debug, Clone)]
+struct CasedToken {
+    /// Original spelling (lowercase for expanded contractions)
+    text: String,
+    /// Lowercase form, as `EnglishGrammar::tokenize` gives it
+    lower: String,
+    /// First word of a sentence
+    initial: bool,
+    /// Written right after the previous token, as in "U.S."
+    joined: bool,
+}
+
+impl CasedToken {
+    /// Starts with a capital, or has one inside ("iPhone")
+    fn capitalised(&self) -> bool {
+        self.text.starts_with(char::is_alphanumeric)
+            && self.text.chars().any(|c| c.is_uppercase()) && self.text != "I"
+    }
+
+    /// Lowercase start with a capital inside ("iPhone", "macOS")
+    fn camel_case(&self) -> bool {
+        self.text.chars().next().is_some_and(|c| c.is_lowercase())
+            && self.text.chars().skip(1).any(|c| c.is_uppercase())
+    }
+
+    fn is_number(&self) -> bool {
+        !self.text.is_empty() && self.text.chars().all(|c| c.is_ascii_digit())
+    }
+}
+
+/// Recognises named entities with a grammar, verbs, dictionary, semantic lexicon and gazetteer
+pub struct EntityRecognizer<'a> {
+    grammar: &'a EnglishGrammar,
+    verbs: &'a VerbDatabase,
+    dictionary: &'a EnglishDictionary,
+    semantics: &'a SemanticDB,
+    gazetteer: &'a Gazetteer,
+}
+
+impl<'a> EntityRecognizer<'a> {
+    pub fn new(
+        grammar: &'a EnglishGrammar,
+        verbs: &'a VerbDatabase,
+        dictionary: &'a EnglishDictionary,
+        semantics: &'a SemanticDB,
+        gazetteer: &'a Gazetteer,
+    ) -> Self {
+        Self { grammar, verbs, dictionary, semantics, gazetteer }
+    }
+
+    /// Entities of a sentence, in order
+    pub fn recognize(&self, sentence: &str) -> Vec<Entity> {
+        let tokens = self.cased_tokens(sentence);
+        let mut entities = Vec::new();
+        let mut i = 0;
+        while i < tokens.len() {
+            if let Some(end) = self.date_at(&tokens, i) {
+                entities.push(entity(&tokens, i, end, EntityType::Date, 0.9));
+                i = end;
+                continue;
+            }
+            if TITLES.contains(&tokens[i].lower.as_str()) && tokens[i].capitalised() {
+                let end = self.name_end(&tokens, i + 1);
+                if end > i + 1 {
+                    entities.push(entity(&tokens, i, end, EntityType::Person, 0.9));
+                    i = end;
+                    continue;
+                }
+            }
+            if tokens[i].capitalised() && !(tokens[i].initial && self.is_function_word(&tokens[i].lower)) {
+                let mut end = self.name_end(&tokens, i);
+                // A title inside the run opens a name of its own ("Ask Mr. O'Brien")
+                if let Some(title) = (i + 1..end).find(|&k| TITLES.contains(&tokens[k].lower.as_str()) && k + 1 < end) {
+                    end = title;
+                }
+                let classified = self.classify(&tokens, i, end);
+                // A verb or adverb opening a sentence is not part of the name
+                // that follows it ("Tell Zorbak", "Yesterday Bob")
+                let weak = classified.is_none_or(|(_, _, confidence)| confidence < 0.75);
+                if tokens[i].initial && end > i + 1 && weak && self.is_known(&tokens[i].lower) {
+                    i += 1;
+                    continue;
+                }
+                if let Some((kind, end, confidence)) = classified {
+                    entities.push(entity(&tokens, i, end, kind, confidence));
+                    i = end;
+                    continue;
+                }
+                i = end.max(i + 1);
+                continue;
+            }
+            i += 1;
+        }
+        entities
+    }
+
+    /// Tokens of `EnglishGrammar::tokenize` with their original spelling
+    fn cased_tokens(&self, sentence: &str) -> Vec<CasedToken> {
+        let mut tokens: Vec<CasedToken> = Vec::new();
+        let mut terminated = true;
+        let mut joined = false;
+        let mut word = String::new();
+        let flush = |word: &mut String, terminated: &mut bool, joined: bool, tokens: &mut Vec<CasedToken>| {
+            if word.is_empty() {
+                return;
+            }
+            // A period after a title or an initial does not end the sentence
+            let after_title = tokens.last().is_some_and(|t| TITLES.contains(&t.lower.as_str()) || t.text.ends_with('.'));
+            let initial = *terminated && !after_title;
+            let expanded = self.grammar.tokenize(word);
+            if expanded.len() == 1 {
+                tokens.push(CasedToken { text: word.clone(), lower: expanded[0].clone(), initial, joined });
+            } else {
+                for (k, lower) in expanded.into_iter().enumerate() {
+                    tokens.push(CasedToken { text: lower.clone(), lower, initial: initial && k == 0, joined: joined && k == 0 });
+                }
+            }
+            word.clear();
+            *terminated = false;
+        };
+        for c in sentence.chars() {
+            if c == '.' && is_initial_letter(&word, joined) {
+                // "U.S.", "John F. Kennedy": the period stays with the letter
+                flush(&mut word, &mut terminated, joined, &mut tokens);
+                if let Some(last) = tokens.last_mut() {
+                    last.text.push('.');
+                }
+                joined = true;
+            } else if c.is_whitespace() || matches!(c, ',' | '.' | '!' | '?') {
+                flush(&mut word, &mut terminated, joined, &mut tokens);
+                if matches!(c, '.' | '!' | '?') {
+                    terminated = true;
+                }
+                joined = false;
+            } else {
+                word.push(c);
+            }
+        }
+        flush(&mut word, &mut terminated, joined, &mut tokens);
+        tokens
+    }
+
+    /// One past the last token of the capitalised run starting at `start`
+    fn name_end(&self, tokens: &[CasedToken], start: usize) -> usize {
+        let mut end = start;
+        while end < tokens.len() {
+            let token = &tokens[end];
+            if (end > start && token.initial) || token.text.starts_with(['(', '"', '[']) {
+                break;
+            }
+            let suffix = end > start && ORGANIZATION_SUFFIXES.contains(&token.lower.as_str());
+            let connector = end > start
+                && CONNECTORS.contains(&token.lower.as_str())
+                && tokens.get(end + 1).is_some_and(|next| next.capitalised() && !next.initial);
+            if !(token.capitalised() || suffix || connector) {
+                break;
+            }
+            end += 1;
+        }
+        end
+    }
+
+    /// Type of the capitalised run `start..end`, possibly extended by a
+    /// version number ("Windows 11")
+    fn classify(&self, tokens: &[CasedToken], start: usize, end: usize) -> Option<(EntityType, usize, f64)> {
+        if end <= start {
+            return None;
+        }
+        let run = &tokens[start..end];
+        let name = run.iter().map(|t| t.lower.as_str()).collect::<Vec<_>>().join(" ");
+        let known = |t: &CasedToken| self.is_known(&t.lower);
+        let initial = run[0].initial;
+
+        // A lone known word opening a sentence is just capitalised
+        if initial && run.len() == 1 && known(&run[0]) {
+            return None;
+        }
+
+        let versioned = tokens.get(end).is_some_and(|t| t.is_number() && !t.initial);
+        let written = entity(tokens, start, end, EntityType::Other, 0.0).text.to_lowercase();
+        if let Some(kind) = self.gazetteer.get(&name).or_else(|| self.gazetteer.get(&written)) {
+            if kind == EntityType::Product && versioned {
+                return Some((kind, end + 1, 0.9));
+            }
+            return Some((kind, end, 0.9));
+        }
+        if ORGANIZATION_SUFFIXES.contains(&run[run.len() - 1].lower.as_str()) && run.len() > 1 {
+            return Some((EntityType::Organization, end, 0.9));
+        }
+        if run.len() > 1 {
+            let organization = run.iter().any(|t| ORGANIZATION_WORDS.contains(&t.lower.as_str())
+                || self.semantics.has_tag(&t.lower, "organization")
+                || self.semantics.has_tag(&t.lower, "institution"));
+            if organization {
+                return Some((EntityType::Organization, end, 0.8));
+            }
+            let category = |t: &CasedToken| self.semantics.get_category(&t.lower).map(|c| c.name());
+            if category(&run[0]) == Some("person") && !known(&run[1]) {
+                return Some((EntityType::Person, end, 0.8));
+            }
+            if category(&run[0]) == Some("place") || category(&run[run.len() - 1]) == Some("place") {
+                return Some((EntityType::Place, end, 0.8));
+            }
+        }
+        // Longest known name at the start of the run ("Google Cloud")
+        let prefix = (1..run.len().min(MAX_NAME_WORDS)).rev().find_map(|n| {
+            let prefix = run[..n].iter().map(|t| t.lower.as_str()).collect::<Vec<_>>().join(" ");
+            self.gazetteer.get(&prefix)
+        });
+        if let Some(kind) = prefix {
+            return Some((kind, end, 0.75));
+        }
+        if run.iter().any(CasedToken::camel_case) || (versioned && !run.iter().all(&known)) {
+            let end = if versioned { end + 1 } else { end };
+            return Some((EntityType::Product, end, 0.7));
+        }
+
+        // An unknown name: a run of known words opening a sentence is not one
+        if run.iter().all(&known) && (initial || run.len() == 1) {
+            return None;
+        }
+        let placed = start > 0 && LOCATIVES.contains(&tokens[start - 1].lower.as_str());
+        let kind = if placed { EntityType::Place } else { EntityType::Other };
+        Some((kind, end, 0.6))
+    }
+
+    /// One past the last token of a date starting at `i`
+    fn date_at(&self, tokens: &[CasedToken], i: usize) -> Option<usize> {
+        if Weekday::ALL.iter().any(|d| d.name() == tokens[i].lower) {
+            let after = (i + 1 < tokens.len()).then(|| self.month_date(tokens, i + 1)).flatten();
+            return Some(after.unwrap_or(i + 1));
+        }
+        if numeric_date(&tokens[i].lower).is_some() {
+            return Some(i + 1);
+        }
+        if let Some(after) = self.month_date(tokens, i) {
+            return Some(after);
+        }
+        // "5th of May", "5 March 2024"
+        if day_number(&tokens[i].lower).is_some() {
+            let month = if tokens.get(i + 1).is_some_and(|t| t.lower == "of") { i + 2 } else { i + 1 };
+            if tokens.get(month).is_some_and(|t| month_number(&t.lower).is_some() && t.capitalised()) {
+                let mut end = month + 1;
+                if tokens.get(end).is_some_and(|t| year_number(&t.lower).is_some()) {
+                    end += 1;
+                }
+                return Some(end);
+            }
+        }
+        None
+    }
+
+    /// One past a date opened by a month name ("March 5 2024", "June")
+    fn month_date(&self, tokens: &[CasedToken], i: usize) -> Option<usize> {
+        let token = &tokens[i];
+        month_number(&token.lower)?;
+        let full = MONTHS[..12].iter().any(|(m, _)| *m == token.lower);
+        let mut end = i + 1;
+        if tokens.get(end).is_some_and(|t| day_number(&t.lower).is_some()) {
+            end += 1;
+        }
+        if tokens.get(end).is_some_and(|t| year_number(&t.lower).is_some()) {
+            end += 1;
+        }
+        // "may" and "march" are also verbs, abbreviations also words
+        let ambiguous = !full || token.lower == "may" || token.lower == "march";
+        let accepted = if ambiguous { token.capitalised() && end > i + 1 } else { true };
+        accepted.then_some(end)
+    }
+
+    /// A dictionary word or plural, verb form or function word
+    fn is_known(&self, word: &str) -> bool {
+        self.dictionary.is_valid(word) || self.dictionary.is_valid(&self.grammar.noun_lemma(word))
+            || self.verbs.is_verb(word) || self.is_function_word(word)
+    }
+
+    /// Articles, pronouns, prepositions, conjunctions and auxiliaries
+    fn is_function_word(&self, word: &str) -> bool {
+        self.grammar.is_article(word) || self.grammar.is_pronoun(word) || self.grammar.is_preposition(word)
+            || self.grammar.is_conjunction(word) || self.grammar.is_auxiliary(word)
+    }
+}
+
+impl Lexicon {
+    /// Named entities of a sentence
+    pub fn recognize_entities(&self, sentence: &str) -> Vec<Entity> {
+        let verbs = self.verbs();
+        let dictionary = self.dictionary();
+        let semantics = self.semantics();
+        let gazetteer = self.gazetteer();
+        EntityRecognizer::new(self.grammar(), &verbs, &dictionary, &semantics, &gazetteer).recognize(sentence)
+    }
+}
+
+fn entity(tokens: &[CasedToken], start: usize, end: usize, kind: EntityType, confidence: f64) -> Entity {
+    let mut text = String::new();
+    for (k, token) in tokens[start..end].iter().enumerate() {
+        if k > 0 && !token.joined {
+            text.push(' ');
+        }
+        text.push_str(&token.text);
+    }
+    Entity {
+        text,
+        tokens: tokens[start..end].iter().map(|t| t.text.clone()).collect(),
+        kind,
+        start,
+        end,
+        confidence,
+    }
+}
+
+/// A single capital before a period: an initial ("F.") or part of an
+/// initialism ("U.S."); a lone "I" only inside an initialism
+fn is_initial_letter(word: &str, joined: bool) -> bool {
+    let mut chars = word.chars();
+    matches!((chars.next(), chars.next()), (Some(c), None) if c.is_uppercase() && (c != 'I' || joined))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recognize(sentence: &str) -> Vec<(String, EntityType)> {
+        let grammar = EnglishGrammar::new();
+        let verbs = VerbDatabase::with_builtin();
+        let dictionary = EnglishDictionary::new();
+        let semantics = SemanticDB::with_builtin();
+        let gazetteer = Gazetteer::with_builtin();
+        EntityRecognizer::new(&grammar, &verbs, &dictionary, &semantics, &gazetteer)
+            .recognize(sentence)
+            .into_iter()
+            .map(|e| (e.text, e.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_rules() {
+        assert_eq!(recognize("I met Dr. Smith yesterday"), vec![("Dr Smith".to_string(), EntityType::Person)]);
+        assert_eq!(recognize("She works for Avermex Research Ltd"),
+            vec![("Avermex Research Ltd".to_string(), EntityType::Organization)]);
+        assert_eq!(recognize("We walked through Central Park"), vec![("Central Park".to_string(), EntityType::Place)]);
+        assert_eq!(recognize("They moved to Zorbania"), vec![("Zorbania".to_string(), EntityType::Place)]);
+        assert_eq!(recognize("He bought an iPhone 15"), vec![("iPhone 15".to_string(), EntityType::Product)]);
+        assert_eq!(recognize("The meeting is on March 5, 2024"), vec![("March 5 2024".to_string(), EntityType::Date)]);
+        assert!(recognize("You may march on").is_empty());
+        assert!(recognize("The cat sat on the mat").is_empty());
+    }
+
+    #[test]
+    fn test_sentence_initial_names() {
+        assert_eq!(recognize("Ask Mr. O'Brien"), vec![("Mr O'Brien".to_string(), EntityType::Person)]);
+        assert_eq!(recognize("Tell Zorbak hello"), vec![("Zorbak".to_string(), EntityType::Other)]);
+        assert_eq!(recognize("Contact Jane Doe (CEO) today")[0].0, "Jane Doe");
+        assert_eq!(recognize("Yesterday Bob called")[0].0, "Bob");
+        assert_eq!(recognize("Avermex builds tools"), vec![("Avermex".to_string(), EntityType::Other)]);
+        assert_eq!(recognize("Troops left the U.S. in May"), vec![("U.S.".to_string(), EntityType::Place)]);
+        assert_eq!(recognize("Central Park is closed"), vec![("Central Park".to_string(), EntityType::Place)]);
+    }
+
+    #[test]
+    fn test_gazetteer() {
+        assert_eq!(recognize("John Smith flew from New York to Paris"), vec![
+            ("John Smith".to_string(), EntityType::Person),
+            ("New York".to_string(), EntityType::Place),
+            ("Paris".to_string(), EntityType::Place),
+        ]);
+        assert_eq!(recognize("I ate an apple at Apple"), vec![("Apple".to_string(), EntityType::Organization)]);
+
+        let mut gazetteer = Gazetteer::new();
+        assert!(gazetteer.is_empty());
+        gazetteer.add("Avermex", EntityType::Organization);
+        assert_eq!(gazetteer.get("avermex"), Some(EntityType::Organization));
+        assert!(Gazetteer::with_builtin().has_tag("Mary", "female"));
+        assert!(!Gazetteer::with_builtin().has_tag("John", "female"));
+        assert!(Gazetteer::with_builtin().len() > 400);
+    }
+
+    #[test]
+    fn test_spans_follow_tokenize() {
+        let grammar = EnglishGrammar::new();
+        let verbs = VerbDatabase::with_builtin();
+        let dictionary = EnglishDictionary::new();
+        let semantics = SemanticDB::with_builtin();
+        let gazetteer = Gazetteer::with_builtin();
+        let sentence = "I don't think Mrs. Jones lives in Berlin.";
+        let tokens = grammar.tokenize(sentence);
+        let entities = EntityRecognizer::new(&grammar, &verbs, &dictionary, &semantics, &gazetteer).recognize(sentence);
+        assert_eq!(entities.len(), 2);
+        assert_eq!(tokens[entities[0].start..entities[0].end], ["mrs", "jones"]);
+        assert_eq!(entities[0].tokens, ["Mrs", "Jones"]);
+        assert_eq!(tokens[entities[1].start], "berlin");
+        assert!(entities[1].contains(entities[1].start));
+    }
+}
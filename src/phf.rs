@@ -0,0 +1,37 @@
+//! Perfect hashing for the static tables generated by `build.rs`
+//!
+//! Hash-and-displace: a key's bucket is `hash(key, 0) % buckets`, and its
+//! slot is `hash(key, displacement[bucket]) % slots`. The build script
+//! picks displacements so that every key gets its own slot; a lookup is
+//! two hashes and one string comparison. This file is also compiled into
+//! the build script so both sides hash identically.
+
+/// Slot value for "no key"
+pub const EMPTY: u32 = u32::MAX;
+
+/// Seeded FNV-1a with a final avalanche step
+pub fn hash(key: &str, seed: u32) -> u32 {
+    let mut h = 0x811c_9dc5_u32 ^ seed.wrapping_mul(0x9e37_79b9);
+    for byte in key.bytes() {
+        h ^= byte as u32;
+        h = h.wrapping_mul(0x0100_0193);
+    }
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^ (h >> 16)
+}
+
+/// Candidate index of `key` in a table; the caller compares the key stored there
+pub fn index(key: &str, displacements: &[u32], slots: &[u32]) -> Option<usize> {
+    if displacements.is_empty() || slots.is_empty() {
+        return None;
+    }
+    let bucket = hash(key, 0) as usize % displacements.len();
+    let slot = hash(key, displacements[bucket]) as usize % slots.len();
+    match slots[slot] {
+        EMPTY => None,
+        i => Some(i as usize),
+    }
+}
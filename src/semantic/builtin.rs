@@ -0,0 +1,35 @@
+//! Built-in noun lexicon
+//!
+//! `build.rs` compiles `nouns.tsv` into the static table included below,
+//! rows of (word, category spec, subcategory, tags), with a perfect-hash
+//! index of the words. Entries are built the first time they are asked for.
+
+use std::sync::OnceLock;
+
+use super::{SemanticCategory, SemanticEntry};
+use crate::phf;
+
+include!(concat!(env!("OUT_DIR"), "/semantic_tables.rs"));
+
+/// Number of built-in nouns
+pub(super) const LEN: usize = NOUNS.len();
+
+static ENTRIES: [OnceLock<SemanticEntry>; LEN] = [const { OnceLock::new() }; LEN];
+
+/// Index of a lowercase word in `NOUNS`
+pub(super) fn index(word: &str) -> Option<usize> {
+    phf::index(word, &NOUNS_DISPLACEMENTS, &NOUNS_SLOTS).filter(|&i| NOUNS[i].0 == word)
+}
+
+/// Entry of a noun, built on first use
+pub(super) fn entry(index: usize) -> &'static SemanticEntry {
+    ENTRIES[index].get_or_init(|| {
+        let (word, category, subcategory, tags) = NOUNS[index];
+        SemanticEntry {
+            word: word.to_string(),
+            category: SemanticCategory::parse(category).expect("category checked by build.rs"),
+            subcategory: subcategory.map(str::to_string),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        }
+    })
+}
@@ -1,12 +1,22 @@
 //! # Semantic Database Module
 //!
 //! Semantic categorization and context analysis for English.
+//!
+//! `SemanticDB::with_builtin()` knows about 1,800 common nouns (places,
+//! people, objects, emotions, time expressions, quantities and concepts),
+//! compiled from `nouns.tsv` into static tables by `build.rs`. Words added
+//! with `add`/`add_entry` are kept on top of the built-in lexicon.
+
+mod builtin;
 
 use std::collections::HashMap;
 
 /// Semantic database
 #[derive(Debug, Clone)]
 pub struct SemanticDB {
+    /// Consult the built-in lexicon
+    builtin: bool,
+    /// Words added at runtime (take precedence over built-in ones)
     words: HashMap<String, SemanticEntry>,
 }
 
@@ -19,6 +29,13 @@ pub struct SemanticEntry {
     pub tags: Vec<String>,
 }
 
+impl SemanticEntry {
+    /// Check if the entry carries a tag ("human", "edible", "location"...)
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
 /// Semantic category
 #[derive(Debug, Clone, PartialEq)]
 pub enum SemanticCategory {
@@ -34,6 +51,60 @@ pub enum SemanticCategory {
     Unknown,
 }
 
+impl SemanticCategory {
+    /// Category name without its details ("place", "person"...)
+    pub fn name(&self) -> &'static str {
+        match self {
+            SemanticCategory::Place { .. } => "place",
+            SemanticCategory::Person { .. } => "person",
+            SemanticCategory::Object { .. } => "object",
+            SemanticCategory::Emotion { .. } => "emotion",
+            SemanticCategory::Concept { .. } => "concept",
+            SemanticCategory::Action { .. } => "action",
+            SemanticCategory::Time { .. } => "time",
+            SemanticCategory::Quantity => "quantity",
+            SemanticCategory::Quality => "quality",
+            SemanticCategory::Unknown => "unknown",
+        }
+    }
+
+    /// Detail of the category: place type, role, object type, valence,
+    /// domain, action type or time type
+    pub fn detail(&self) -> Option<&str> {
+        match self {
+            SemanticCategory::Place { place_type } => Some(place_type),
+            SemanticCategory::Person { role } => role.as_deref(),
+            SemanticCategory::Object { object_type } => Some(object_type),
+            SemanticCategory::Emotion { valence } => Some(valence.name()),
+            SemanticCategory::Concept { domain } => domain.as_deref(),
+            SemanticCategory::Action { action_type } => Some(action_type),
+            SemanticCategory::Time { time_type } => Some(time_type),
+            SemanticCategory::Quantity | SemanticCategory::Quality | SemanticCategory::Unknown => None,
+        }
+    }
+
+    /// Parse "name" or "name:detail" as written in `nouns.tsv`
+    pub fn parse(spec: &str) -> Option<Self> {
+        let (name, detail) = match spec.split_once(':') {
+            Some((name, detail)) => (name, Some(detail.to_string())),
+            None => (spec, None),
+        };
+        Some(match name {
+            "place" => SemanticCategory::Place { place_type: detail? },
+            "person" => SemanticCategory::Person { role: detail },
+            "object" => SemanticCategory::Object { object_type: detail? },
+            "emotion" => SemanticCategory::Emotion { valence: Valence::parse(&detail?)? },
+            "concept" => SemanticCategory::Concept { domain: detail },
+            "action" => SemanticCategory::Action { action_type: detail? },
+            "time" => SemanticCategory::Time { time_type: detail? },
+            "quantity" => SemanticCategory::Quantity,
+            "quality" => SemanticCategory::Quality,
+            "unknown" => SemanticCategory::Unknown,
+            _ => return None,
+        })
+    }
+}
+
 /// Emotional valence
 #[derive(Debug, Clone, PartialEq)]
 pub enum Valence {
@@ -42,6 +113,26 @@ pub enum Valence {
     Neutral,
 }
 
+impl Valence {
+    /// Lowercase name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Valence::Positive => "positive",
+            Valence::Negative => "negative",
+            Valence::Neutral => "neutral",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "positive" => Some(Valence::Positive),
+            "negative" => Some(Valence::Negative),
+            "neutral" => Some(Valence::Neutral),
+            _ => None,
+        }
+    }
+}
+
 impl Default for SemanticDB {
     fn default() -> Self {
         Self::new()
@@ -51,24 +142,64 @@ impl Default for SemanticDB {
 impl SemanticDB {
     pub fn new() -> Self {
         Self {
+            builtin: false,
             words: HashMap::new(),
         }
     }
 
+    /// Create database with the built-in noun lexicon
+    pub fn with_builtin() -> Self {
+        Self {
+            builtin: true,
+            words: HashMap::new(),
+        }
+    }
+
+    /// Get the entry of a word
+    pub fn get(&self, word: &str) -> Option<&SemanticEntry> {
+        let w = word.to_lowercase();
+        self.words.get(&w).or_else(|| if self.builtin { builtin::index(&w).map(builtin::entry) } else { None })
+    }
+
     /// Get semantic category for a word
     pub fn get_category(&self, word: &str) -> Option<&SemanticCategory> {
-        self.words.get(&word.to_lowercase()).map(|e| &e.category)
+        self.get(word).map(|e| &e.category)
+    }
+
+    /// Check if a word carries a tag
+    pub fn has_tag(&self, word: &str, tag: &str) -> bool {
+        self.get(word).is_some_and(|e| e.has_tag(tag))
     }
 
     /// Add word with semantic info
     pub fn add(&mut self, word: &str, category: SemanticCategory) {
-        self.words.insert(word.to_lowercase(), SemanticEntry {
+        self.add_entry(SemanticEntry {
             word: word.to_lowercase(),
             category,
             subcategory: None,
             tags: vec![],
         });
     }
+
+    /// Add a full entry, replacing any entry of the same word
+    pub fn add_entry(&mut self, mut entry: SemanticEntry) {
+        entry.word = entry.word.to_lowercase();
+        self.words.insert(entry.word.clone(), entry);
+    }
+
+    /// Number of known words
+    pub fn len(&self) -> usize {
+        if self.builtin {
+            builtin::LEN + self.words.keys().filter(|w| builtin::index(w).is_none()).count()
+        } else {
+            self.words.len()
+        }
+    }
+
+    /// Check if empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 #[cfg(test)]
@@ -81,4 +212,33 @@ mod tests {
         db.add("happiness", SemanticCategory::Emotion { valence: Valence::Positive });
         assert!(db.get_category("happiness").is_some());
     }
+
+    #[test]
+    fn test_builtin_lexicon() {
+        let db = SemanticDB::with_builtin();
+        assert!(db.len() > 1500);
+        assert_eq!(db.get_category("City"), Some(&SemanticCategory::Place { place_type: "settlement".to_string() }));
+        assert_eq!(db.get_category("doctor").map(|c| c.detail()), Some(Some("profession")));
+        assert_eq!(db.get("doctor").and_then(|e| e.subcategory.as_deref()), Some("medical"));
+        assert!(db.has_tag("pizza", "edible"));
+        assert!(db.has_tag("teacher", "human"));
+        assert_eq!(db.get_category("fear"), Some(&SemanticCategory::Emotion { valence: Valence::Negative }));
+        assert_eq!(db.get_category("monday").map(|c| c.name()), Some("time"));
+        assert_eq!(db.get_category("dozen"), Some(&SemanticCategory::Quantity));
+
+        // Every built-in entry builds
+        for i in 0..builtin::LEN {
+            assert_eq!(builtin::index(&builtin::entry(i).word), Some(i));
+        }
+    }
+
+    #[test]
+    fn test_runtime_words_override_builtin() {
+        let mut db = SemanticDB::with_builtin();
+        let len = db.len();
+        db.add("kubernetes", SemanticCategory::Concept { domain: Some("technology".to_string()) });
+        db.add("apple", SemanticCategory::Concept { domain: Some("technology".to_string()) });
+        assert_eq!(db.len(), len + 1);
+        assert_eq!(db.get_category("apple").map(|c| c.name()), Some("concept"));
+    }
 }
@@ -0,0 +1,410 @@
+//! # Sentiment Analysis
+//!
+//! Deterministic, lexicon-based polarity. Each sentiment-bearing word has a
+//! prior score in [-1, 1] from one of three sources: the word list in
+//! `words.tsv`, the group of an Emotion verb ("love", "hate", "fear"), or
+//! the valence of an emotion noun in the semantic lexicon.
+//!
+//! The prior is then adjusted by its context:
+//!
+//! - **negation**: a negator up to three tokens before, in the same clause,
+//!   flips and dampens it ("don't love" → mildly negative)
+//! - **intensifiers**: "really", "very" strengthen it, "slightly" weakens it
+//! - **contrast**: words before "but"/"however" count half, words after it
+//!   half again as much; a clause opened by "although" counts half
+//!
+//! A sentence's polarity is the sum of its word scores normalised into
+//! (-1, 1); its intensity is the same over absolute scores, so a mixed
+//! sentence is intense but not polar. A document averages the sentences
+//! that carry any sentiment.
+//!
+//! ```
+//! use nl_sre_english::SemanticDisambiguator;
+//! use nl_sre_english::semantic::Valence;
+//!
+//! let dis = SemanticDisambiguator::new();
+//! let sentiment = dis.sentiment("The food was good, but the service was really terrible.");
+//! assert_eq!(sentiment.valence(), Valence::Negative);
+//! assert_eq!(sentiment.sentences[0].words.len(), 2);
+//! ```
+
+use crate::{DetectedAction, SemanticDisambiguator};
+use crate::semantic::{SemanticCategory, SemanticDB, Valence};
+use crate::phf;
+use crate::verbs::{FunctionalCategory, VerbGroup};
+
+include!(concat!(env!("OUT_DIR"), "/sentiment_tables.rs"));
+
+/// Words that negate what follows
+const NEGATORS: &[&str] = &[
+    "not", "no", "never", "none", "nobody", "nothing", "neither", "nor",
+    "nowhere", "without", "hardly", "barely", "scarcely", "cannot",
+];
+
+/// Words that strengthen or weaken the next sentiment word, with their factor
+const INTENSIFIERS: &[(&str, f64)] = &[
+    ("extremely", 1.8), ("absolutely", 1.7), ("incredibly", 1.7), ("totally", 1.5),
+    ("completely", 1.5), ("utterly", 1.6), ("deeply", 1.6), ("really", 1.5),
+    ("very", 1.5), ("truly", 1.4), ("so", 1.4), ("too", 1.3), ("highly", 1.4),
+    ("especially", 1.3), ("most", 1.3), ("quite", 1.2), ("pretty", 1.2),
+    ("fairly", 0.8), ("somewhat", 0.6), ("rather", 0.8), ("slightly", 0.5),
+    ("mildly", 0.6), ("little", 0.6),
+];
+
+/// Words that shift the weight onto what follows them
+const CONTRASTS: &[&str] = &["but", "however"];
+
+/// Words opening a clause that concedes a point
+const CONCESSIVES: &[&str] = &["although", "though", "despite"];
+
+/// Verbs after which "like" is a preposition ("looks like rain")
+const LINKING_VERBS: &[&str] = &["be", "look", "seem", "feel", "sound", "smell", "taste", "act"];
+
+/// Tokens before a sentiment word searched for a negator
+const NEGATION_SCOPE: usize = 3;
+
+/// Tokens before a sentiment word searched for intensifiers
+const INTENSIFIER_SCOPE: usize = 2;
+
+/// Factor applied to a negated score
+const NEGATION: f64 = -0.5;
+
+/// Weight of words before a contrast and in concessive clauses
+const BEFORE_CONTRAST: f64 = 0.5;
+
+/// Weight of words after a contrast
+const AFTER_CONTRAST: f64 = 1.5;
+
+/// Normalisation constant: `x / sqrt(x² + NORMALIZATION)`
+const NORMALIZATION: f64 = 1.0;
+
+/// Polarity beyond which a text is positive or negative
+const VALENCE_THRESHOLD: f64 = 0.1;
+
+/// Where a word's prior score comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SentimentSource {
+    /// The sentiment word list
+    Lexicon,
+    /// An Emotion verb of this group
+    EmotionVerb(VerbGroup),
+    /// An emotion noun of the semantic lexicon
+    EmotionNoun,
+}
+
+/// A word that contributed to a sentiment score
+#[derive(Debug, Clone)]
+pub struct SentimentWord {
+    /// The word as it appears (lowercased)
+    pub word: String,
+    /// Token index in its sentence
+    pub position: usize,
+    /// Source of the prior score
+    pub source: SentimentSource,
+    /// Score of the word on its own
+    pub prior: f64,
+    /// Inside the scope of a negator
+    pub negated: bool,
+    /// Product of the intensifier factors applied
+    pub intensity: f64,
+    /// Weight of its clause (contrast and concession)
+    pub weight: f64,
+    /// Final contribution
+    pub score: f64,
+    /// Negators and intensifiers that changed it
+    pub modifiers: Vec<String>,
+}
+
+/// Sentiment of one sentence
+#[derive(Debug, Clone)]
+pub struct SentenceSentiment {
+    /// Sentence text
+    pub text: String,
+    /// Polarity in (-1, 1)
+    pub polarity: f64,
+    /// Strength of the sentiment regardless of direction, in [0, 1)
+    pub intensity: f64,
+    /// Contributing words in sentence order
+    pub words: Vec<SentimentWord>,
+}
+
+impl SentenceSentiment {
+    /// Positive, negative or neutral
+    pub fn valence(&self) -> Valence {
+        valence_of(self.polarity)
+    }
+}
+
+/// Sentiment of a text
+#[derive(Debug, Clone)]
+pub struct DocumentSentiment {
+    /// Mean polarity of the sentences carrying sentiment
+    pub polarity: f64,
+    /// Mean intensity of the sentences carrying sentiment
+    pub intensity: f64,
+    /// Every sentence, in order
+    pub sentences: Vec<SentenceSentiment>,
+}
+
+impl DocumentSentiment {
+    /// Positive, negative or neutral
+    pub fn valence(&self) -> Valence {
+        valence_of(self.polarity)
+    }
+
+    /// Contributing words of all sentences
+    pub fn words(&self) -> impl Iterator<Item = &SentimentWord> {
+        self.sentences.iter().flat_map(|s| s.words.iter())
+    }
+
+    /// One line per contributing word, for logs and debugging
+    pub fn explain(&self) -> Vec<String> {
+        self.words()
+            .map(|w| {
+                let mut line = format!("{}: {:+.2}", w.word, w.score);
+                if !w.modifiers.is_empty() {
+                    line.push_str(&format!(" ({} {:+.2})", w.modifiers.join(" "), w.prior));
+                }
+                if w.weight != 1.0 {
+                    line.push_str(&format!(" x{:.1} clause", w.weight));
+                }
+                line
+            })
+            .collect()
+    }
+}
+
+fn valence_of(polarity: f64) -> Valence {
+    if polarity > VALENCE_THRESHOLD {
+        Valence::Positive
+    } else if polarity < -VALENCE_THRESHOLD {
+        Valence::Negative
+    } else {
+        Valence::Neutral
+    }
+}
+
+fn normalize(x: f64) -> f64 {
+    x / (x * x + NORMALIZATION).sqrt()
+}
+
+/// Prior score of an Emotion verb group
+fn group_prior(group: VerbGroup) -> Option<f64> {
+    match group {
+        VerbGroup::Love => Some(0.8),
+        VerbGroup::Enjoy => Some(0.6),
+        VerbGroup::Satisfy => Some(0.5),
+        VerbGroup::Hope => Some(0.3),
+        VerbGroup::Hate => Some(-0.8),
+        VerbGroup::Suffer => Some(-0.6),
+        VerbGroup::Anger => Some(-0.6),
+        VerbGroup::Fear => Some(-0.5),
+        _ => None,
+    }
+}
+
+/// Prior polarity of a word in `words.tsv`
+fn listed_polarity(word: &str) -> Option<f64> {
+    phf::index(word, &POLARITY_DISPLACEMENTS, &POLARITY_SLOTS)
+        .filter(|&i| POLARITY[i].0 == word)
+        .map(|i| POLARITY[i].1)
+}
+
+impl SemanticDisambiguator {
+    /// Sentiment of a text, sentence by sentence
+    pub fn sentiment(&self, text: &str) -> DocumentSentiment {
+        let sentences: Vec<SentenceSentiment> = self.grammar().split_sentences(text).into_iter()
+            .map(|s| self.sentence_sentiment(s))
+            .collect();
+
+        let polar: Vec<&SentenceSentiment> = sentences.iter().filter(|s| !s.words.is_empty()).collect();
+        let mean = |f: fn(&SentenceSentiment) -> f64| if polar.is_empty() {
+            0.0
+        } else {
+            polar.iter().map(|s| f(s)).sum::<f64>() / polar.len() as f64
+        };
+        DocumentSentiment {
+            polarity: mean(|s| s.polarity),
+            intensity: mean(|s| s.intensity),
+            sentences,
+        }
+    }
+
+    /// Sentiment of one sentence
+    pub fn sentence_sentiment(&self, sentence: &str) -> SentenceSentiment {
+        let tokens = self.grammar().tokenize(sentence);
+        let actions = self.detect_actions(sentence);
+
+        // Clause of every token: tokenizing the comma-separated parts gives
+        // the same tokens as tokenizing the sentence
+        let mut clause_of = Vec::with_capacity(tokens.len());
+        for (clause, part) in sentence.split([',', ';', ':']).enumerate() {
+            clause_of.extend(std::iter::repeat_n(clause, self.grammar().tokenize(part).len()));
+        }
+        clause_of.resize(tokens.len(), usize::MAX);
+
+        let weights = self.clause_weights(&tokens, &clause_of);
+        let semantics = self.semantics();
+
+        let mut words = Vec::new();
+        for (i, token) in tokens.iter().enumerate() {
+            let Some((source, prior)) = self.prior(&semantics, &tokens, i, &actions) else { continue };
+
+            let mut modifiers = Vec::new();
+            let mut intensity = 1.0;
+            let mut negations = 0;
+            for j in (i.saturating_sub(NEGATION_SCOPE)..i).rev() {
+                if clause_of[j] != clause_of[i] || self.grammar().is_conjunction(&tokens[j]) {
+                    break;
+                }
+                let word = tokens[j].as_str();
+                if NEGATORS.contains(&word) {
+                    negations += 1;
+                    modifiers.insert(0, tokens[j].clone());
+                } else if let Some(&(_, factor)) = INTENSIFIERS.iter().find(|(w, _)| *w == word) {
+                    if i - j <= INTENSIFIER_SCOPE {
+                        intensity *= factor;
+                        modifiers.insert(0, tokens[j].clone());
+                    }
+                }
+            }
+            let negated = negations % 2 == 1;
+            let weight = weights[i];
+            let score = prior * intensity * weight * if negated { NEGATION } else { 1.0 };
+            words.push(SentimentWord {
+                word: token.clone(),
+                position: i,
+                source,
+                prior,
+                negated,
+                intensity,
+                weight,
+                score,
+                modifiers,
+            });
+        }
+
+        let sum: f64 = words.iter().map(|w| w.score).sum();
+        let magnitude: f64 = words.iter().map(|w| w.score.abs()).sum();
+        SentenceSentiment {
+            text: sentence.to_string(),
+            polarity: normalize(sum),
+            intensity: normalize(magnitude),
+            words,
+        }
+    }
+
+    /// Prior score of the token at `i`, if it carries sentiment
+    fn prior(
+        &self,
+        semantics: &SemanticDB,
+        tokens: &[String],
+        i: usize,
+        actions: &[DetectedAction],
+    ) -> Option<(SentimentSource, f64)> {
+        let token = tokens[i].as_str();
+        if let Some(score) = listed_polarity(token) {
+            return Some((SentimentSource::Lexicon, score));
+        }
+        if let Some(action) = actions.iter().find(|a| a.position == i && a.category == FunctionalCategory::Emotion) {
+            // "looks like", "feel like": a preposition, not a verb of liking
+            let after_linking = i > 0 && actions.iter().any(|a| a.position == i - 1
+                && LINKING_VERBS.contains(&a.base_form.as_str()));
+            if token == "like" && after_linking {
+                return None;
+            }
+            return group_prior(action.group).map(|score| (SentimentSource::EmotionVerb(action.group), score));
+        }
+        let noun = semantics.get(token).or_else(|| semantics.get(&self.grammar().noun_lemma(token)))?;
+        match noun.category {
+            SemanticCategory::Emotion { valence: Valence::Positive } => Some((SentimentSource::EmotionNoun, 0.6)),
+            SemanticCategory::Emotion { valence: Valence::Negative } => Some((SentimentSource::EmotionNoun, -0.6)),
+            _ => None,
+        }
+    }
+
+    /// Weight of every token from contrasts and concessive clauses
+    fn clause_weights(&self, tokens: &[String], clause_of: &[usize]) -> Vec<f64> {
+        let mut weights = vec![1.0; tokens.len()];
+        if let Some(k) = tokens.iter().rposition(|t| CONTRASTS.contains(&t.as_str())) {
+            for (i, w) in weights.iter_mut().enumerate() {
+                *w = if i < k { BEFORE_CONTRAST } else { AFTER_CONTRAST };
+            }
+        }
+        for (k, token) in tokens.iter().enumerate() {
+            if CONCESSIVES.contains(&token.as_str()) {
+                for i in (k..tokens.len()).take_while(|&i| clause_of[i] == clause_of[k]) {
+                    weights[i] = BEFORE_CONTRAST;
+                }
+            }
+        }
+        weights
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SemanticDisambiguator;
+    use crate::semantic::Valence;
+    use super::SentimentSource;
+    use crate::VerbGroup;
+
+    #[test]
+    fn test_polarity_and_sources() {
+        let dis = SemanticDisambiguator::new();
+        let good = dis.sentence_sentiment("This is a great phone");
+        assert_eq!(good.valence(), Valence::Positive);
+        assert_eq!(good.words[0].source, SentimentSource::Lexicon);
+
+        let hate = dis.sentence_sentiment("I hate waiting");
+        assert_eq!(hate.valence(), Valence::Negative);
+        assert_eq!(hate.words[0].source, SentimentSource::EmotionVerb(VerbGroup::Hate));
+
+        let joy = dis.sentence_sentiment("She felt pure joy");
+        assert_eq!(joy.words.last().unwrap().source, SentimentSource::EmotionNoun);
+        assert_eq!(dis.sentence_sentiment("The box is on the table").valence(), Valence::Neutral);
+        assert!(dis.sentence_sentiment("It looks like rain").words.is_empty());
+    }
+
+    #[test]
+    fn test_negation_and_intensifiers() {
+        let dis = SemanticDisambiguator::new();
+        let plain = dis.sentence_sentiment("I love it");
+        let negated = dis.sentence_sentiment("I don't love it");
+        assert!(negated.words[0].negated);
+        assert_eq!(negated.valence(), Valence::Negative);
+        assert!(negated.polarity.abs() < plain.polarity.abs());
+        assert_eq!(dis.sentence_sentiment("not bad at all").valence(), Valence::Positive);
+
+        let strong = dis.sentence_sentiment("I really hate it");
+        assert!(strong.polarity < dis.sentence_sentiment("I hate it").polarity);
+        assert_eq!(strong.words[0].modifiers, vec!["really"]);
+        assert!(dis.sentence_sentiment("slightly annoying").intensity < dis.sentence_sentiment("annoying").intensity);
+
+        // Negation does not reach across a clause boundary
+        assert!(!dis.sentence_sentiment("Not me, I loved it").words[0].negated);
+    }
+
+    #[test]
+    fn test_contrast() {
+        let dis = SemanticDisambiguator::new();
+        let mixed = dis.sentence_sentiment("The food was good but the service was bad");
+        assert_eq!(mixed.valence(), Valence::Negative);
+        assert!(mixed.intensity > mixed.polarity.abs());
+        let conceded = dis.sentence_sentiment("Although the room was dirty, the staff were wonderful");
+        assert_eq!(conceded.valence(), Valence::Positive);
+        assert_eq!(conceded.words[0].weight, 0.5);
+    }
+
+    #[test]
+    fn test_document_sentiment() {
+        let dis = SemanticDisambiguator::new();
+        let doc = dis.sentiment("I love this camera. The battery is terrible. The strap is black. The photos are excellent!");
+        assert_eq!(doc.sentences.len(), 4);
+        assert_eq!(doc.sentences[2].valence(), Valence::Neutral);
+        assert_eq!(doc.valence(), Valence::Positive);
+        assert_eq!(doc.words().count(), 3);
+        assert_eq!(doc.explain().len(), 3);
+        assert_eq!(dis.sentiment("").valence(), Valence::Neutral);
+    }
+}
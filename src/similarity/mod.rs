@@ -0,0 +1,198 @@
+//! # Sentence Similarity
+//!
+//! Compares two sentences by aligning their actions. A pair of actions
+//! scores `verb × (0.7 + 0.3 × overlap)`, where `overlap` is the Jaccard
+//! overlap of their argument heads; pairs are aligned greedily from the
+//! best score down, and the sentence score is the aligned total divided by
+//! the larger number of actions.
+
+use std::collections::BTreeSet;
+
+use crate::SemanticDisambiguator;
+use crate::srl::SemanticFrame;
+use crate::verbs::similarity::VerbSimilarity;
+
+/// Share of a pair score given by the verb alone
+const VERB_WEIGHT: f64 = 0.7;
+/// Share of a pair score given by argument overlap
+const ARGUMENT_WEIGHT: f64 = 0.3;
+
+/// Two actions aligned across sentences
+#[derive(Debug, Clone)]
+pub struct ActionAlignment {
+    /// Base form of the action in the first sentence
+    pub left: String,
+    /// Base form of the action in the second sentence
+    pub right: String,
+    /// Verb-level similarity
+    pub verb: VerbSimilarity,
+    /// Jaccard overlap of argument heads
+    pub argument_overlap: f64,
+    /// Argument heads found in both actions
+    pub shared_heads: Vec<String>,
+    /// Combined pair score
+    pub score: f64,
+}
+
+/// Similarity between two sentences with its alignment
+#[derive(Debug, Clone)]
+pub struct SentenceSimilarity {
+    /// Score in [0, 1]
+    pub score: f64,
+    /// Aligned action pairs, best first
+    pub alignments: Vec<ActionAlignment>,
+    /// Actions of the first sentence left unaligned
+    pub unaligned_left: Vec<String>,
+    /// Actions of the second sentence left unaligned
+    pub unaligned_right: Vec<String>,
+}
+
+impl SentenceSimilarity {
+    /// One line per alignment, for logs and debugging
+    pub fn explain(&self) -> Vec<String> {
+        let mut lines: Vec<String> = self.alignments.iter()
+            .map(|a| format!(
+                "{} ~ {}: {:.2} (verb {:.2}: {}; arguments {:.2} {:?})",
+                a.left, a.right, a.score, a.verb.score, a.verb.explanation,
+                a.argument_overlap, a.shared_heads,
+            ))
+            .collect();
+        for verb in &self.unaligned_left {
+            lines.push(format!("{}: no counterpart in second sentence", verb));
+        }
+        for verb in &self.unaligned_right {
+            lines.push(format!("{}: no counterpart in first sentence", verb));
+        }
+        lines
+    }
+}
+
+impl SemanticDisambiguator {
+    /// Similarity between two sentences, aligning actions and argument heads
+    pub fn sentence_similarity(&self, a: &str, b: &str) -> SentenceSimilarity {
+        let left = self.semantic_frames(a);
+        let right = self.semantic_frames(b);
+
+        let mut pairs: Vec<(usize, usize, ActionAlignment)> = Vec::new();
+        for (i, fa) in left.iter().enumerate() {
+            for (j, fb) in right.iter().enumerate() {
+                pairs.push((i, j, self.align(fa, fb)));
+            }
+        }
+        // Best first; ties keep sentence order so results are deterministic
+        pairs.sort_by(|x, y| y.2.score.total_cmp(&x.2.score)
+            .then(x.0.cmp(&y.0))
+            .then(x.1.cmp(&y.1)));
+
+        let mut used_left = vec![false; left.len()];
+        let mut used_right = vec![false; right.len()];
+        let mut alignments = Vec::new();
+        for (i, j, alignment) in pairs {
+            if !used_left[i] && !used_right[j] && alignment.score > 0.0 {
+                used_left[i] = true;
+                used_right[j] = true;
+                alignments.push(alignment);
+            }
+        }
+
+        let total: f64 = alignments.iter().map(|a| a.score).sum();
+        let denominator = left.len().max(right.len());
+        let score = if denominator == 0 { 0.0 } else { total / denominator as f64 };
+
+        let unaligned = |frames: &[SemanticFrame], used: &[bool]| frames.iter()
+            .zip(used)
+            .filter(|(_, u)| !**u)
+            .map(|(f, _)| f.action.base_form.clone())
+            .collect();
+
+        SentenceSimilarity {
+            score,
+            unaligned_left: unaligned(&left, &used_left),
+            unaligned_right: unaligned(&right, &used_right),
+            alignments,
+        }
+    }
+
+    fn align(&self, a: &SemanticFrame, b: &SemanticFrame) -> ActionAlignment {
+        let verb = if a.action.particle.is_some() || b.action.particle.is_some() {
+            self.phrasal_similarity(a, b)
+        } else {
+            self.verbs().similarity(&a.action.base_form, &b.action.base_form)
+        };
+
+        let heads_a = self.argument_heads(a);
+        let heads_b = self.argument_heads(b);
+        let shared: Vec<String> = heads_a.intersection(&heads_b).cloned().collect();
+        let union = heads_a.union(&heads_b).count();
+        let argument_overlap = if union == 0 { 1.0 } else { shared.len() as f64 / union as f64 };
+
+        ActionAlignment {
+            left: a.action.base_form.clone(),
+            right: b.action.base_form.clone(),
+            score: verb.score * (VERB_WEIGHT + ARGUMENT_WEIGHT * argument_overlap),
+            verb,
+            argument_overlap,
+            shared_heads: shared,
+        }
+    }
+
+    /// Phrasal verbs compare by their own group and category
+    fn phrasal_similarity(&self, a: &SemanticFrame, b: &SemanticFrame) -> VerbSimilarity {
+        let same = a.action.base_form == b.action.base_form;
+        let same_group = a.action.group == b.action.group;
+        let same_category = a.action.category == b.action.category;
+        let (score, explanation) = if same {
+            (1.0, format!("same verb '{}'", a.action.base_form))
+        } else if same_group {
+            (0.7, format!("same group {}", a.action.group.name()))
+        } else if same_category {
+            (0.4, format!("same category {}", a.action.category.name()))
+        } else {
+            (0.0, "unrelated".to_string())
+        };
+        VerbSimilarity {
+            score,
+            same_group,
+            same_category,
+            synonym_distance: if same { Some(0) } else { None },
+            antonyms: false,
+            explanation,
+        }
+    }
+
+    /// Lemmatised last word of each argument ("the red books" -> "book")
+    fn argument_heads(&self, frame: &SemanticFrame) -> BTreeSet<String> {
+        frame.arguments.iter()
+            .filter_map(|a| a.text.split_whitespace().last())
+            .map(|w| self.grammar().noun_lemma(w))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sentence_similarity() {
+        let dis = SemanticDisambiguator::new();
+        let close = dis.sentence_similarity("walk to the store", "stroll to the store");
+        let far = dis.sentence_similarity("walk to the store", "eat the cake");
+        assert!(close.score > far.score);
+        assert_eq!(close.alignments.len(), 1);
+        assert_eq!(close.alignments[0].shared_heads, vec!["store".to_string()]);
+        assert!(!close.explain().is_empty());
+
+        let same = dis.sentence_similarity("Delete the file", "delete the file");
+        assert!((same.score - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unaligned_actions() {
+        let dis = SemanticDisambiguator::new();
+        let sim = dis.sentence_similarity("open the door and walk home", "open the door");
+        assert_eq!(sim.alignments.len(), 1);
+        assert_eq!(sim.unaligned_left, vec!["walk".to_string()]);
+        assert!(sim.score < 1.0);
+    }
+}
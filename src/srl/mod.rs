@@ -0,0 +1,487 @@
+//! # Semantic Role Labelling
+//!
+//! Attaches "who did what to whom, where, when, with what" to detected
+//! actions. Roles come from the verb's subcategorisation frames, its
+//! functional category and the preposition heading each phrase:
+//!
+//! - "**John** cut **the bread** with **a knife**" → Agent, Patient, Instrument
+//! - "She walked to **the store** from **home**" → Goal, Source
+//! - "The cake was eaten by **Tom** on **Monday**" → Patient, Agent, Time
+
+use crate::DetectedAction;
+use crate::verbs::{VerbDatabase, FunctionalCategory, Frame, ThematicRole, FormRole};
+use crate::verbs::frames::DETERMINERS;
+use crate::grammar::EnglishGrammar;
+
+/// Semantic role of an argument
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SemanticRole {
+    /// Doer of the action
+    Agent,
+    /// Entity affected by the action
+    Patient,
+    /// Receiver of a transfer or message
+    Recipient,
+    /// Means used to perform the action
+    Instrument,
+    /// Place where the action happens
+    Location,
+    /// Starting point of a movement or transfer
+    Source,
+    /// End point of a movement
+    Goal,
+    /// When the action happens
+    Time,
+}
+
+impl SemanticRole {
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SemanticRole::Agent => "Agent",
+            SemanticRole::Patient => "Patient",
+            SemanticRole::Recipient => "Recipient",
+            SemanticRole::Instrument => "Instrument",
+            SemanticRole::Location => "Location",
+            SemanticRole::Source => "Source",
+            SemanticRole::Goal => "Goal",
+            SemanticRole::Time => "Time",
+        }
+    }
+}
+
+/// A phrase filling a semantic role
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoleFiller {
+    /// Role played
+    pub role: SemanticRole,
+    /// Phrase text without its preposition ("a knife")
+    pub text: String,
+    /// Preposition heading the phrase ("with")
+    pub preposition: Option<String>,
+    /// First token index
+    pub start: usize,
+    /// One past the last token index
+    pub end: usize,
+}
+
+/// An action with its labelled arguments
+#[derive(Debug, Clone)]
+pub struct SemanticFrame {
+    /// The detected action
+    pub action: DetectedAction,
+    /// Labelled arguments in sentence order
+    pub arguments: Vec<RoleFiller>,
+    /// Passive voice ("was eaten by")
+    pub passive: bool,
+}
+
+impl SemanticFrame {
+    /// First argument with a role
+    pub fn get(&self, role: SemanticRole) -> Option<&RoleFiller> {
+        self.arguments.iter().find(|a| a.role == role)
+    }
+
+    /// Text of the first argument with a role
+    pub fn text(&self, role: SemanticRole) -> Option<&str> {
+        self.get(role).map(|a| a.text.as_str())
+    }
+
+    /// All arguments with a role
+    pub fn all(&self, role: SemanticRole) -> Vec<&RoleFiller> {
+        self.arguments.iter().filter(|a| a.role == role).collect()
+    }
+}
+
+/// Words that make a phrase temporal ("on Monday", "in the morning")
+const TIME_WORDS: &[&str] = &[
+    "monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday",
+    "january", "february", "march", "april", "may", "june", "july", "august",
+    "september", "october", "november", "december",
+    "morning", "afternoon", "evening", "night", "noon", "midnight", "dawn", "dusk",
+    "today", "tomorrow", "yesterday", "tonight", "weekend", "moment",
+    "seconds", "minute", "minutes", "hour", "hours", "day", "days",
+    "week", "weeks", "month", "months", "year", "years", "o'clock",
+    "spring", "summer", "autumn", "winter",
+];
+
+/// Time adverbs that stand alone without a preposition
+const BARE_TIME: &[&str] = &[
+    "today", "tomorrow", "yesterday", "tonight", "now", "later", "soon",
+];
+
+/// Determiners that start a bare time phrase ("next week", "every day")
+const TIME_DETERMINERS: &[&str] = &["last", "next", "this", "every"];
+
+/// Words skipped when collecting a subject
+const SUBJECT_SKIP: &[&str] = &["not", "never", "please", "then", "also", "just"];
+
+/// Labels semantic roles using a verb database and grammar
+pub struct SemanticRoleLabeler<'a> {
+    verbs: &'a VerbDatabase,
+    grammar: &'a EnglishGrammar,
+}
+
+impl<'a> SemanticRoleLabeler<'a> {
+    pub fn new(verbs: &'a VerbDatabase, grammar: &'a EnglishGrammar) -> Self {
+        Self { verbs, grammar }
+    }
+
+    /// Label the arguments of each action found in `tokens`
+    ///
+    /// Auxiliaries ("was" in "was eaten") and a leading "please" are
+    /// folded into the following action rather than framed on their own;
+    /// verb forms used as nouns ("the store") are skipped.
+    pub fn label(&self, tokens: &[String], actions: &[DetectedAction]) -> Vec<SemanticFrame> {
+        let actions: Vec<&DetectedAction> = actions.iter().enumerate()
+            .filter(|(n, a)| {
+                let next = actions.get(n + 1).map(|b| b.position);
+                let token = tokens[a.position].as_str();
+                let helper = (self.grammar.is_auxiliary(token) || token == "please")
+                    && next.map(|p| p <= a.position + 2).unwrap_or(false);
+                // A verb form after a determiner is a noun ("the store")
+                let nominal = a.position > 0 && DETERMINERS.contains(&tokens[a.position - 1].as_str());
+                !nominal && !helper
+            })
+            .map(|(_, a)| a)
+            .collect();
+
+        let mut frames: Vec<SemanticFrame> = Vec::new();
+        let mut clause_start = 0;
+
+        for (n, action) in actions.iter().enumerate() {
+            let verb = action.position;
+            let next_verb = actions.get(n + 1).map(|a| a.position).unwrap_or(tokens.len());
+            let particles = self.particle_positions(tokens, action, next_verb);
+            let frames_of_verb = self.frames_of(action);
+
+            // Clause ends at a coordinating conjunction before the next verb
+            let post_end = (verb + 1..next_verb)
+                .find(|&i| self.is_clause_break(&tokens[i]))
+                .unwrap_or(next_verb);
+
+            let mut arguments = Vec::new();
+            let passive = self.is_passive(tokens, clause_start, action);
+
+            // Subject: the NP before the verb, or the previous subject when
+            // coordinated ("She walked ... and bought ...")
+            let subject_role = if passive || frames_of_verb.iter().all(|f| f.subject == ThematicRole::Theme) {
+                SemanticRole::Patient
+            } else {
+                SemanticRole::Agent
+            };
+            let subject = self.subject_span(tokens, clause_start, verb, &mut arguments);
+            match subject {
+                Some((s, e)) => arguments.push(RoleFiller {
+                    role: subject_role,
+                    text: tokens[s..e].join(" "),
+                    preposition: None,
+                    start: s,
+                    end: e,
+                }),
+                None => {
+                    let coordinated = n > 0 && (clause_start..verb).any(|i| self.is_clause_break(&tokens[i]));
+                    let previous = frames.last()
+                        .and_then(|f| f.arguments.iter().find(|a| a.start < f.action.position).cloned());
+                    if let (true, Some(mut prev)) = (coordinated, previous) {
+                        prev.role = subject_role;
+                        arguments.push(prev);
+                    }
+                }
+            }
+
+            // Post-verbal phrases
+            let post: Vec<usize> = (verb + 1..post_end).filter(|i| !particles.contains(i)).collect();
+            self.label_post_verbal(tokens, &post, action, &frames_of_verb, passive, &mut arguments);
+
+            arguments.sort_by_key(|a| a.start);
+            frames.push(SemanticFrame {
+                action: (*action).clone(),
+                arguments,
+                passive,
+            });
+            clause_start = post_end;
+        }
+
+        frames
+    }
+
+    /// Frames of the action's verb (phrasal or simple)
+    fn frames_of(&self, action: &DetectedAction) -> Vec<Frame> {
+        if action.particle.is_some() {
+            if let Some(p) = self.verbs.lookup_phrasal(&action.base_form) {
+                return p.frames.clone();
+            }
+        }
+        self.verbs.lookup(&action.base_form).map(|e| e.frames.clone()).unwrap_or_default()
+    }
+
+    /// Token positions of a phrasal verb's particle
+    fn particle_positions(&self, tokens: &[String], action: &DetectedAction, end: usize) -> Vec<usize> {
+        let Some(particle) = &action.particle else { return Vec::new() };
+        let words: Vec<&str> = particle.split_whitespace().collect();
+        let adjacent = words.iter().enumerate()
+            .all(|(k, w)| tokens.get(action.position + 1 + k).map(|t| t == w).unwrap_or(false));
+        if adjacent {
+            return (action.position + 1..action.position + 1 + words.len()).collect();
+        }
+        (action.position + 1..end)
+            .find(|&i| tokens[i] == words[0])
+            .map(|i| vec![i])
+            .unwrap_or_default()
+    }
+
+    fn is_clause_break(&self, token: &str) -> bool {
+        token == "then" || (self.grammar.is_conjunction(token) && !self.grammar.is_preposition(token))
+    }
+
+    /// "was eaten", "is being built", "were not seen"
+    fn is_passive(&self, tokens: &[String], clause_start: usize, action: &DetectedAction) -> bool {
+        if !action.has_role(FormRole::PastParticiple) || action.has_role(FormRole::Base) {
+            return false;
+        }
+        (clause_start..action.position).rev()
+            .take_while(|&i| self.grammar.is_auxiliary(&tokens[i]) || SUBJECT_SKIP.contains(&tokens[i].as_str()))
+            .any(|i| matches!(tokens[i].as_str(), "is" | "are" | "was" | "were" | "be" | "been" | "being" | "am"))
+    }
+
+    /// Span of the subject NP before the verb; time phrases found on the way
+    /// are labelled directly
+    fn subject_span(&self, tokens: &[String], start: usize, verb: usize, arguments: &mut Vec<RoleFiller>) -> Option<(usize, usize)> {
+        let mut first = None;
+        let mut last = None;
+        let mut i = start;
+        while i < verb {
+            let t = tokens[i].as_str();
+            if let Some(len) = self.time_phrase_len(&tokens[i..verb]) {
+                arguments.push(self.filler(tokens, SemanticRole::Time, None, i, i + len));
+                i += len;
+                continue;
+            }
+            let skip = self.grammar.is_auxiliary(t) || self.is_clause_break(t)
+                || SUBJECT_SKIP.contains(&t) || self.grammar.is_preposition(t);
+            if skip {
+                if first.is_some() { break; }
+            } else {
+                first.get_or_insert(i);
+                last = Some(i + 1);
+            }
+            i += 1;
+        }
+        first.zip(last)
+    }
+
+    /// Length of a bare time phrase at the start of `tokens`
+    fn time_phrase_len(&self, tokens: &[String]) -> Option<usize> {
+        let first = tokens.first()?.as_str();
+        if BARE_TIME.contains(&first) {
+            return Some(1);
+        }
+        if TIME_DETERMINERS.contains(&first) && tokens.get(1).map(|t| is_time_word(t)).unwrap_or(false) {
+            return Some(2);
+        }
+        None
+    }
+
+    fn label_post_verbal(
+        &self,
+        tokens: &[String],
+        post: &[usize],
+        action: &DetectedAction,
+        frames: &[Frame],
+        passive: bool,
+        arguments: &mut Vec<RoleFiller>,
+    ) {
+        let mut k = 0;
+        let mut objects_done = false;
+
+        while k < post.len() {
+            let i = post[k];
+            let t = tokens[i].as_str();
+
+            if let Some(len) = self.time_phrase_len(&tokens[i..]) {
+                arguments.push(self.filler(tokens, SemanticRole::Time, None, i, i + len));
+                k += len;
+                continue;
+            }
+
+            if self.grammar.is_preposition(t) {
+                // Prepositional phrase runs to the next preposition
+                let mut j = k + 1;
+                while j < post.len() && !self.grammar.is_preposition(&tokens[post[j]])
+                    && self.time_phrase_len(&tokens[post[j]..]).is_none() {
+                    j += 1;
+                }
+                if j > k + 1 {
+                    let (s, e) = (post[k + 1], post[j - 1] + 1);
+                    let temporal = tokens[s..e].iter().any(|w| is_time_word(w));
+                    if let Some(role) = self.role_for_prep(t, temporal, action, frames, passive) {
+                        arguments.push(self.filler(tokens, role, Some(t), s, e));
+                    }
+                }
+                objects_done = true;
+                k = j;
+                continue;
+            }
+
+            // Bare NP objects directly after the verb
+            let mut j = k;
+            while j < post.len() && !self.grammar.is_preposition(&tokens[post[j]])
+                && self.time_phrase_len(&tokens[post[j]..]).is_none() {
+                j += 1;
+            }
+            if !objects_done {
+                let np: Vec<String> = post[k..j].iter().map(|&p| tokens[p].clone()).collect();
+                self.label_objects(tokens, &post[k..j], &np, action, frames, arguments);
+                objects_done = true;
+            }
+            k = j;
+        }
+    }
+
+    /// Label bare NP objects, splitting "Mary the book" for ditransitives
+    fn label_objects(
+        &self,
+        tokens: &[String],
+        positions: &[usize],
+        np: &[String],
+        action: &DetectedAction,
+        frames: &[Frame],
+        arguments: &mut Vec<RoleFiller>,
+    ) {
+        let (s, e) = (positions[0], positions[positions.len() - 1] + 1);
+
+        if frames.contains(&Frame::DITRANSITIVE) {
+            if let Some(fill) = Frame::DITRANSITIVE.fill(np, self.grammar) {
+                if fill.unmatched.is_empty() {
+                    let split = s + fill.arguments[0].text.split_whitespace().count();
+                    arguments.push(self.filler(tokens, SemanticRole::Recipient, None, s, split));
+                    arguments.push(self.filler(tokens, SemanticRole::Patient, None, split, e));
+                    return;
+                }
+            }
+        }
+
+        // Movement to a bare place adverb: "go home"
+        let role = if action.category == FunctionalCategory::Movement
+            && matches!(np[0].as_str(), "home" | "here" | "there" | "away" | "back") {
+            SemanticRole::Goal
+        } else {
+            SemanticRole::Patient
+        };
+        arguments.push(self.filler(tokens, role, None, s, e));
+    }
+
+    /// Role of a prepositional phrase for this verb
+    fn role_for_prep(
+        &self,
+        prep: &str,
+        temporal: bool,
+        action: &DetectedAction,
+        frames: &[Frame],
+        passive: bool,
+    ) -> Option<SemanticRole> {
+        use FunctionalCategory as C;
+
+        let frame_role = frames.iter().find_map(|f| f.role_for_prep(prep));
+        let role = match prep {
+            "during" | "before" | "after" | "until" | "since" => SemanticRole::Time,
+            "by" if passive => SemanticRole::Agent,
+            _ if temporal => SemanticRole::Time,
+            "with" => {
+                let physical = matches!(action.category,
+                    C::Physical | C::Destruction | C::Creation | C::Consumption);
+                if frame_role == Some(ThematicRole::Instrument) || physical {
+                    SemanticRole::Instrument
+                } else {
+                    return None;
+                }
+            }
+            "to" | "into" | "onto" | "toward" | "towards" | "for" => {
+                if frame_role == Some(ThematicRole::Recipient) {
+                    SemanticRole::Recipient
+                } else if prep == "for" {
+                    return None;
+                } else {
+                    SemanticRole::Goal
+                }
+            }
+            "from" | "off" | "out" => SemanticRole::Source,
+            "about" | "against" | "without" | "beyond" => return None,
+            _ => SemanticRole::Location,
+        };
+        Some(role)
+    }
+
+    fn filler(&self, tokens: &[String], role: SemanticRole, prep: Option<&str>, start: usize, end: usize) -> RoleFiller {
+        RoleFiller {
+            role,
+            text: tokens[start..end].join(" "),
+            preposition: prep.map(|p| p.to_string()),
+            start,
+            end,
+        }
+    }
+}
+
+/// Check if a token is a time word ("monday", "3pm", "10:30", "2026")
+fn is_time_word(token: &str) -> bool {
+    if TIME_WORDS.contains(&token) {
+        return true;
+    }
+    let digits = token.trim_end_matches("am").trim_end_matches("pm");
+    let clock = !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit() || c == ':');
+    clock && (digits.len() != token.len() || digits.contains(':') || digits.len() == 4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SemanticDisambiguator;
+
+    #[test]
+    fn test_instrument_and_goal() {
+        let dis = SemanticDisambiguator::new();
+        let frames = dis.semantic_frames("John cut the bread with a knife");
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].text(SemanticRole::Agent), Some("john"));
+        assert_eq!(frames[0].text(SemanticRole::Patient), Some("the bread"));
+        assert_eq!(frames[0].text(SemanticRole::Instrument), Some("a knife"));
+
+        let frames = dis.semantic_frames("She walked to the store from home and bought some food");
+        assert_eq!(frames[0].text(SemanticRole::Goal), Some("the store"));
+        assert_eq!(frames[0].text(SemanticRole::Source), Some("home"));
+        assert_eq!(frames[1].text(SemanticRole::Agent), Some("she"));
+        assert_eq!(frames[1].text(SemanticRole::Patient), Some("some food"));
+
+        let frames = dis.semantic_frames("He threw the ball onto the roof");
+        assert_eq!(frames[0].text(SemanticRole::Patient), Some("the ball"));
+        assert_eq!(frames[0].text(SemanticRole::Goal), Some("the roof"));
+    }
+
+    #[test]
+    fn test_recipient_and_time() {
+        let dis = SemanticDisambiguator::new();
+        let frames = dis.semantic_frames("Tomorrow I will give Mary the book");
+        let give = &frames[0];
+        assert_eq!(give.action.base_form, "give");
+        assert_eq!(give.text(SemanticRole::Recipient), Some("mary"));
+        assert_eq!(give.text(SemanticRole::Patient), Some("the book"));
+        assert_eq!(give.text(SemanticRole::Time), Some("tomorrow"));
+
+        let frames = dis.semantic_frames("We sent the letter to Anna on Monday");
+        assert_eq!(frames[0].text(SemanticRole::Recipient), Some("anna"));
+        assert_eq!(frames[0].text(SemanticRole::Time), Some("monday"));
+    }
+
+    #[test]
+    fn test_passive() {
+        let dis = SemanticDisambiguator::new();
+        let frames = dis.semantic_frames("The cake was eaten by the children in the kitchen");
+        assert_eq!(frames.len(), 1);
+        assert!(frames[0].passive);
+        assert_eq!(frames[0].text(SemanticRole::Patient), Some("the cake"));
+        assert_eq!(frames[0].text(SemanticRole::Agent), Some("the children"));
+        assert_eq!(frames[0].text(SemanticRole::Location), Some("the kitchen"));
+    }
+}
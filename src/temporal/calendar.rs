@@ -0,0 +1,299 @@
+//! Proleptic Gregorian calendar arithmetic
+//!
+//! Dates convert to and from a day count since 1970-01-01 with the
+//! civil-from-days algorithms, so adding days, finding weekdays and moving
+//! across months and leap years need no tables.
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Day of the week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// All days, Monday first
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday,
+        Weekday::Friday, Weekday::Saturday, Weekday::Sunday,
+    ];
+
+    /// Lowercase name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "monday",
+            Weekday::Tuesday => "tuesday",
+            Weekday::Wednesday => "wednesday",
+            Weekday::Thursday => "thursday",
+            Weekday::Friday => "friday",
+            Weekday::Saturday => "saturday",
+            Weekday::Sunday => "sunday",
+        }
+    }
+
+    /// Two-letter code used in recurrence rules ("MO")
+    pub fn code(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "MO",
+            Weekday::Tuesday => "TU",
+            Weekday::Wednesday => "WE",
+            Weekday::Thursday => "TH",
+            Weekday::Friday => "FR",
+            Weekday::Saturday => "SA",
+            Weekday::Sunday => "SU",
+        }
+    }
+
+    /// Parse a name or its plural ("friday", "Fridays")
+    pub fn parse(word: &str) -> Option<Self> {
+        let word = word.to_lowercase();
+        let word = word.strip_suffix('s').filter(|w| w.ends_with("day")).unwrap_or(&word);
+        Self::ALL.into_iter().find(|d| d.name() == word)
+    }
+
+    /// Days since Monday (0 - 6)
+    pub fn index(&self) -> i64 {
+        *self as i64
+    }
+}
+
+/// A calendar date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Create a date, `None` if it does not exist
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        ((1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month))
+            .then_some(Self { year, month, day })
+    }
+
+    /// Today's date in UTC
+    pub fn today() -> Self {
+        let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    /// Parse "2026-10-16"
+    pub fn parse_iso(text: &str) -> Option<Self> {
+        let mut parts = text.splitn(3, '-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        Self::new(year, month, day)
+    }
+
+    /// Days since 1970-01-01
+    pub fn days(&self) -> i64 {
+        let (m, d) = (self.month as i64, self.day as i64);
+        let y = self.year as i64 - if m <= 2 { 1 } else { 0 };
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Date a number of days after 1970-01-01
+    ///
+    /// Panics past the years an `i32` holds; see `checked_from_days`.
+    pub fn from_days(days: i64) -> Self {
+        Self::checked_from_days(days).expect("date out of range")
+    }
+
+    /// Date a number of days after 1970-01-01, `None` past the years an `i32` holds
+    pub fn checked_from_days(days: i64) -> Option<Self> {
+        let z = days.checked_add(719_468)?;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = i32::try_from(yoe + era * 400 + if month <= 2 { 1 } else { 0 }).ok()?;
+        Some(Self { year, month, day })
+    }
+
+    /// Day of the week
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::ALL[(self.days() + 3).rem_euclid(7) as usize]
+    }
+
+    /// Date `n` days later (earlier if negative)
+    ///
+    /// Panics past the years an `i32` holds; see `checked_add_days`.
+    pub fn add_days(&self, n: i64) -> Self {
+        self.checked_add_days(n).expect("date out of range")
+    }
+
+    /// Date `n` days later, `None` past the years an `i32` holds
+    pub fn checked_add_days(&self, n: i64) -> Option<Self> {
+        Self::checked_from_days(self.days().checked_add(n)?)
+    }
+
+    /// Date `n` months later, clamped to the end of the month (Jan 31 + 1 → Feb 28)
+    ///
+    /// Panics past the years an `i32` holds; see `checked_add_months`.
+    pub fn add_months(&self, n: i64) -> Self {
+        self.checked_add_months(n).expect("date out of range")
+    }
+
+    /// Date `n` months later, `None` past the years an `i32` holds
+    pub fn checked_add_months(&self, n: i64) -> Option<Self> {
+        let total = (self.year as i64 * 12 + (self.month as i64 - 1)).checked_add(n)?;
+        let year = i32::try_from(total.div_euclid(12)).ok()?;
+        let month = (total.rem_euclid(12) + 1) as u8;
+        Some(Self { year, month, day: self.day.min(days_in_month(year, month)) })
+    }
+
+    /// First `weekday` on or after this date
+    ///
+    /// Panics past the years an `i32` holds; see `checked_next_weekday`.
+    pub fn next_weekday(&self, weekday: Weekday) -> Self {
+        self.checked_next_weekday(weekday).expect("date out of range")
+    }
+
+    /// First `weekday` on or after this date, `None` past the years an `i32` holds
+    pub fn checked_next_weekday(&self, weekday: Weekday) -> Option<Self> {
+        self.checked_add_days((weekday.index() - self.weekday().index()).rem_euclid(7))
+    }
+
+    /// Monday of this date's week
+    ///
+    /// Panics past the years an `i32` holds; see `checked_week_start`.
+    pub fn week_start(&self) -> Self {
+        self.checked_week_start().expect("date out of range")
+    }
+
+    /// Monday of this date's week, `None` past the years an `i32` holds
+    pub fn checked_week_start(&self) -> Option<Self> {
+        self.checked_add_days(-self.weekday().index())
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Time of day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Time {
+    pub hour: u8,
+    pub minute: u8,
+}
+
+impl Time {
+    /// Create a time, `None` if out of range
+    pub fn new(hour: u8, minute: u8) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    /// Minutes since midnight
+    pub fn minutes(&self) -> i64 {
+        self.hour as i64 * 60 + self.minute as i64
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// A date with an optional time of day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DateTime {
+    pub date: Date,
+    pub time: Option<Time>,
+}
+
+impl DateTime {
+    /// Date and time
+    pub fn new(date: Date, time: Time) -> Self {
+        Self { date, time: Some(time) }
+    }
+
+    /// Moment `minutes` later; a date without a time counts from midnight
+    ///
+    /// Panics past the years an `i32` holds; see `checked_add_minutes`.
+    pub fn add_minutes(&self, minutes: i64) -> Self {
+        self.checked_add_minutes(minutes).expect("date out of range")
+    }
+
+    /// Moment `minutes` later, `None` past the years an `i32` holds
+    pub fn checked_add_minutes(&self, minutes: i64) -> Option<Self> {
+        let total = self.time.map_or(0, |t| t.minutes()).checked_add(minutes)?;
+        let date = self.date.checked_add_days(total.div_euclid(1440))?;
+        let of_day = total.rem_euclid(1440);
+        Some(Self::new(date, Time { hour: (of_day / 60) as u8, minute: (of_day % 60) as u8 }))
+    }
+}
+
+impl From<Date> for DateTime {
+    fn from(date: Date) -> Self {
+        Self { date, time: None }
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.time {
+            Some(time) => write!(f, "{}T{}", self.date, time),
+            None => write!(f, "{}", self.date),
+        }
+    }
+}
+
+fn is_leap(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calendar_arithmetic() {
+        let date = Date::new(2026, 10, 16).unwrap();
+        assert_eq!(date.weekday(), Weekday::Friday);
+        assert_eq!(Date::from_days(date.days()), date);
+        assert_eq!(Date::from_days(0).to_string(), "1970-01-01");
+        assert_eq!(date.add_days(20).to_string(), "2026-11-05");
+        assert_eq!(Date::new(2024, 1, 31).unwrap().add_months(1).to_string(), "2024-02-29");
+        assert_eq!(date.next_weekday(Weekday::Monday).to_string(), "2026-10-19");
+        assert_eq!(date.next_weekday(Weekday::Friday), date);
+        assert_eq!(date.week_start().to_string(), "2026-10-12");
+        assert!(Date::new(2025, 2, 29).is_none());
+        assert_eq!(Date::parse_iso("2000-02-29"), Date::new(2000, 2, 29));
+
+        let late = DateTime::new(date, Time::new(23, 30).unwrap());
+        assert_eq!(late.add_minutes(45).to_string(), "2026-10-17T00:15");
+        assert_eq!(Weekday::parse("Fridays"), Some(Weekday::Friday));
+        assert_eq!(Weekday::parse("sun"), None);
+    }
+}
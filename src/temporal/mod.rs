@@ -0,0 +1,730 @@
+//! # Temporal Expressions
+//!
+//! Finds time expressions in text and resolves them against a reference
+//! date supplied by the caller:
+//!
+//! - **instants**: "tomorrow at 3pm", "next Friday", "in two weeks",
+//!   "March 5", "2026-10-16", "three days ago"
+//! - **durations**: "for two hours", "half an hour"
+//! - **recurrences**: "every Monday", "every other week", "daily at 9am",
+//!   with an RFC 5545 style rule (`FREQ=WEEKLY;BYDAY=MO`)
+//!
+//! Slashed dates are read month first ("10/16/2026") unless the first number
+//! cannot be a month. A bare weekday is the next one on or after the
+//! reference date; "next Friday" is the first one after it.
+//!
+//! ```
+//! use nl_sre_english::temporal::{TemporalTagger, TemporalValue, Date};
+//!
+//! let tagger = TemporalTagger::new(Date::new(2026, 10, 16).unwrap());
+//! let found = tagger.tag("call me tomorrow at 3pm");
+//! match &found[0].value {
+//!     TemporalValue::Instant(at) => assert_eq!(at.to_string(), "2026-10-17T15:00"),
+//!     other => panic!("unexpected {:?}", other),
+//! }
+//! ```
+
+pub mod calendar;
+pub(crate) mod names;
+
+pub use calendar::{Date, Time, DateTime, Weekday};
+
+use names::{day_number, numeric_date, year_number};
+
+use std::fmt;
+
+/// Prepositions kept apart from the expression they introduce
+const TIME_PREPOSITIONS: &[&str] = &["on", "at", "by", "until", "till", "before", "after", "since", "from"];
+
+/// Prepositions that make a quantity a duration ("for two hours")
+const DURATION_PREPOSITIONS: &[&str] = &["for", "during", "within", "lasting"];
+
+/// Number words
+const NUMBER_WORDS: &[(&str, u32)] = &[
+    ("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5), ("six", 6),
+    ("seven", 7), ("eight", 8), ("nine", 9), ("ten", 10), ("eleven", 11), ("twelve", 12),
+    ("fifteen", 15), ("twenty", 20), ("thirty", 30), ("forty", 40), ("fifty", 50), ("sixty", 60),
+];
+
+/// Ordinals that space a recurrence ("every third week"); "other" is every second
+const ORDINALS: &[(&str, u32)] = &[
+    ("other", 2), ("second", 2), ("third", 3), ("fourth", 4), ("fifth", 5), ("sixth", 6),
+];
+
+/// Unit of a duration or recurrence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl TimeUnit {
+    /// Lowercase name
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimeUnit::Minute => "minute",
+            TimeUnit::Hour => "hour",
+            TimeUnit::Day => "day",
+            TimeUnit::Week => "week",
+            TimeUnit::Month => "month",
+            TimeUnit::Year => "year",
+        }
+    }
+
+    /// Frequency name of a recurrence rule ("WEEKLY")
+    pub fn frequency(&self) -> &'static str {
+        match self {
+            TimeUnit::Minute => "MINUTELY",
+            TimeUnit::Hour => "HOURLY",
+            TimeUnit::Day => "DAILY",
+            TimeUnit::Week => "WEEKLY",
+            TimeUnit::Month => "MONTHLY",
+            TimeUnit::Year => "YEARLY",
+        }
+    }
+
+    /// Parse a unit word with the number of units it stands for ("fortnight" = 2 weeks)
+    fn parse(word: &str) -> Option<(Self, u32)> {
+        let word = word.strip_suffix('s').unwrap_or(word);
+        Some(match word {
+            "minute" | "min" => (TimeUnit::Minute, 1),
+            "hour" | "hr" => (TimeUnit::Hour, 1),
+            "day" => (TimeUnit::Day, 1),
+            "week" => (TimeUnit::Week, 1),
+            "fortnight" => (TimeUnit::Week, 2),
+            "month" => (TimeUnit::Month, 1),
+            "year" => (TimeUnit::Year, 1),
+            _ => return None,
+        })
+    }
+}
+
+/// A length of time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Duration {
+    pub amount: u32,
+    pub unit: TimeUnit,
+}
+
+impl Duration {
+    /// ISO 8601 form ("P3D", "PT2H")
+    pub fn iso(&self) -> String {
+        match self.unit {
+            TimeUnit::Minute => format!("PT{}M", self.amount),
+            TimeUnit::Hour => format!("PT{}H", self.amount),
+            TimeUnit::Day => format!("P{}D", self.amount),
+            TimeUnit::Week => format!("P{}W", self.amount),
+            TimeUnit::Month => format!("P{}M", self.amount),
+            TimeUnit::Year => format!("P{}Y", self.amount),
+        }
+    }
+
+    /// Moment this long after `from` (before it if `sign` is negative),
+    /// `None` past the years an `i32` holds
+    pub fn offset(&self, from: DateTime, sign: i64) -> Option<DateTime> {
+        let n = self.amount as i64 * sign;
+        let date = match self.unit {
+            TimeUnit::Minute => return from.checked_add_minutes(n),
+            TimeUnit::Hour => return from.checked_add_minutes(n * 60),
+            TimeUnit::Day => from.date.checked_add_days(n)?,
+            TimeUnit::Week => from.date.checked_add_days(n * 7)?,
+            TimeUnit::Month => from.date.checked_add_months(n)?,
+            TimeUnit::Year => from.date.checked_add_months(n * 12)?,
+        };
+        Some(DateTime { date, ..from })
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = if self.amount == 1 { "" } else { "s" };
+        write!(f, "{} {}{}", self.amount, self.unit.name(), plural)
+    }
+}
+
+/// A repeating schedule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recurrence {
+    /// Repetition unit
+    pub unit: TimeUnit,
+    /// Units between repetitions ("every other week" = 2)
+    pub interval: u32,
+    /// Days of a weekly schedule ("every Monday and Friday")
+    pub weekdays: Vec<Weekday>,
+    /// Time of day of each occurrence
+    pub time: Option<Time>,
+    /// First occurrence on or after the reference date
+    pub first: DateTime,
+}
+
+impl Recurrence {
+    /// RFC 5545 style rule ("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;BYHOUR=9;BYMINUTE=0")
+    pub fn rule(&self) -> String {
+        let mut rule = format!("FREQ={}", self.unit.frequency());
+        if self.interval > 1 {
+            rule.push_str(&format!(";INTERVAL={}", self.interval));
+        }
+        if !self.weekdays.is_empty() {
+            let days: Vec<&str> = self.weekdays.iter().map(|d| d.code()).collect();
+            rule.push_str(&format!(";BYDAY={}", days.join(",")));
+        }
+        if let Some(time) = self.time {
+            rule.push_str(&format!(";BYHOUR={};BYMINUTE={}", time.hour, time.minute));
+        }
+        rule
+    }
+
+    /// The first `count` occurrences
+    pub fn occurrences(&self, count: usize) -> Vec<DateTime> {
+        if self.weekdays.is_empty() {
+            let step = Duration { amount: self.interval, unit: self.unit };
+            return std::iter::successors(Some(self.first), |at| step.offset(*at, 1))
+                .take(count)
+                .collect();
+        }
+        let Some(week) = self.first.date.checked_week_start() else { return Vec::new() };
+        (0..)
+            .map_while(|n| self.first.date.checked_add_days(n))
+            .filter(|d| self.weekdays.contains(&d.weekday()))
+            .filter(|d| ((d.days() - d.weekday().index() - week.days()) / 7) % self.interval as i64 == 0)
+            .map(|date| DateTime { date, time: self.time })
+            .take(count)
+            .collect()
+    }
+}
+
+/// Resolved value of a temporal expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemporalValue {
+    /// A point in time
+    Instant(DateTime),
+    /// A length of time
+    Duration(Duration),
+    /// A repeating schedule
+    Recurrence(Recurrence),
+}
+
+/// A temporal expression found in text
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemporalExpression {
+    /// Expression text ("every friday")
+    pub text: String,
+    /// Preposition before the expression ("on", "by")
+    pub preposition: Option<String>,
+    /// First token index
+    pub start: usize,
+    /// One past the last token index
+    pub end: usize,
+    /// Resolved value
+    pub value: TemporalValue,
+}
+
+impl TemporalExpression {
+    /// Check if a token index lies inside the expression or is its preposition
+    pub fn covers(&self, position: usize) -> bool {
+        let start = if self.preposition.is_some() { self.start - 1 } else { self.start };
+        (start..self.end).contains(&position)
+    }
+}
+
+/// Finds and resolves temporal expressions relative to a reference date
+#[derive(Debug, Clone)]
+pub struct TemporalTagger {
+    reference: DateTime,
+}
+
+impl TemporalTagger {
+    /// Tagger resolving against a date, or a date and time
+    pub fn new(reference: impl Into<DateTime>) -> Self {
+        Self { reference: reference.into() }
+    }
+
+    /// Reference moment
+    pub fn reference(&self) -> DateTime {
+        self.reference
+    }
+
+    /// Temporal expressions of a text
+    ///
+    /// Token spans count words split at whitespace and `, . ! ?`; contractions
+    /// are not expanded, so to line spans up with `EnglishGrammar::tokenize`
+    /// pass its tokens to `tag_tokens`.
+    pub fn tag(&self, text: &str) -> Vec<TemporalExpression> {
+        let tokens: Vec<String> = text
+            .split(|c: char| c.is_whitespace() || c == ',' || c == '.' || c == '!' || c == '?')
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_lowercase())
+            .collect();
+        self.tag_tokens(&tokens)
+    }
+
+    /// Temporal expressions of lowercase tokens
+    pub fn tag_tokens(&self, tokens: &[String]) -> Vec<TemporalExpression> {
+        let t: Vec<&str> = tokens.iter().map(|s| s.as_str()).collect();
+        let mut found = Vec::new();
+        let mut i = 0;
+        while i < t.len() {
+            let hit = self.recurrence(&t, i)
+                .or_else(|| self.relative(&t, i))
+                .or_else(|| self.duration(&t, i))
+                .or_else(|| self.instant(&t, i));
+            match hit {
+                Some((end, value)) => {
+                    let preposition = i.checked_sub(1)
+                        .map(|p| t[p])
+                        .filter(|p| TIME_PREPOSITIONS.contains(p))
+                        .map(|p| p.to_string());
+                    found.push(TemporalExpression {
+                        text: t[i..end].join(" "),
+                        preposition,
+                        start: i,
+                        end,
+                        value,
+                    });
+                    i = end;
+                }
+                None => i += 1,
+            }
+        }
+        found
+    }
+
+    /// "every Monday", "every other week", "daily at 9am", "on Fridays"
+    fn recurrence(&self, t: &[&str], i: usize) -> Option<(usize, TemporalValue)> {
+        let mut weekdays = Vec::new();
+        let mut time = None;
+        let mut interval = 1;
+        let (unit, mut end) = match t[i] {
+            "hourly" => (TimeUnit::Hour, i + 1),
+            "daily" => (TimeUnit::Day, i + 1),
+            "weekly" => (TimeUnit::Week, i + 1),
+            "monthly" => (TimeUnit::Month, i + 1),
+            "yearly" | "annually" => (TimeUnit::Year, i + 1),
+            "every" | "each" => {
+                let mut j = i + 1;
+                if let Some(n) = t.get(j).and_then(|w| ordinal(w)) {
+                    interval = n;
+                    j += 1;
+                }
+                let word = *t.get(j)?;
+                if let Some((n, unit, end)) = quantity(t, j) {
+                    interval = n.checked_mul(interval)?;
+                    (unit, end)
+                } else if let Some((unit, n)) = TimeUnit::parse(word).filter(|_| !word.ends_with('s')) {
+                    interval *= n;
+                    (unit, j + 1)
+                } else if word == "weekday" {
+                    weekdays = Weekday::ALL[..5].to_vec();
+                    (TimeUnit::Week, j + 1)
+                } else if word == "weekend" {
+                    weekdays = Weekday::ALL[5..].to_vec();
+                    (TimeUnit::Week, j + 1)
+                } else if let Some(part) = part_of_day(word) {
+                    time = Some(part);
+                    (TimeUnit::Day, j + 1)
+                } else {
+                    let end = weekday_list(t, j, &mut weekdays);
+                    if weekdays.is_empty() {
+                        return None;
+                    }
+                    (TimeUnit::Week, end)
+                }
+            }
+            // "on Mondays and Fridays"
+            word if word.ends_with("days") && Weekday::parse(word).is_some() => {
+                let end = weekday_list(t, i, &mut weekdays);
+                (TimeUnit::Week, end)
+            }
+            _ => return None,
+        };
+        // "every 0 days" never recurs
+        if interval == 0 {
+            return None;
+        }
+        if let Some((at, e)) = self.time_after(t, end) {
+            time = Some(at);
+            end = e;
+        }
+
+        let date = self.reference.date;
+        let first_date = weekdays.iter()
+            .map(|d| date.checked_next_weekday(*d))
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .min()
+            .unwrap_or(date);
+        let first = match (unit, time) {
+            (TimeUnit::Minute | TimeUnit::Hour, None) => self.reference,
+            (_, time) => DateTime { date: first_date, time },
+        };
+        weekdays.sort();
+        Some((end, TemporalValue::Recurrence(Recurrence { unit, interval, weekdays, time, first })))
+    }
+
+    /// "in two weeks", "three days ago", "an hour from now"
+    fn relative(&self, t: &[&str], i: usize) -> Option<(usize, TemporalValue)> {
+        if t[i] == "in" {
+            let (amount, unit, end) = quantity(t, i + 1)?;
+            let at = Duration { amount, unit }.offset(self.reference, 1)?;
+            return Some((end, TemporalValue::Instant(at)));
+        }
+        let (amount, unit, end) = quantity(t, i)?;
+        let duration = Duration { amount, unit };
+        let (end, sign) = match t.get(end..).unwrap_or_default() {
+            ["ago", ..] => (end + 1, -1),
+            ["from", "now", ..] => (end + 2, 1),
+            ["later" | "hence", ..] => (end + 1, 1),
+            _ => return None,
+        };
+        Some((end, TemporalValue::Instant(duration.offset(self.reference, sign)?)))
+    }
+
+    /// "for two hours", "half an hour"
+    fn duration(&self, t: &[&str], i: usize) -> Option<(usize, TemporalValue)> {
+        let start = if DURATION_PREPOSITIONS.contains(&t[i]) { i + 1 } else { i };
+        let (amount, unit, end) = quantity(t, start)?;
+        Some((end, TemporalValue::Duration(Duration { amount, unit })))
+    }
+
+    /// A date, a time, or both ("tomorrow at 3pm", "3pm on Friday")
+    fn instant(&self, t: &[&str], i: usize) -> Option<(usize, TemporalValue)> {
+        if let Some((mut at, mut end)) = self.date(t, i) {
+            if let Some((time, e)) = self.time_after(t, end) {
+                at.time = Some(within(at.time, time, &t[end..e]));
+                end = e;
+            }
+            return Some((end, TemporalValue::Instant(at)));
+        }
+        let after_at = i > 0 && t[i - 1] == "at";
+        let clock = clock_time(t, i);
+        let (mut time, clock_end) = clock.or_else(|| after_at.then(|| bare_hour(t, i)).flatten())?;
+        let mut date = self.reference.date;
+        let mut end = clock_end;
+        let on = if t.get(end) == Some(&"on") { end + 1 } else { end };
+        if let Some((at, e)) = self.date(t, on) {
+            date = at.date;
+            time = within(at.time, time, &t[i..clock_end]);
+            end = e;
+        } else if clock.is_none() {
+            // A bare hour needs a date after it: "at 5 on Monday", not "at 5 apples"
+            return None;
+        }
+        Some((end, TemporalValue::Instant(DateTime::new(date, time))))
+    }
+
+    /// A time following a date ("at 3pm", "morning", "in the evening")
+    fn time_after(&self, t: &[&str], i: usize) -> Option<(Time, usize)> {
+        match t.get(i..).unwrap_or_default() {
+            ["at", ..] => clock_time(t, i + 1).or_else(|| bare_hour(t, i + 1)),
+            ["in", "the", part, ..] => part_of_day(part).map(|time| (time, i + 3)),
+            [part, ..] if part_of_day(part).is_some() => part_of_day(part).map(|time| (time, i + 1)),
+            _ => clock_time(t, i),
+        }
+    }
+
+    /// A date word or calendar date, `None` if it falls past the years an `i32` holds
+    fn date(&self, t: &[&str], i: usize) -> Option<(DateTime, usize)> {
+        let today = self.reference.date;
+        let day = |date: Date, end: usize| Some((DateTime::from(date), end));
+        match t.get(i..).unwrap_or_default() {
+            ["now", ..] => Some((self.reference, i + 1)),
+            ["today", ..] => day(today, i + 1),
+            ["tonight", ..] => Some((DateTime::new(today, part_of_day("night")?), i + 1)),
+            ["tomorrow", ..] => day(today.checked_add_days(1)?, i + 1),
+            ["yesterday", ..] => day(today.checked_add_days(-1)?, i + 1),
+            ["day", "after", "tomorrow", ..] => day(today.checked_add_days(2)?, i + 3),
+            ["day", "before", "yesterday", ..] => day(today.checked_add_days(-2)?, i + 3),
+            ["this", part, ..] if part_of_day(part).is_some() => Some((DateTime::new(today, part_of_day(part)?), i + 2)),
+            [which @ ("next" | "this" | "last" | "coming"), what, ..] => {
+                let shift: i64 = match *which { "next" | "coming" => 1, "last" => -1, _ => 0 };
+                let date = if let Some(weekday) = Weekday::parse(what).filter(|_| !what.ends_with('s')) {
+                    match *which {
+                        "this" => today.checked_week_start()?.checked_add_days(weekday.index())?,
+                        "last" => {
+                            let yesterday = today.checked_add_days(-1)?;
+                            yesterday.checked_add_days(-(yesterday.weekday().index() - weekday.index()).rem_euclid(7))?
+                        }
+                        _ => today.checked_add_days(1)?.checked_next_weekday(weekday)?,
+                    }
+                } else {
+                    match *what {
+                        "week" => today.checked_week_start()?.checked_add_days(7 * shift)?,
+                        "weekend" => today.checked_week_start()?.checked_add_days(5 + 7 * shift.max(0))?,
+                        "month" => Date::new(today.year, today.month, 1)?.checked_add_months(shift)?,
+                        "year" => Date::new(today.year.checked_add(shift as i32)?, 1, 1)?,
+                        _ => return None,
+                    }
+                };
+                day(date, i + 2)
+            }
+            [word, ..] if !word.ends_with('s') && Weekday::parse(word).is_some() => {
+                day(today.checked_next_weekday(Weekday::parse(word)?)?, i + 1)
+            }
+            [word, ..] if numeric_date(word).is_some() => day(numeric_date(word)?, i + 1),
+            _ => self.calendar_date(t, i).map(|(date, end)| (DateTime::from(date), end)),
+        }
+    }
+
+    /// "March 5", "March 5th 2027", "5 March", "5th of March"
+    fn calendar_date(&self, t: &[&str], i: usize) -> Option<(Date, usize)> {
+        let word = *t.get(i)?;
+        let (month, day, mut end) = if let Some(month) = month_number(word) {
+            (month, day_number(t.get(i + 1)?)?, i + 2)
+        } else {
+            let day = day_number(word)?;
+            let m = if t.get(i + 1) == Some(&"of") { i + 2 } else { i + 1 };
+            (month_number(t.get(m)?)?, day, m + 1)
+        };
+        let year = t.get(end).and_then(|y| year_number(y));
+        if year.is_some() {
+            end += 1;
+        }
+        let today = self.reference.date;
+        let date = match year {
+            Some(year) => Date::new(year, month, day)?,
+            // Without a year: the next such date
+            None => Date::new(today.year, month, day)
+                .filter(|d| *d >= today)
+                .or_else(|| Date::new(today.year.checked_add(1)?, month, day))?,
+        };
+        Some((date, end))
+    }
+}
+
+/// Weekdays joined by "and"/"or" starting at `i`; returns the end
+fn weekday_list(t: &[&str], i: usize, weekdays: &mut Vec<Weekday>) -> usize {
+    let mut end = i;
+    let mut j = i;
+    while let Some(day) = t.get(j).and_then(|w| Weekday::parse(w)) {
+        if !weekdays.contains(&day) {
+            weekdays.push(day);
+        }
+        end = j + 1;
+        j = if matches!(t.get(end), Some(&"and") | Some(&"or")) { end + 1 } else { end };
+    }
+    end
+}
+
+/// A count of units ("two weeks", "an hour", "a couple of days", "half an hour")
+fn quantity(t: &[&str], i: usize) -> Option<(u32, TimeUnit, usize)> {
+    if let ["half", "a" | "an", "hour", ..] = t.get(i..)? {
+        return Some((30, TimeUnit::Minute, i + 3));
+    }
+    let (n, j) = match t.get(i..)? {
+        ["a", "couple", "of", ..] => (2, i + 3),
+        ["a" | "an", ..] => (1, i + 1),
+        [word, ..] => (number(word)?, i + 1),
+        [] => return None,
+    };
+    let (unit, factor) = TimeUnit::parse(t.get(j)?)?;
+    Some((n.checked_mul(factor)?, unit, j + 1))
+}
+
+fn number(word: &str) -> Option<u32> {
+    word.parse().ok().or_else(|| NUMBER_WORDS.iter().find(|(w, _)| *w == word).map(|(_, n)| *n))
+}
+
+/// Month of a name or abbreviation, except "mar", an ordinary word in lowercase text
+fn month_number(word: &str) -> Option<u8> {
+    names::month_number(word).filter(|_| word != "mar")
+}
+
+/// An ordinal spacing a recurrence: "second", "3rd", "other"
+fn ordinal(word: &str) -> Option<u32> {
+    ORDINALS.iter().find(|(w, _)| *w == word).map(|(_, n)| *n)
+        .or_else(|| day_number(word).filter(|_| word.ends_with(char::is_alphabetic)).map(u32::from))
+}
+
+/// Typical time of a part of the day
+fn part_of_day(word: &str) -> Option<Time> {
+    match word {
+        "morning" => Time::new(9, 0),
+        "noon" | "midday" => Time::new(12, 0),
+        "afternoon" => Time::new(15, 0),
+        "evening" => Time::new(18, 0),
+        "night" | "tonight" => Time::new(21, 0),
+        "midnight" => Time::new(0, 0),
+        _ => None,
+    }
+}
+
+/// A clock time: "3pm", "3:30pm", "15:00", "3 pm", "3 p.m.", "3 o'clock", "noon"
+fn clock_time(t: &[&str], i: usize) -> Option<(Time, usize)> {
+    let word = *t.get(i)?;
+    if matches!(word, "noon" | "midday" | "midnight") {
+        return Some((part_of_day(word)?, i + 1));
+    }
+    let (body, meridiem, end) = if let Some(body) = word.strip_suffix("pm") {
+        (body, Some(true), i + 1)
+    } else if let Some(body) = word.strip_suffix("am") {
+        (body, Some(false), i + 1)
+    } else {
+        match t.get(i + 1..).unwrap_or_default() {
+            ["pm", ..] => (word, Some(true), i + 2),
+            ["am", ..] => (word, Some(false), i + 2),
+            ["p", "m", ..] => (word, Some(true), i + 3),
+            ["a", "m", ..] => (word, Some(false), i + 3),
+            ["o'clock", ..] => (word, None, i + 2),
+            _ if word.contains(':') => (word, None, i + 1),
+            _ => return None,
+        }
+    };
+    let (hour, minute) = match body.split_once(':') {
+        Some((h, m)) if m.len() == 2 => (h.parse::<u8>().ok()?, m.parse::<u8>().ok()?),
+        Some(_) => return None,
+        None => (body.parse::<u8>().ok()?, 0),
+    };
+    let hour = match meridiem {
+        Some(_) if !(1..=12).contains(&hour) => return None,
+        Some(true) => hour % 12 + 12,
+        Some(false) => hour % 12,
+        None => hour,
+    };
+    Some((Time::new(hour, minute)?, end))
+}
+
+/// A clock time read within a part of the day: "tonight at 8" is 20:00
+/// unless written "8am"
+fn within(part: Option<Time>, time: Time, written: &[&str]) -> Time {
+    let meridiem = written.iter().any(|w| w.ends_with("am") || w.ends_with("pm") || *w == "m");
+    match part {
+        Some(part) if part.hour >= 12 && time.hour < 12 && !meridiem => Time { hour: time.hour + 12, ..time },
+        _ => time,
+    }
+}
+
+/// A bare hour after "at" ("at 5"), taken as a time only next to a date
+fn bare_hour(t: &[&str], i: usize) -> Option<(Time, usize)> {
+    let hour: u8 = t.get(i)?.parse().ok()?;
+    if t.get(i + 1).is_some_and(|w| TimeUnit::parse(w).is_some()) {
+        return None;
+    }
+    Some((Time::new(hour, 0)?, i + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tagger() -> TemporalTagger {
+        // A Friday
+        TemporalTagger::new(DateTime::new(Date::new(2026, 10, 16).unwrap(), Time::new(10, 0).unwrap()))
+    }
+
+    fn instant(text: &str) -> String {
+        match &tagger().tag(text)[0].value {
+            TemporalValue::Instant(at) => at.to_string(),
+            other => panic!("{}: {:?}", text, other),
+        }
+    }
+
+    #[test]
+    fn test_instants() {
+        assert_eq!(instant("tomorrow at 3pm"), "2026-10-17T15:00");
+        assert_eq!(instant("in two weeks"), "2026-10-30T10:00");
+        assert_eq!(instant("in 90 minutes"), "2026-10-16T11:30");
+        assert_eq!(instant("three days ago"), "2026-10-13T10:00");
+        assert_eq!(instant("2026-10-16"), "2026-10-16");
+        assert_eq!(instant("10/20/2026"), "2026-10-20");
+        assert_eq!(instant("on Monday"), "2026-10-19");
+        assert_eq!(instant("next Friday"), "2026-10-23");
+        assert_eq!(instant("last Friday"), "2026-10-09");
+        assert_eq!(instant("this Wednesday"), "2026-10-14");
+        assert_eq!(instant("next month"), "2026-11-01");
+        assert_eq!(instant("March 5th"), "2027-03-05");
+        assert_eq!(instant("the 5th of November 2026"), "2026-11-05");
+        assert_eq!(instant("tomorrow morning"), "2026-10-17T09:00");
+        assert_eq!(instant("at 5 on Monday"), "2026-10-19T05:00");
+        assert_eq!(instant("tomorrow at 5"), "2026-10-17T05:00");
+        assert!(tagger().tag("buy it at 5 apples a pound").is_empty());
+        assert!(tagger().tag("meet at 5").is_empty());
+        assert_eq!(instant("3:30 p.m."), "2026-10-16T15:30");
+        assert_eq!(instant("the day after tomorrow"), "2026-10-18");
+    }
+
+    #[test]
+    fn test_durations() {
+        let found = tagger().tag("wait for two hours then rest half an hour");
+        let durations: Vec<String> = found.iter()
+            .filter_map(|e| match &e.value { TemporalValue::Duration(d) => Some(d.iso()), _ => None })
+            .collect();
+        assert_eq!(durations, vec!["PT2H", "PT30M"]);
+        assert_eq!(found[0].text, "for two hours");
+        assert_eq!(Duration { amount: 2, unit: TimeUnit::Week }.to_string(), "2 weeks");
+    }
+
+    #[test]
+    fn test_recurrences() {
+        let recurrence = |text: &str| match &tagger().tag(text)[0].value {
+            TemporalValue::Recurrence(r) => r.clone(),
+            other => panic!("{}: {:?}", text, other),
+        };
+        let monday = recurrence("every Monday");
+        assert_eq!(monday.rule(), "FREQ=WEEKLY;BYDAY=MO");
+        assert_eq!(monday.first.to_string(), "2026-10-19");
+
+        let pair = recurrence("every other Tuesday and Thursday at 9am");
+        assert_eq!(pair.rule(), "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH;BYHOUR=9;BYMINUTE=0");
+        let dates: Vec<String> = pair.occurrences(3).iter().map(|d| d.date.to_string()).collect();
+        assert_eq!(dates, vec!["2026-10-20", "2026-10-22", "2026-11-03"]);
+
+        assert_eq!(recurrence("daily at noon").rule(), "FREQ=DAILY;BYHOUR=12;BYMINUTE=0");
+        assert_eq!(recurrence("every 3 days").rule(), "FREQ=DAILY;INTERVAL=3");
+        assert_eq!(recurrence("every second Monday").rule(), "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO");
+        assert_eq!(recurrence("every third week").rule(), "FREQ=WEEKLY;INTERVAL=3");
+        assert_eq!(recurrence("every 2nd month").rule(), "FREQ=MONTHLY;INTERVAL=2");
+        assert_eq!(recurrence("on Fridays").weekdays, vec![Weekday::Friday]);
+        assert_eq!(recurrence("every weekday").weekdays.len(), 5);
+        let monthly = recurrence("every month");
+        assert_eq!(monthly.occurrences(2)[1].to_string(), "2026-11-16");
+    }
+
+    #[test]
+    fn test_spans_and_prepositions() {
+        let found = tagger().tag("Send the report on Friday, then call me in an hour.");
+        assert_eq!(found.len(), 2);
+        assert_eq!((found[0].start, found[0].end), (4, 5));
+        assert_eq!(found[0].preposition.as_deref(), Some("on"));
+        assert!(found[0].covers(3));
+        assert_eq!(found[1].text, "in an hour");
+        assert!(tagger().tag("delete 3 files and may the force be with you").is_empty());
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        for text in ["in 3000000000 fortnights", "every 2147483648 fortnights", "every other 3000000000 days",
+                     "in 4000000000 years", "every 0 days"] {
+            assert!(tagger().tag(text).iter().all(|e| !matches!(e.value, TemporalValue::Instant(_) | TemporalValue::Recurrence(_))), "{}", text);
+        }
+        assert_eq!(instant("tonight at 8"), "2026-10-16T20:00");
+        assert_eq!(instant("at 8 tonight"), "2026-10-16T20:00");
+        assert_eq!(instant("tonight at 8am"), "2026-10-16T08:00");
+        assert_eq!(Date::new(2026, 1, 1).unwrap().checked_add_months(i64::MAX), None);
+        assert!(tagger().tag("257/1/2026").is_empty());
+        assert!(tagger().tag("1/1/3000000000").is_empty());
+    }
+
+    #[test]
+    fn test_reference_at_calendar_limits() {
+        let resolves = |reference: Date, text: &str| {
+            TemporalTagger::new(reference).tag(text).iter()
+                .any(|e| matches!(e.value, TemporalValue::Instant(_) | TemporalValue::Recurrence(_)))
+        };
+        let last = Date::new(i32::MAX, 12, 31).unwrap();
+        for text in ["tomorrow", "next week", "next month", "next year", "this weekend", "every Monday", "on Friday"] {
+            resolves(last, text);
+        }
+        assert!(!resolves(last, "tomorrow"));
+        assert!(!resolves(last, "next year"));
+        let first = Date::new(i32::MIN, 1, 1).unwrap();
+        for text in ["yesterday", "last month", "last year", "this week"] {
+            resolves(first, text);
+        }
+        assert!(!resolves(first, "yesterday"));
+        assert!(!resolves(first, "last year"));
+    }
+}
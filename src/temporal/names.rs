@@ -0,0 +1,46 @@
+//! Month names, day and year numbers and numeric dates as written, shared
+//! by the temporal tagger and named-entity recognition. Words are lowercase.
+
+use super::calendar::Date;
+
+/// Month names with their number: the twelve full names, then abbreviations
+pub(crate) const MONTHS: &[(&str, u8)] = &[
+    ("january", 1), ("february", 2), ("march", 3), ("april", 4), ("may", 5), ("june", 6),
+    ("july", 7), ("august", 8), ("september", 9), ("october", 10), ("november", 11), ("december", 12),
+    ("jan", 1), ("feb", 2), ("mar", 3), ("apr", 4), ("jun", 6), ("jul", 7), ("aug", 8),
+    ("sep", 9), ("sept", 9), ("oct", 10), ("nov", 11), ("dec", 12),
+];
+
+/// Number of a month name or abbreviation
+pub(crate) fn month_number(word: &str) -> Option<u8> {
+    MONTHS.iter().find(|(m, _)| *m == word).map(|(_, n)| *n)
+}
+
+/// Day of the month, with an optional ordinal suffix ("5", "21st")
+pub(crate) fn day_number(word: &str) -> Option<u8> {
+    let digits = word.trim_end_matches(|c: char| c.is_alphabetic());
+    if !matches!(&word[digits.len()..], "" | "st" | "nd" | "rd" | "th") || digits.is_empty() || digits.len() > 2 {
+        return None;
+    }
+    digits.parse().ok().filter(|d| (1..=31).contains(d))
+}
+
+/// A four-digit year from 1000 to 2999
+pub(crate) fn year_number(word: &str) -> Option<i32> {
+    if word.len() != 4 || !word.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    word.parse().ok().filter(|y| (1000..3000).contains(y))
+}
+
+/// "2026-10-16", "10/16/2026" (month first unless impossible)
+pub(crate) fn numeric_date(word: &str) -> Option<Date> {
+    if let Some(date) = Date::parse_iso(word) {
+        return Some(date);
+    }
+    let parts: Vec<u32> = word.split('/').map(|p| p.parse().ok()).collect::<Option<_>>()?;
+    let [a, b, year] = parts[..] else { return None };
+    let year = i32::try_from(if year < 100 { 2000 + year } else { year }).ok()?;
+    let (a, b) = (u8::try_from(a).ok()?, u8::try_from(b).ok()?);
+    Date::new(year, a, b).or_else(|| Date::new(year, b, a))
+}
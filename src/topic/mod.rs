@@ -0,0 +1,295 @@
+//! # Topic Profiles
+//!
+//! Summarises what a document is about, for routing and tagging:
+//!
+//! - **actions**: detected verbs aggregated by functional category and verb
+//!   group, weighted by detection confidence
+//! - **keywords**: noun phrases weighted by inverse word frequency, so
+//!   "server" outweighs "thing"; resolved pronouns count for their antecedent
+//! - **domains**: `DomainRegistry` plugins whose context keywords occur
+//!
+//! Categories and domains are ranked together, each with the words that
+//! support it. Scores are relative to the strongest topic (1.0).
+//!
+//! ```
+//! use nl_sre_english::{SemanticDisambiguator, DomainRegistry};
+//!
+//! let dis = SemanticDisambiguator::new();
+//! let profile = dis.topics("Please send the invoice and tell the client.", &DomainRegistry::new());
+//! assert_eq!(profile.top().unwrap().name, "Communication");
+//! ```
+
+use std::collections::HashMap;
+
+use crate::DetectedAction;
+use crate::disambiguator::SemanticDisambiguator;
+use crate::domain::DomainRegistry;
+use crate::phf;
+use crate::verbs::{CategoryKey, GroupKey};
+
+include!(concat!(env!("OUT_DIR"), "/frequency_tables.rs"));
+
+/// Zipf value above the most frequent word; a keyword weighs the difference
+const MAX_ZIPF: f64 = 8.0;
+
+/// Zipf value of a dictionary word missing from `word_frequencies.tsv`
+const DICTIONARY_ZIPF: f64 = 4.3;
+
+/// Zipf value of a word missing from the dictionary
+const RARE_ZIPF: f64 = 3.0;
+
+/// Weight of a domain keyword occurrence (an action weighs its confidence)
+const DOMAIN_WEIGHT: f64 = 1.5;
+
+/// Keywords reported
+const MAX_KEYWORDS: usize = 10;
+
+/// What a topic is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopicKind {
+    /// Functional category of the actions
+    Category(CategoryKey),
+    /// Verb group of the actions
+    Group(GroupKey),
+    /// A registered domain, by index in the registry
+    Domain(usize),
+}
+
+/// A word supporting a topic
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evidence {
+    /// Word as written (lowercase)
+    pub text: String,
+    /// Sentence index
+    pub sentence: usize,
+    /// Token index in the sentence
+    pub position: usize,
+    /// Contribution to the topic
+    pub weight: f64,
+}
+
+/// A ranked topic
+#[derive(Debug, Clone, PartialEq)]
+pub struct Topic {
+    /// Category, group or domain name
+    pub name: String,
+    pub kind: TopicKind,
+    /// Weight relative to the strongest topic (0.0 - 1.0)
+    pub score: f64,
+    /// Sum of the evidence weights
+    pub weight: f64,
+    /// Supporting words, in order of appearance
+    pub evidence: Vec<Evidence>,
+}
+
+/// A salient noun phrase
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyword {
+    /// Phrase without its determiner ("red file")
+    pub phrase: String,
+    /// Head noun lemma
+    pub head: String,
+    /// Mentions, counting resolved pronouns
+    pub count: usize,
+    /// Count times the inverse frequency of the head
+    pub weight: f64,
+}
+
+/// Topics, verb groups and keywords of a document
+#[derive(Debug, Clone, Default)]
+pub struct TopicProfile {
+    /// Categories and domains, strongest first
+    pub topics: Vec<Topic>,
+    /// Verb groups, strongest first
+    pub groups: Vec<Topic>,
+    /// Salient noun phrases, heaviest first
+    pub keywords: Vec<Keyword>,
+}
+
+impl TopicProfile {
+    /// Strongest topic
+    pub fn top(&self) -> Option<&Topic> {
+        self.topics.first()
+    }
+
+    /// Topic or group by name (case-insensitive)
+    pub fn topic(&self, name: &str) -> Option<&Topic> {
+        self.topics.iter().chain(&self.groups).find(|t| t.name.eq_ignore_ascii_case(name))
+    }
+
+    /// One line per topic with its evidence ("Communication 1.00: send, tell")
+    pub fn explain(&self) -> Vec<String> {
+        self.topics.iter()
+            .map(|t| {
+                let words: Vec<&str> = t.evidence.iter().map(|e| e.text.as_str()).collect();
+                format!("{} {:.2}: {}", t.name, t.score, words.join(", "))
+            })
+            .collect()
+    }
+}
+
+impl SemanticDisambiguator {
+    /// Topic profile of a text, matching domains from `domains`
+    pub fn topics(&self, text: &str, domains: &DomainRegistry) -> TopicProfile {
+        let sentences = self.grammar().split_sentences(text);
+        let discourse = self.lexicon().resolve_references(&sentences);
+        let tokens: Vec<Vec<String>> = sentences.iter().map(|s| self.grammar().tokenize(s)).collect();
+        let actions: Vec<(usize, DetectedAction)> = sentences.iter().enumerate()
+            .flat_map(|(n, s)| self.detect_actions(s).into_iter().map(move |a| (n, a)))
+            // Auxiliaries and a polite "please" say nothing about the topic
+            .filter(|(_, a)| !(self.grammar().is_auxiliary(&a.verb) || (a.verb == "please" && a.position == 0)))
+            .collect();
+
+        let verbs = self.verbs();
+        let dictionary = self.dictionary();
+        // Zipf values are log frequencies, so this is a log inverse frequency
+        let idf = |word: &str| {
+            let zipf = phf::index(word, &ZIPF_DISPLACEMENTS, &ZIPF_SLOTS)
+                .filter(|&i| ZIPF[i].0 == word)
+                .map(|i| ZIPF[i].1)
+                .unwrap_or(if dictionary.is_valid(word) { DICTIONARY_ZIPF } else { RARE_ZIPF });
+            MAX_ZIPF - zipf
+        };
+
+        // Actions by category and group
+        let mut categories: Vec<Topic> = Vec::new();
+        let mut groups: Vec<Topic> = Vec::new();
+        for (n, action) in &actions {
+            let evidence = Evidence {
+                text: action.verb.clone(),
+                sentence: *n,
+                position: action.position,
+                weight: action.confidence,
+            };
+            let category = action.category_key();
+            let group = action.group_key();
+            add_evidence(&mut categories, verbs.registry().category_name(category), TopicKind::Category(category), evidence.clone());
+            add_evidence(&mut groups, verbs.registry().group_name(group), TopicKind::Group(group), evidence);
+        }
+
+        // Domains whose context keywords occur
+        for (d, domain) in domains.domains().iter().enumerate() {
+            for keyword in domain.get_context_keywords() {
+                let words: Vec<String> = keyword.to_lowercase().split_whitespace().map(str::to_string).collect();
+                if words.is_empty() {
+                    continue;
+                }
+                for (n, sentence) in tokens.iter().enumerate() {
+                    for start in 0..sentence.len() {
+                        let Some(window) = sentence.get(start..start + words.len()) else { break };
+                        let matches = window.iter().zip(&words)
+                            .all(|(token, word)| token == word || self.grammar().noun_lemma(token) == *word);
+                        if matches {
+                            let evidence = Evidence { text: window.join(" "), sentence: n, position: start, weight: DOMAIN_WEIGHT };
+                            add_evidence(&mut categories, domain.name(), TopicKind::Domain(d), evidence);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Noun phrases, with pronouns counted for their antecedents
+        let mut keywords: HashMap<String, Keyword> = HashMap::new();
+        let mentions = discourse.mentions.iter()
+            .chain(discourse.references.iter().filter_map(|r| discourse.antecedent(r).filter(|_| !r.possessive)));
+        for mention in mentions {
+            let phrase = mention.text.split_once(' ')
+                .filter(|(first, _)| mention.entity.is_none() && (self.grammar().is_article(first) || is_determiner(first)))
+                .map_or(mention.text.as_str(), |(_, rest)| rest);
+            let keyword = keywords.entry(phrase.to_string()).or_insert_with(|| Keyword {
+                phrase: phrase.to_string(),
+                head: mention.head.clone(),
+                count: 0,
+                weight: 0.0,
+            });
+            keyword.count += 1;
+            keyword.weight += idf(&mention.head);
+        }
+        let mut keywords: Vec<Keyword> = keywords.into_values().filter(|k| k.weight > 0.0).collect();
+        keywords.sort_by(|a, b| b.weight.total_cmp(&a.weight).then_with(|| a.phrase.cmp(&b.phrase)));
+        keywords.truncate(MAX_KEYWORDS);
+
+        TopicProfile { topics: rank(categories), groups: rank(groups), keywords }
+    }
+}
+
+/// Possessive and demonstrative determiners dropped from keyword phrases
+fn is_determiner(word: &str) -> bool {
+    matches!(word, "this" | "that" | "these" | "those" | "my" | "your" | "his" | "her" | "its" | "our" | "their" | "some" | "any")
+}
+
+fn add_evidence(topics: &mut Vec<Topic>, name: &str, kind: TopicKind, evidence: Evidence) {
+    let index = match topics.iter().position(|t| t.kind == kind) {
+        Some(index) => index,
+        None => {
+            topics.push(Topic { name: name.to_string(), kind, score: 0.0, weight: 0.0, evidence: Vec::new() });
+            topics.len() - 1
+        }
+    };
+    topics[index].weight += evidence.weight;
+    topics[index].evidence.push(evidence);
+}
+
+/// Sort by weight and score relative to the strongest
+fn rank(mut topics: Vec<Topic>) -> Vec<Topic> {
+    topics.sort_by(|a, b| b.weight.total_cmp(&a.weight).then_with(|| a.name.cmp(&b.name)));
+    let strongest = topics.first().map_or(1.0, |t| t.weight);
+    for topic in &mut topics {
+        topic.score = topic.weight / strongest;
+        topic.evidence.sort_by_key(|e| (e.sentence, e.position));
+    }
+    topics
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DomainPlugin, DomainRegistry, FunctionalCategory, SemanticDisambiguator, ValidatedQuantity, VerbGroup};
+    use crate::verbs::CategoryKey;
+    use super::TopicKind;
+
+    struct Billing;
+
+    impl DomainPlugin for Billing {
+        fn name(&self) -> &str { "billing" }
+        fn get_special_units(&self) -> Vec<&str> { vec![] }
+        fn get_context_keywords(&self) -> Vec<&str> { vec!["invoice", "refund", "credit card"] }
+        fn sanitize_quantity(&self, _: f64, _: &str) -> Option<ValidatedQuantity> { None }
+    }
+
+    #[test]
+    fn test_topic_profile() {
+        let dis = SemanticDisambiguator::new();
+        let mut domains = DomainRegistry::new();
+        domains.register(Billing);
+
+        let ticket = "I was charged twice on my credit card. Please send me a refund for the invoice. \
+                      Tell the manager and give it back today.";
+        let profile = dis.topics(ticket, &domains);
+
+        let billing = profile.topic("billing").unwrap();
+        assert_eq!(billing.kind, TopicKind::Domain(0));
+        let words: Vec<&str> = billing.evidence.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(words, vec!["credit card", "refund", "invoice"]);
+        assert_eq!(profile.top().unwrap().name, "billing");
+
+        let transfer = profile.topic("transfer").unwrap();
+        assert_eq!(transfer.kind, TopicKind::Category(CategoryKey::Builtin(FunctionalCategory::Transfer)));
+        assert!(transfer.evidence.iter().any(|e| e.text == "send"));
+        let giving_back = profile.topic(VerbGroup::Return.name()).unwrap();
+        assert!(giving_back.evidence.iter().any(|e| e.text == "give back"));
+        assert!(profile.topics.windows(2).all(|w| w[0].score >= w[1].score));
+
+        // "it" counts for the invoice
+        let invoice = profile.keywords.iter().find(|k| k.head == "invoice").unwrap();
+        assert_eq!(invoice.phrase, "invoice");
+        assert_eq!(invoice.count, 2);
+
+        // Both in the dictionary, the rarer word weighs more
+        let profile = dis.topics("Check the thing on the server.", &domains);
+        let weight = |head: &str| profile.keywords.iter().find(|k| k.head == head).unwrap().weight;
+        assert!(weight("server") > 1.5 * weight("thing"));
+        let profile = dis.topics("Check the data in the database.", &domains);
+        let weight = |head: &str| profile.keywords.iter().find(|k| k.head == head).unwrap().weight;
+        assert!(weight("database") > weight("data"));
+    }
+}
@@ -18,8 +18,14 @@
 //! assert_eq!(rules[0].to_prolog(), "eat(cat, fish).");
 //! ```
 
+use std::ops::Range;
+use std::sync::{Arc, RwLockReadGuard};
+
 use crate::grammar::EnglishGrammar;
-use crate::verbs::VerbDatabase;
+use crate::lexicon::Lexicon;
+use crate::morphology::Degree;
+use crate::verbs::{VerbDatabase, Frame, ThematicRole};
+use crate::verbs::frames::SlotKind;
 
 /// Sentence type detected from surface form
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -60,6 +66,17 @@ impl PirsRule {
     }
 }
 
+impl PirsRule {
+    /// Render the head as a nested term ("go(home)") for use as an argument
+    fn term(&self) -> String {
+        if self.args.is_empty() {
+            self.head.clone()
+        } else {
+            format!("{}({})", self.head, self.args.join(", "))
+        }
+    }
+}
+
 impl std::fmt::Display for PirsRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.to_prolog())
@@ -146,6 +163,12 @@ const COMMON_ADJECTIVES: &[&str] = &[
     "previous", "current", "modern", "ancient", "recent", "early", "late",
 ];
 
+/// Common English adverbs without the -ly ending
+const COMMON_ADVERBS: &[&str] = &[
+    "then", "now", "again", "also", "too", "soon", "later", "first", "already",
+    "still", "just", "here", "there", "often", "always", "sometimes",
+];
+
 /// WH-question words
 const WH_WORDS: &[&str] = &[
     "who", "what", "where", "when", "why", "how", "which", "whom", "whose",
@@ -153,30 +176,49 @@ const WH_WORDS: &[&str] = &[
 
 /// English to PIRS Transpiler
 pub struct Transpiler {
-    grammar: EnglishGrammar,
-    verbs: VerbDatabase,
+    lexicon: Arc<Lexicon>,
     adjective_set: std::collections::HashSet<String>,
 }
 
 impl Transpiler {
     /// Create a new transpiler
     pub fn new() -> Self {
+        Self::with_lexicon(Arc::new(Lexicon::new()))
+    }
+
+    /// Create a transpiler on a shared lexicon
+    pub fn with_lexicon(lexicon: Arc<Lexicon>) -> Self {
         let mut adjective_set = std::collections::HashSet::new();
         for adj in COMMON_ADJECTIVES {
             adjective_set.insert(adj.to_string());
         }
         Self {
-            grammar: EnglishGrammar::new(),
-            verbs: VerbDatabase::with_builtin(),
+            lexicon,
             adjective_set,
         }
     }
 
+    /// Get the shared lexicon
+    pub fn lexicon(&self) -> &Arc<Lexicon> {
+        &self.lexicon
+    }
+
+    fn grammar(&self) -> &EnglishGrammar {
+        self.lexicon.grammar()
+    }
+
+    fn verbs(&self) -> RwLockReadGuard<'_, VerbDatabase> {
+        self.lexicon.verbs()
+    }
+
     /// Transpile English text to PIRS rules
     ///
     /// Splits the text into sentences and converts each one.
+    ///
+    /// Pronouns are resolved across sentences first, so "Open the file.
+    /// Then delete it." gives `delete(file)`.
     pub fn to_pirs(&self, text: &str) -> Vec<PirsRule> {
-        let mut rules = Vec::new();
+        let mut sentences = Vec::new();
 
         let mut start = 0;
         let chars: Vec<char> = text.chars().collect();
@@ -194,9 +236,7 @@ impl Transpiler {
                         '!' => SentenceType::Command,
                         _ => SentenceType::Declarative,
                     };
-                    if let Some(rule) = self.transpile_sentence(sentence, stype) {
-                        rules.push(rule);
-                    }
+                    sentences.push((sentence, stype));
                 }
                 start = i + 1;
             }
@@ -209,9 +249,18 @@ impl Transpiler {
             let remaining = text[byte_start..].trim();
             if !remaining.is_empty() {
                 let stype = self.detect_sentence_type(remaining);
-                if let Some(rule) = self.transpile_sentence(remaining, stype) {
-                    rules.push(rule);
-                }
+                sentences.push((remaining, stype));
+            }
+        }
+
+        let texts: Vec<&str> = sentences.iter().map(|(s, _)| *s).collect();
+        let discourse = self.lexicon.resolve_references(&texts);
+        let mut rules = Vec::new();
+        for (n, (sentence, stype)) in sentences.into_iter().enumerate() {
+            let (raw_tokens, substituted) = discourse.substitute_spans(n, &self.grammar().tokenize(sentence));
+            let tokens = self.tag_tokens(&raw_tokens, &substituted);
+            if let Some(rule) = self.parse_tokens(&tokens, stype) {
+                rules.push(rule);
             }
         }
 
@@ -248,14 +297,14 @@ impl Transpiler {
             let words: Vec<&str> = lower.split_whitespace().collect();
             if words.len() >= 2 {
                 let second = words[1];
-                if self.grammar.is_pronoun(second) || !self.verbs.is_verb(second) {
+                if self.grammar().is_pronoun(second) || !self.verbs().is_verb(second) {
                     return SentenceType::Question;
                 }
             }
         }
 
         // Imperative: starts with a verb (no subject before it)
-        if self.verbs.is_verb(first_word) && !self.grammar.is_auxiliary(first_word) {
+        if self.verbs().is_verb(first_word) && !self.grammar().is_auxiliary(first_word) {
             return SentenceType::Command;
         }
 
@@ -269,14 +318,30 @@ impl Transpiler {
 
     /// Tokenize an English sentence into tagged tokens
     fn tokenize(&self, sentence: &str) -> Vec<Token> {
-        let raw_tokens = self.grammar.tokenize(sentence);
+        self.tag_tokens(&self.grammar().tokenize(sentence), &[])
+    }
+
+    /// Tag the words of a sentence
+    ///
+    /// Tokens in `nominal` (antecedents put in for pronouns) are never verbs.
+    fn tag_tokens(&self, raw_tokens: &[String], nominal: &[Range<usize>]) -> Vec<Token> {
         let mut tokens = Vec::new();
+        let mut particles: Vec<usize> = Vec::new();
 
-        for word in &raw_tokens {
+        for (i, word) in raw_tokens.iter().enumerate() {
             let lower = word.to_lowercase();
 
-            // Skip empty
-            if lower.is_empty() {
+            // Skip empty and particles already merged into a phrasal verb
+            if lower.is_empty() || particles.contains(&i) {
+                continue;
+            }
+
+            if nominal.iter().any(|span| span.contains(&i)) {
+                let (pos, lemma) = match self.classify_token(&lower) {
+                    (TokenPOS::Verb, _) => (TokenPOS::Noun, self.grammar().noun_lemma(&lower)),
+                    classified => classified,
+                };
+                tokens.push(Token { lemma, pos, is_negation: false, is_preposition: false });
                 continue;
             }
 
@@ -291,6 +356,18 @@ impl Transpiler {
                 continue;
             }
 
+            // Phrasal verb: "turn the lights off" -> turn_off
+            if let Some(m) = self.verbs().match_phrasal(raw_tokens, i) {
+                particles.extend(m.particle_positions.iter().copied());
+                tokens.push(Token {
+                    lemma: m.entry.base().replace(' ', "_"),
+                    pos: TokenPOS::Verb,
+                    is_negation: false,
+                    is_preposition: false,
+                });
+                continue;
+            }
+
             // Classify POS
             let (pos, lemma) = self.classify_token(&lower);
 
@@ -309,35 +386,46 @@ impl Transpiler {
     /// Classify a token's part of speech
     fn classify_token(&self, word: &str) -> (TokenPOS, String) {
         // Article
-        if self.grammar.is_article(word) {
+        if self.grammar().is_article(word) {
             return (TokenPOS::Article, word.to_string());
         }
 
         // Preposition
-        if self.grammar.is_preposition(word) {
+        if self.grammar().is_preposition(word) {
             return (TokenPOS::Preposition, word.to_string());
         }
 
         // Pronoun
-        if self.grammar.is_pronoun(word) {
+        if self.grammar().is_pronoun(word) {
             return (TokenPOS::Pronoun, word.to_string());
         }
 
         // Conjunction
-        if self.grammar.is_conjunction(word) {
+        if self.grammar().is_conjunction(word) {
             return (TokenPOS::Conjunction, word.to_string());
         }
 
         // Auxiliary
-        if self.grammar.is_auxiliary(word) {
+        if self.grammar().is_auxiliary(word) {
             return (TokenPOS::Auxiliary, word.to_string());
         }
 
         // Verb - look up and get base form
-        if let Some(base) = self.verbs.base_form(word) {
+        if let Some(base) = self.verbs().base_form(word) {
             return (TokenPOS::Verb, base.to_string());
         }
 
+        // Comparative/superlative of a known adjective: "bigger" -> "big"
+        let (adj_lemma, degree) = self.grammar().morphology()
+            .adjective_lemma_with(word, |w| self.adjective_set.contains(w));
+        if degree != Degree::Positive && self.adjective_set.contains(&adj_lemma) {
+            return (TokenPOS::Adjective, adj_lemma);
+        }
+
+        if COMMON_ADVERBS.contains(&word) {
+            return (TokenPOS::Adverb, word.to_string());
+        }
+
         // Adjective
         if self.adjective_set.contains(word) || word.ends_with("ly") {
             if word.ends_with("ly") && !self.adjective_set.contains(word) {
@@ -346,8 +434,11 @@ impl Transpiler {
             return (TokenPOS::Adjective, word.to_string());
         }
 
-        // Default: noun
-        (TokenPOS::Noun, word.to_string())
+        // Default: noun, lemmatized to singular unless it is a known name ("james")
+        if self.lexicon.gazetteer().get(word).is_some() {
+            return (TokenPOS::Noun, word.to_string());
+        }
+        (TokenPOS::Noun, self.grammar().noun_lemma(word))
     }
 
     /// Parse tokens into a PIRS rule
@@ -379,11 +470,32 @@ impl Transpiler {
 
                 // Collect subject tokens (before verb)
                 let subject_tokens = &content_tokens[..idx];
-                // Collect object tokens (after verb)
-                let object_tokens = &content_tokens[idx + 1..];
+                // Collect object tokens (after verb), splitting off a
+                // that-clause or to-infinitive the verb's frames allow
+                let frames = self.frames_of(&verb.lemma);
+                let (object_tokens, complement) = self.split_complement(&frames, &content_tokens[idx + 1..]);
 
                 let subject_phrases = self.extract_noun_phrases(subject_tokens);
-                let (object_phrases, prep_phrases) = self.extract_objects_and_preps(object_tokens);
+                let (mut object_phrases, mut prep_phrases) = self.extract_objects_and_preps(object_tokens);
+
+                // Normalise argument order to Agent, Theme, Recipient:
+                // "gave Mary the book" / "gave the book to Mary" -> give(_, book, mary)
+                let mut recipients = Vec::new();
+                let ditransitive = object_phrases.len() == 2 && frames.contains(&Frame::DITRANSITIVE);
+                let clause_recipient = object_phrases.len() == 1 && complement.is_some()
+                    && frames.iter().any(|f| f.role_of(SlotKind::NounPhrase) == Some(ThematicRole::Recipient));
+                if ditransitive || clause_recipient {
+                    recipients.push(object_phrases.remove(0));
+                } else if !object_phrases.is_empty() {
+                    prep_phrases.retain(|pp| {
+                        let dative = frames.iter().any(|f| f.has_object()
+                            && f.role_for_prep(&pp.prep) == Some(ThematicRole::Recipient));
+                        if dative {
+                            recipients.push(pp.object.clone());
+                        }
+                        !dative
+                    });
+                }
 
                 // Build head predicate
                 let head = if negated {
@@ -400,9 +512,16 @@ impl Transpiler {
                 for np in &object_phrases {
                     args.push(np.to_pirs_term());
                 }
+                let mut body: Vec<String> = Vec::new();
+                if let Some(clause) = complement {
+                    args.push(clause.term());
+                    body.extend(clause.body);
+                }
+                for np in &recipients {
+                    args.push(np.to_pirs_term());
+                }
 
                 // Build body from prepositional phrases + sentence type
-                let mut body: Vec<String> = Vec::new();
                 for pp in &prep_phrases {
                     body.push(pp.to_pirs_term());
                 }
@@ -445,6 +564,35 @@ impl Transpiler {
         }
     }
 
+    /// Subcategorisation frames of a verb lemma ("give", "turn_off")
+    fn frames_of(&self, lemma: &str) -> Vec<Frame> {
+        if let Some(entry) = self.verbs().lookup(lemma) {
+            return entry.frames.clone();
+        }
+        self.verbs().lookup_phrasal(&lemma.replace('_', " "))
+            .map(|p| p.frames.clone())
+            .unwrap_or_default()
+    }
+
+    /// Split a that-clause ("that it rains") or to-infinitive ("to go home")
+    /// off the post-verb tokens and transpile it as a nested term
+    fn split_complement<'t>(&self, frames: &[Frame], tokens: &'t [&'t Token]) -> (&'t [&'t Token], Option<PirsRule>) {
+        let takes_clause = frames.iter().any(|f| f.role_of(SlotKind::ThatClause).is_some());
+        let takes_infinitive = frames.iter().any(|f| f.role_of(SlotKind::Infinitive).is_some());
+
+        for (p, token) in tokens.iter().enumerate() {
+            let rest = &tokens[p + 1..];
+            let starts = (takes_clause && token.lemma == "that" && rest.iter().any(|t| t.pos == TokenPOS::Verb))
+                || (takes_infinitive && token.lemma == "to" && rest.first().map(|t| t.pos == TokenPOS::Verb).unwrap_or(false));
+            if starts {
+                let owned: Vec<Token> = rest.iter().map(|t| (*t).clone()).collect();
+                let clause = self.parse_tokens(&owned, SentenceType::Declarative);
+                return (&tokens[..p], clause);
+            }
+        }
+        (tokens, None)
+    }
+
     /// Extract noun phrases with adjective modifiers from a token slice
     fn extract_noun_phrases(&self, tokens: &[&Token]) -> Vec<NounPhrase> {
         let mut phrases = Vec::new();
@@ -551,6 +699,47 @@ mod tests {
         assert_eq!(rules[0].to_prolog(), "eat(cat, fish).");
     }
 
+    #[test]
+    fn test_noun_lemmas() {
+        let t = Transpiler::new();
+        let rules = t.to_pirs("cats eat fish");
+        assert_eq!(rules[0].to_prolog(), "eat(cat, fish).");
+
+        let rules = t.to_pirs("The bigger children love mice.");
+        assert_eq!(rules[0].to_prolog(), "love(child_big, mouse).");
+
+        let rules = t.to_pirs("James loves movies.");
+        assert_eq!(rules[0].to_prolog(), "love(james, movie).");
+    }
+
+    #[test]
+    fn test_phrasal_verbs() {
+        let t = Transpiler::new();
+        let rules = t.to_pirs("John turned the lights off.");
+        assert_eq!(rules[0].head, "turn_off");
+
+        let rules = t.to_pirs("Mary looks after the kids.");
+        assert_eq!(rules[0].head, "look_after");
+    }
+
+    #[test]
+    fn test_frame_arguments() {
+        let t = Transpiler::new();
+        let rules = t.to_pirs("John gave Mary the book.");
+        assert_eq!(rules[0].to_prolog(), "give(john, book, mary).");
+        let rules = t.to_pirs("John gave the book to Mary.");
+        assert_eq!(rules[0].to_prolog(), "give(john, book, mary).");
+
+        let rules = t.to_pirs("Mary said that John left.");
+        assert_eq!(rules[0].to_prolog(), "say(mary, leave(john)).");
+        let rules = t.to_pirs("I want to go home.");
+        assert_eq!(rules[0].to_prolog(), "want(i, go(home)).");
+
+        // Locations stay as body conditions
+        let rules = t.to_pirs("Put the cup on the table!");
+        assert!(rules[0].body.contains(&"on(table)".to_string()));
+    }
+
     #[test]
     fn test_negation() {
         let t = Transpiler::new();
@@ -655,4 +844,19 @@ mod tests {
         assert_eq!(rules.len(), 1);
         assert!(rules[0].body.contains(&"type(imperative)".to_string()));
     }
+
+    #[test]
+    fn test_pronouns_across_sentences() {
+        let t = Transpiler::new();
+        let rules = t.to_pirs("Open the file. Then delete it.");
+        assert_eq!(rules[1].head, "delete");
+        assert_eq!(rules[1].args, vec!["file".to_string()]);
+
+        let rules = t.to_pirs("The boy found a red ball. He kicked it.");
+        assert_eq!(rules[1].head, "kick");
+        assert_eq!(rules[1].args, rules[0].args);
+
+        let rules = t.to_pirs("John loves Mary. She loves him.");
+        assert_eq!(rules[1].to_string(), "love(mary, john).");
+    }
 }
@@ -0,0 +1,114 @@
+//! Built-in verb tables
+//!
+//! `build.rs` compiles `builtin_verbs.tsv` into the static arrays included
+//! below: entries, perfect-hash indexes of bases, forms and aliases, the
+//! synonym/antonym graph, and the category/group indexes. Looking a word up
+//! needs no allocation and no start-up work. The `VerbEntry` values of a base
+//! are built the first time they are asked for and shared by every
+//! `VerbDatabase` in the process.
+
+use std::sync::OnceLock;
+
+use super::{VerbEntry, FunctionalCategory, VerbGroup, FormRole, Frame, frames};
+use crate::phf;
+
+/// A verb sense as stored in the generated tables
+pub(super) struct BuiltinEntry {
+    pub base: &'static str,
+    pub past: &'static str,
+    pub past_participle: &'static str,
+    pub present_participle: &'static str,
+    pub third_person: &'static str,
+    pub variants: &'static [&'static str],
+    pub category: FunctionalCategory,
+    pub group: VerbGroup,
+    pub irregular: bool,
+    pub transitive: Option<bool>,
+    pub frequency: u8,
+    pub synonyms: &'static [&'static str],
+    pub antonyms: &'static [&'static str],
+    /// Frames given in the data, `None` for the group defaults
+    pub frames: Option<&'static [Frame]>,
+}
+
+/// A base form and its senses
+pub(super) struct BuiltinBase {
+    pub name: &'static str,
+    /// Indices into `ENTRIES`, in data order
+    pub entries: &'static [u16],
+    /// Highest frequency among the senses
+    pub frequency: u8,
+}
+
+include!(concat!(env!("OUT_DIR"), "/verb_tables.rs"));
+
+static MATERIALIZED: [OnceLock<Vec<VerbEntry>>; BASE_COUNT] = [const { OnceLock::new() }; BASE_COUNT];
+
+impl BuiltinEntry {
+    fn to_entry(&self) -> VerbEntry {
+        let strings = |words: &[&str]| words.iter().map(|w| w.to_string()).collect();
+        let frames = self.frames.map(|frames| frames.to_vec())
+            .unwrap_or_else(|| frames::default_frames(self.group, self.transitive));
+        VerbEntry {
+            base: self.base.to_string(),
+            past: self.past.to_string(),
+            past_participle: self.past_participle.to_string(),
+            present_participle: self.present_participle.to_string(),
+            third_person: self.third_person.to_string(),
+            category: self.category,
+            group: self.group,
+            irregular: self.irregular,
+            transitive: self.transitive,
+            frequency: self.frequency,
+            synonyms: strings(self.synonyms),
+            antonyms: strings(self.antonyms),
+            variants: strings(self.variants),
+            frames,
+            custom_category: None,
+            custom_group: None,
+        }
+    }
+}
+
+/// Index of a base form in `BASES`
+pub(super) fn base_index(base: &str) -> Option<usize> {
+    phf::index(base, &BASE_DISPLACEMENTS, &BASE_SLOTS).filter(|&i| BASES[i].name == base)
+}
+
+/// Entries of a base, built on first use
+pub(super) fn entries(base: usize) -> &'static Vec<VerbEntry> {
+    MATERIALIZED[base].get_or_init(|| {
+        BASES[base].entries.iter().map(|&i| ENTRIES[i as usize].to_entry()).collect()
+    })
+}
+
+/// (base index, role) readings of a lowercase form
+pub(super) fn readings(form: &str) -> &'static [(u16, FormRole)] {
+    phf::index(form, &FORM_DISPLACEMENTS, &FORM_SLOTS)
+        .filter(|&i| FORMS[i].0 == form)
+        .map(|i| FORMS[i].1)
+        .unwrap_or(&[])
+}
+
+/// (synonym, head base) of a form of a synonym that has no entry
+pub(super) fn alias(form: &str) -> Option<(&'static str, &'static str)> {
+    phf::index(form, &ALIAS_DISPLACEMENTS, &ALIAS_SLOTS)
+        .filter(|&i| ALIASES[i].0 == form)
+        .map(|i| (ALIASES[i].1, ALIASES[i].2))
+}
+
+/// Sorted synonyms of a graph node
+pub(super) fn synonyms(node: &str) -> &'static [&'static str] {
+    phf::index(node, &SYNONYM_DISPLACEMENTS, &SYNONYM_SLOTS)
+        .filter(|&i| SYNONYM_GRAPH[i].0 == node)
+        .map(|i| SYNONYM_GRAPH[i].1)
+        .unwrap_or(&[])
+}
+
+/// Sorted antonyms of a graph node
+pub(super) fn antonyms(node: &str) -> &'static [&'static str] {
+    phf::index(node, &ANTONYM_DISPLACEMENTS, &ANTONYM_SLOTS)
+        .filter(|&i| ANTONYM_GRAPH[i].0 == node)
+        .map(|i| ANTONYM_GRAPH[i].1)
+        .unwrap_or(&[])
+}
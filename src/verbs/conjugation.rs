@@ -0,0 +1,434 @@
+//! Conjugation: full tense/aspect/voice paradigms built on `VerbEntry` forms
+//!
+//! ```
+//! use nl_sre_english::verbs::VerbDatabase;
+//! use nl_sre_english::verbs::conjugation::{Tense, Aspect, Person, Number, Voice, Polarity};
+//!
+//! let db = VerbDatabase::with_builtin();
+//! let form = db.conjugate("eat", Tense::Present, Aspect::PerfectProgressive,
+//!     Person::Third, Number::Singular, Voice::Active, Polarity::Negative);
+//! assert_eq!(form.as_deref(), Some("has not been eating"));
+//! ```
+
+use std::fmt;
+
+use super::{VerbDatabase, VerbEntry};
+
+/// Grammatical tense
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tense {
+    Past,
+    Present,
+    Future,
+}
+
+/// Grammatical aspect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Aspect {
+    /// eats, ate, will eat
+    Simple,
+    /// is eating
+    Progressive,
+    /// has eaten
+    Perfect,
+    /// has been eating
+    PerfectProgressive,
+}
+
+/// Grammatical person
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Person {
+    First,
+    Second,
+    Third,
+}
+
+/// Grammatical number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Number {
+    Singular,
+    Plural,
+}
+
+/// Grammatical voice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Voice {
+    Active,
+    Passive,
+}
+
+/// Affirmative or negated verb phrase
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Polarity {
+    Positive,
+    Negative,
+}
+
+impl Tense {
+    pub fn all() -> &'static [Tense] {
+        &[Tense::Past, Tense::Present, Tense::Future]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tense::Past => "Past",
+            Tense::Present => "Present",
+            Tense::Future => "Future",
+        }
+    }
+}
+
+impl Aspect {
+    pub fn all() -> &'static [Aspect] {
+        &[Aspect::Simple, Aspect::Progressive, Aspect::Perfect, Aspect::PerfectProgressive]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Aspect::Simple => "Simple",
+            Aspect::Progressive => "Progressive",
+            Aspect::Perfect => "Perfect",
+            Aspect::PerfectProgressive => "Perfect Progressive",
+        }
+    }
+}
+
+/// The five principal parts of a verb
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Parts {
+    base: String,
+    past: String,
+    past_participle: String,
+    present_participle: String,
+    third_person: String,
+}
+
+impl Parts {
+    fn from_entry(entry: &VerbEntry) -> Self {
+        Self {
+            base: entry.base.clone(),
+            past: entry.past.clone(),
+            past_participle: entry.past_participle.clone(),
+            present_participle: entry.present_participle.clone(),
+            third_person: entry.third_person.clone(),
+        }
+    }
+
+    /// Built-in parts of the auxiliaries, available even in an empty database
+    fn auxiliary(base: &str) -> Option<Self> {
+        let (past, pp, ing, third) = match base {
+            "be" => ("was", "been", "being", "is"),
+            "have" => ("had", "had", "having", "has"),
+            "do" => ("did", "done", "doing", "does"),
+            _ => return None,
+        };
+        Some(Self {
+            base: base.to_string(),
+            past: past.to_string(),
+            past_participle: pp.to_string(),
+            present_participle: ing.to_string(),
+            third_person: third.to_string(),
+        })
+    }
+
+    /// Finite (tensed, agreeing) form
+    fn finite(&self, tense: Tense, person: Person, number: Number) -> String {
+        let first_sg = person == Person::First && number == Number::Singular;
+        let third_sg = person == Person::Third && number == Number::Singular;
+        match (self.base.as_str(), tense) {
+            ("be", Tense::Present) if first_sg => "am".to_string(),
+            ("be", Tense::Present) if third_sg => "is".to_string(),
+            ("be", Tense::Present) => "are".to_string(),
+            ("be", Tense::Past) if first_sg || third_sg => "was".to_string(),
+            ("be", Tense::Past) => "were".to_string(),
+            (_, Tense::Present) if third_sg => self.third_person.clone(),
+            (_, Tense::Past) => self.past.clone(),
+            _ => self.base.clone(),
+        }
+    }
+}
+
+/// Non-finite form required by the preceding element of the verb chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Base,
+    PastParticiple,
+    PresentParticiple,
+}
+
+/// A single cell of a paradigm table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParadigmCell {
+    pub tense: Tense,
+    pub aspect: Aspect,
+    pub voice: Voice,
+    pub person: Person,
+    pub number: Number,
+    /// Conjugated verb phrase ("has been eating")
+    pub form: String,
+}
+
+/// Full conjugation table of a verb
+#[derive(Debug, Clone)]
+pub struct Paradigm {
+    /// Base form
+    pub base: String,
+    /// Past tense
+    pub past: String,
+    /// Past participle
+    pub past_participle: String,
+    /// Present participle
+    pub present_participle: String,
+    /// Every tense/aspect/voice/person/number combination (affirmative)
+    pub cells: Vec<ParadigmCell>,
+}
+
+impl Paradigm {
+    /// Get one cell of the table
+    pub fn get(&self, tense: Tense, aspect: Aspect, voice: Voice, person: Person, number: Number) -> Option<&str> {
+        self.cells.iter()
+            .find(|c| c.tense == tense && c.aspect == aspect && c.voice == voice
+                && c.person == person && c.number == number)
+            .map(|c| c.form.as_str())
+    }
+}
+
+/// Column order for table output: I, you, he/she/it, we, you (pl), they
+const PERSONS: [(Person, Number, &str); 6] = [
+    (Person::First, Number::Singular, "I"),
+    (Person::Second, Number::Singular, "you"),
+    (Person::Third, Number::Singular, "he/she/it"),
+    (Person::First, Number::Plural, "we"),
+    (Person::Second, Number::Plural, "you (pl)"),
+    (Person::Third, Number::Plural, "they"),
+];
+
+impl fmt::Display for Paradigm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} - {} - {} - {}", self.base, self.past, self.past_participle, self.present_participle)?;
+        for voice in [Voice::Active, Voice::Passive] {
+            if !self.cells.iter().any(|c| c.voice == voice) {
+                continue;
+            }
+            writeln!(f)?;
+            writeln!(f, "{}", if voice == Voice::Active { "ACTIVE" } else { "PASSIVE" })?;
+            for tense in Tense::all() {
+                for aspect in Aspect::all() {
+                    let forms: Vec<String> = PERSONS.iter()
+                        .map(|(p, n, pronoun)| {
+                            let form = self.get(*tense, *aspect, voice, *p, *n).unwrap_or("-");
+                            format!("{} {}", pronoun, form)
+                        })
+                        .collect();
+                    writeln!(f, "{:<28} {}", format!("{} {}", tense.name(), aspect.name()), forms.join(" | "))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl VerbDatabase {
+    /// Conjugate a verb into a full verb phrase
+    ///
+    /// Returns `None` if the verb is unknown, or for the passive of a verb
+    /// marked intransitive.
+    #[allow(clippy::too_many_arguments)]
+    pub fn conjugate(
+        &self,
+        verb: &str,
+        tense: Tense,
+        aspect: Aspect,
+        person: Person,
+        number: Number,
+        voice: Voice,
+        polarity: Polarity,
+    ) -> Option<String> {
+        let parts = self.parts_of(verb)?;
+        if voice == Voice::Passive && self.is_intransitive(&parts.base) {
+            return None;
+        }
+        Some(build_phrase(&parts, tense, aspect, person, number, voice, polarity))
+    }
+
+    /// Build the full (affirmative) conjugation table of a verb
+    pub fn paradigm(&self, verb: &str) -> Option<Paradigm> {
+        let parts = self.parts_of(verb)?;
+        let mut voices = vec![Voice::Active];
+        if !self.is_intransitive(&parts.base) {
+            voices.push(Voice::Passive);
+        }
+
+        let mut cells = Vec::new();
+        for &voice in &voices {
+            for &tense in Tense::all() {
+                for &aspect in Aspect::all() {
+                    for (person, number, _) in PERSONS {
+                        cells.push(ParadigmCell {
+                            tense,
+                            aspect,
+                            voice,
+                            person,
+                            number,
+                            form: build_phrase(&parts, tense, aspect, person, number, voice, Polarity::Positive),
+                        });
+                    }
+                }
+            }
+        }
+
+        Some(Paradigm {
+            base: parts.base,
+            past: parts.past,
+            past_participle: parts.past_participle,
+            present_participle: parts.present_participle,
+            cells,
+        })
+    }
+
+    /// Principal parts for any form of a verb; auxiliaries are always known
+    fn parts_of(&self, verb: &str) -> Option<Parts> {
+        let w = verb.to_lowercase();
+        let base = self.base_form(&w).unwrap_or(&w).to_string();
+        if let Some(parts) = Parts::auxiliary(&base) {
+            return Some(parts);
+        }
+        self.lookup(&base).map(Parts::from_entry)
+    }
+
+    /// True only if every sense of the verb is marked intransitive
+    fn is_intransitive(&self, base: &str) -> bool {
+        self.lookup_all(base)
+            .map(|entries| entries.iter().all(|e| e.transitive == Some(false)))
+            .unwrap_or(false)
+    }
+}
+
+/// Role of an element in the verb chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    PerfectHave,
+    ProgressiveBe,
+    PassiveBe,
+    Main,
+}
+
+impl Role {
+    /// Form this element requires of the next one
+    fn selects(&self) -> Slot {
+        match self {
+            Role::PerfectHave | Role::PassiveBe => Slot::PastParticiple,
+            Role::ProgressiveBe => Slot::PresentParticiple,
+            Role::Main => Slot::Base,
+        }
+    }
+}
+
+fn build_phrase(
+    parts: &Parts,
+    tense: Tense,
+    aspect: Aspect,
+    person: Person,
+    number: Number,
+    voice: Voice,
+    polarity: Polarity,
+) -> String {
+    let be = Parts::auxiliary("be").unwrap_or_else(|| parts.clone());
+    let have = Parts::auxiliary("have").unwrap_or_else(|| parts.clone());
+
+    let mut chain: Vec<(&Parts, Role)> = Vec::new();
+    if matches!(aspect, Aspect::Perfect | Aspect::PerfectProgressive) {
+        chain.push((&have, Role::PerfectHave));
+    }
+    if matches!(aspect, Aspect::Progressive | Aspect::PerfectProgressive) {
+        chain.push((&be, Role::ProgressiveBe));
+    }
+    if voice == Voice::Passive {
+        chain.push((&be, Role::PassiveBe));
+    }
+    chain.push((parts, Role::Main));
+
+    let mut words: Vec<String> = Vec::new();
+    let mut slot = None;
+    if tense == Tense::Future {
+        words.push("will".to_string());
+        slot = Some(Slot::Base);
+    }
+
+    // Negating a simple active lexical verb needs do-support: "does not eat"
+    let negative = polarity == Polarity::Negative;
+    if negative && slot.is_none() && chain.len() == 1 && parts.base != "be" {
+        let aux_do = Parts::auxiliary("do").unwrap_or_else(|| parts.clone());
+        words.push(aux_do.finite(tense, person, number));
+        slot = Some(Slot::Base);
+    }
+
+    for (element, role) in chain {
+        words.push(match slot {
+            None => element.finite(tense, person, number),
+            Some(Slot::Base) => element.base.clone(),
+            Some(Slot::PastParticiple) => element.past_participle.clone(),
+            Some(Slot::PresentParticiple) => element.present_participle.clone(),
+        });
+        slot = Some(role.selects());
+    }
+
+    if negative {
+        words.insert(1, "not".to_string());
+    }
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::too_many_arguments)]
+    fn conj(db: &VerbDatabase, verb: &str, tense: Tense, aspect: Aspect, person: Person,
+            number: Number, voice: Voice, polarity: Polarity) -> String {
+        db.conjugate(verb, tense, aspect, person, number, voice, polarity).unwrap()
+    }
+
+    #[test]
+    fn test_conjugate_active() {
+        let db = VerbDatabase::with_builtin();
+        use Polarity::*;
+        assert_eq!(conj(&db, "eat", Tense::Present, Aspect::Simple, Person::Third, Number::Singular, Voice::Active, Positive), "eats");
+        assert_eq!(conj(&db, "eat", Tense::Past, Aspect::Simple, Person::First, Number::Plural, Voice::Active, Negative), "did not eat");
+        assert_eq!(conj(&db, "eat", Tense::Present, Aspect::PerfectProgressive, Person::Third, Number::Singular, Voice::Active, Negative), "has not been eating");
+        assert_eq!(conj(&db, "go", Tense::Future, Aspect::Perfect, Person::Second, Number::Singular, Voice::Active, Positive), "will have gone");
+        assert_eq!(conj(&db, "walked", Tense::Present, Aspect::Progressive, Person::First, Number::Singular, Voice::Active, Positive), "am walking");
+    }
+
+    #[test]
+    fn test_conjugate_passive() {
+        let db = VerbDatabase::with_builtin();
+        use Polarity::*;
+        assert_eq!(conj(&db, "send", Tense::Future, Aspect::Simple, Person::Third, Number::Singular, Voice::Passive, Positive), "will be sent");
+        assert_eq!(conj(&db, "send", Tense::Past, Aspect::Progressive, Person::Third, Number::Plural, Voice::Passive, Positive), "were being sent");
+        assert_eq!(conj(&db, "send", Tense::Present, Aspect::Perfect, Person::Third, Number::Singular, Voice::Passive, Negative), "has not been sent");
+    }
+
+    #[test]
+    fn test_conjugate_auxiliaries() {
+        let db = VerbDatabase::new();
+        use Polarity::*;
+        assert_eq!(conj(&db, "be", Tense::Present, Aspect::Simple, Person::First, Number::Singular, Voice::Active, Negative), "am not");
+        assert_eq!(conj(&db, "be", Tense::Past, Aspect::Simple, Person::Second, Number::Singular, Voice::Active, Positive), "were");
+        assert_eq!(conj(&db, "have", Tense::Present, Aspect::Simple, Person::Third, Number::Singular, Voice::Active, Positive), "has");
+        assert_eq!(conj(&db, "do", Tense::Present, Aspect::Simple, Person::Third, Number::Singular, Voice::Active, Negative), "does not do");
+        assert!(db.conjugate("walk", Tense::Past, Aspect::Simple, Person::First, Number::Singular, Voice::Active, Positive).is_none());
+    }
+
+    #[test]
+    fn test_paradigm() {
+        let db = VerbDatabase::with_builtin();
+        let p = db.paradigm("take").unwrap();
+        assert_eq!(p.past, "took");
+        assert_eq!(p.get(Tense::Past, Aspect::Perfect, Voice::Passive, Person::Third, Number::Singular), Some("had been taken"));
+        assert_eq!(p.cells.len(), 3 * 4 * 2 * 6);
+        let table = p.to_string();
+        assert!(table.contains("he/she/it takes"));
+        assert!(table.contains("PASSIVE"));
+    }
+}
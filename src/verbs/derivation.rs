@@ -0,0 +1,231 @@
+//! Derivational morphology: links between verbs and derived words
+//!
+//! - **Action nouns**: create → creation, decide → decision, move → movement, arrive → arrival
+//! - **Agent nouns**: teach → teacher, run → runner, edit → editor
+//! - **Ability adjectives**: wash → washable, rely → reliable
+//!
+//! Derived words are analysed back to their verb by suffix rules checked
+//! against the database, so "deletion" maps to "delete" even when it is
+//! not in the dictionary. Forward links (verb → words) are checked against
+//! the dictionary.
+
+use super::{VerbDatabase, VerbEntry, inflection};
+use crate::dictionary::EnglishDictionary;
+
+/// Kind of derived word
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DerivationKind {
+    /// Noun naming the action: creation, movement, arrival
+    ActionNoun,
+    /// Noun naming the doer: teacher, editor
+    AgentNoun,
+    /// Adjective of possibility: washable, reliable
+    Ability,
+}
+
+impl DerivationKind {
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            DerivationKind::ActionNoun => "ActionNoun",
+            DerivationKind::AgentNoun => "AgentNoun",
+            DerivationKind::Ability => "Ability",
+        }
+    }
+}
+
+/// A word derived from a verb
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivedWord {
+    /// The derived word ("creation")
+    pub word: String,
+    /// Base form of the verb ("create")
+    pub verb: String,
+    /// Kind of derivation
+    pub kind: DerivationKind,
+    /// Suffix added ("ion")
+    pub suffix: &'static str,
+}
+
+/// Suffix rules: (derived ending, verb endings to try, kind, suffix label)
+///
+/// Longer endings come first so "-ication" wins over "-ation".
+const RULES: &[(&str, &[&str], DerivationKind, &str)] = &[
+    ("ication", &["y"], DerivationKind::ActionNoun, "ation"),
+    ("ation", &["ate", "e", ""], DerivationKind::ActionNoun, "ation"),
+    ("ction", &["ct", "ce"], DerivationKind::ActionNoun, "ion"),
+    ("ssion", &["t", "ss"], DerivationKind::ActionNoun, "ion"),
+    ("sion", &["de", "d", "se"], DerivationKind::ActionNoun, "ion"),
+    ("tion", &["te", "t"], DerivationKind::ActionNoun, "ion"),
+    ("ment", &["", "e"], DerivationKind::ActionNoun, "ment"),
+    ("ial", &["y"], DerivationKind::ActionNoun, "al"),
+    ("al", &["e", ""], DerivationKind::ActionNoun, "al"),
+    ("ier", &["y"], DerivationKind::AgentNoun, "er"),
+    ("er", &["", "e"], DerivationKind::AgentNoun, "er"),
+    ("or", &["", "e"], DerivationKind::AgentNoun, "or"),
+    ("iable", &["y"], DerivationKind::Ability, "able"),
+    ("able", &["", "e"], DerivationKind::Ability, "able"),
+    ("ible", &["", "e"], DerivationKind::Ability, "ible"),
+];
+
+/// Derivations the suffix rules cannot produce: (word, verb, kind, suffix)
+const IRREGULAR: &[(&str, &str, DerivationKind, &str)] = &[
+    ("destruction", "destroy", DerivationKind::ActionNoun, "ion"),
+    ("explanation", "explain", DerivationKind::ActionNoun, "ation"),
+    ("description", "describe", DerivationKind::ActionNoun, "ion"),
+    ("prescription", "prescribe", DerivationKind::ActionNoun, "ion"),
+    ("subscription", "subscribe", DerivationKind::ActionNoun, "ion"),
+    ("solution", "solve", DerivationKind::ActionNoun, "ion"),
+    ("resolution", "resolve", DerivationKind::ActionNoun, "ion"),
+    ("reception", "receive", DerivationKind::ActionNoun, "ion"),
+    ("perception", "perceive", DerivationKind::ActionNoun, "ion"),
+    ("deception", "deceive", DerivationKind::ActionNoun, "ion"),
+    ("pronunciation", "pronounce", DerivationKind::ActionNoun, "ation"),
+    ("repetition", "repeat", DerivationKind::ActionNoun, "ion"),
+    ("competition", "compete", DerivationKind::ActionNoun, "ion"),
+    ("composition", "compose", DerivationKind::ActionNoun, "ion"),
+    ("position", "pose", DerivationKind::ActionNoun, "ion"),
+    ("addition", "add", DerivationKind::ActionNoun, "ion"),
+    ("definition", "define", DerivationKind::ActionNoun, "ion"),
+    ("analysis", "analyze", DerivationKind::ActionNoun, "sis"),
+    ("liar", "lie", DerivationKind::AgentNoun, "ar"),
+    ("beggar", "beg", DerivationKind::AgentNoun, "ar"),
+];
+
+/// Words that look derived but are not ("letter" is not "one who lets")
+const NOT_DERIVED: &[&str] = &[
+    "letter", "better", "matter", "butter", "bitter", "litter", "batter",
+    "banner", "dinner", "manner", "summer", "hammer", "number", "member",
+    "water", "paper", "power", "corner", "order", "other", "mother", "father",
+    "brother", "sister", "never", "ever", "over", "under", "after",
+    "signal", "total", "metal", "general", "final", "normal", "animal", "capital",
+    "hospital", "several", "moment", "comment", "mention", "station", "nation",
+    "motion", "potion", "ration", "fashion", "cancer", "major", "minor", "mirror",
+    "error", "color", "colour", "floor", "door", "table", "cable", "able", "fable",
+    "vegetable", "comfortable", "possible", "terrible", "horrible", "bible",
+    "tower", "flower", "finger", "ginger", "answer", "offer", "super", "upper",
+];
+
+/// Minimum length of a word analysed by suffix rules
+const MIN_DERIVED_LEN: usize = 5;
+
+impl VerbDatabase {
+    /// Analyse a derived word back to its verb ("deletion" → delete)
+    ///
+    /// Returns `None` for words that are verb forms themselves.
+    pub fn derivation_of(&self, word: &str) -> Option<(&VerbEntry, DerivedWord)> {
+        let w = word.to_lowercase();
+        if self.has_form(&w) || NOT_DERIVED.contains(&w.as_str()) {
+            return None;
+        }
+
+        if let Some(&(_, verb, kind, suffix)) = IRREGULAR.iter().find(|(d, ..)| *d == w) {
+            let entry = self.lookup(verb)?;
+            return Some((entry, DerivedWord { word: w, verb: verb.to_string(), kind, suffix }));
+        }
+        if w.len() < MIN_DERIVED_LEN {
+            return None;
+        }
+
+        for &(ending, replacements, kind, suffix) in RULES {
+            let Some(stem) = w.strip_suffix(ending) else { continue };
+            if stem.len() < 2 {
+                continue;
+            }
+            let mut candidates: Vec<String> = replacements.iter()
+                .map(|r| format!("{}{}", stem, r))
+                .collect();
+            // Undouble for agent nouns: runner -> run
+            if kind == DerivationKind::AgentNoun && ending == "er" {
+                let mut chars = stem.char_indices().rev();
+                let last = chars.next().zip(chars.next()).filter(|((_, a), (_, b))| a == b);
+                if let Some(((i, _), _)) = last {
+                    let undoubled = &stem[..i];
+                    if inflection::inflect(undoubled).present_participle == format!("{}ing", stem) {
+                        candidates.push(undoubled.to_string());
+                    }
+                }
+            }
+            for candidate in candidates {
+                if self.entries_of(&candidate).is_some() {
+                    let entry = self.lookup(&candidate)?;
+                    return Some((entry, DerivedWord { word: w, verb: candidate, kind, suffix }));
+                }
+            }
+        }
+        None
+    }
+
+    /// Derived words of a verb that exist in the dictionary
+    pub fn derived_words(&self, verb: &str, dictionary: &EnglishDictionary) -> Vec<DerivedWord> {
+        let Some(base) = self.base_form(verb).map(|b| b.to_string()) else { return Vec::new() };
+
+        let mut candidates: Vec<String> = IRREGULAR.iter()
+            .filter(|(_, v, ..)| *v == base)
+            .map(|(d, ..)| d.to_string())
+            .collect();
+        for &(ending, replacements, _, _) in RULES {
+            for r in replacements {
+                if let Some(stem) = base.strip_suffix(r) {
+                    candidates.push(format!("{}{}", stem, ending));
+                }
+            }
+        }
+        let doubled = inflection::inflect(&base).present_participle;
+        if let Some(stem) = doubled.strip_suffix("ing") {
+            candidates.push(format!("{}er", stem));
+        }
+
+        let mut result: Vec<DerivedWord> = Vec::new();
+        for candidate in candidates {
+            if !dictionary.is_valid(&candidate) || result.iter().any(|d| d.word == candidate) {
+                continue;
+            }
+            if let Some((_, derived)) = self.derivation_of(&candidate) {
+                if derived.verb == base {
+                    result.push(derived);
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analysis() {
+        let db = VerbDatabase::with_builtin();
+        let cases = [
+            ("creation", "create", DerivationKind::ActionNoun),
+            ("deletion", "delete", DerivationKind::ActionNoun),
+            ("decision", "decide", DerivationKind::ActionNoun),
+            ("movement", "move", DerivationKind::ActionNoun),
+            ("arrival", "arrive", DerivationKind::ActionNoun),
+            ("destruction", "destroy", DerivationKind::ActionNoun),
+            ("teacher", "teach", DerivationKind::AgentNoun),
+            ("runner", "run", DerivationKind::AgentNoun),
+            ("washable", "wash", DerivationKind::Ability),
+            ("editor", "edit", DerivationKind::AgentNoun),
+        ];
+        for (word, verb, kind) in cases {
+            let (_, d) = db.derivation_of(word).unwrap_or_else(|| panic!("no derivation for {}", word));
+            assert_eq!(d.verb, verb, "{}", word);
+            assert_eq!(d.kind, kind, "{}", word);
+        }
+        assert!(db.derivation_of("letter").is_none());
+        assert!(db.derivation_of("walked").is_none());
+        // "₂" ends in two equal bytes
+        assert!(db.derivation_of("ab₂er").is_none());
+    }
+
+    #[test]
+    fn test_forward_links() {
+        let db = VerbDatabase::with_builtin();
+        let dict = EnglishDictionary::new();
+        let words: Vec<String> = db.derived_words("decide", &dict).into_iter().map(|d| d.word).collect();
+        assert!(words.contains(&"decision".to_string()));
+    }
+}
@@ -0,0 +1,359 @@
+//! Plain-text verb format
+//!
+//! One verb sense per line, fields separated by tabs:
+//!
+//! ```text
+//! # base  past  past_participle  present_participle  third_person  category  group  transitivity  frequency  synonyms  antonyms   frames  custom_category  custom_group
+//! walk    -     -                -                   -             Movement  Walk   both          95         stroll,march
+//! go      went  gone             -                   -             Movement  Walk   intransitive  100        -          come,stay
+//! tell    told  told             -                   -             Communication  Speak  transitive  95  -  -  transitive,ditransitive,recipient_that
+//! ```
+//!
+//! - `-` in a form column means "generate it": a verb with generated past
+//!   and past participle is regular.
+//! - `category` and `group` are enum variant names (`Physical`,
+//!   `HelpCausation`); display names ("Physical Action") are also accepted.
+//! - `transitivity` is `transitive`, `intransitive` or `both`.
+//! - `frequency` is 1-100.
+//! - `synonyms` and `antonyms` are comma-separated, `-` or empty for none,
+//!   and may be omitted at the end of a line.
+//! - `frames` lists `Frame` constants by lowercase name (`that_clause`);
+//!   `-`, empty or omitted means the group defaults.
+//! - `custom_category` and `custom_group` name a category and group of the
+//!   database's `CategoryRegistry`, registered before loading; `-`, empty or
+//!   omitted for none.
+//! - Blank lines and lines starting with `#` are ignored.
+//! - A base may appear once per group. An inflected form may not belong to
+//!   two bases; a base that is also another verb's inflection ("found",
+//!   "lay") is allowed and listed by `VerbDatabase::form_collisions`.
+//!
+//! Phrasal verbs are not part of the format.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use super::{
+    VerbDatabase, VerbEntry, FormRole, Frame, FunctionalCategory, VerbGroup, CategoryKey, CategoryRegistry,
+    GroupKey, frames, inflection,
+};
+use super::tsv::{self, ALL_COLUMNS, EMPTY, REQUIRED_COLUMNS};
+
+pub use super::tsv::FormatErrorKind;
+
+/// Column header written by `export_to_string`
+const HEADER: &str = "# base\tpast\tpast_participle\tpresent_participle\tthird_person\tcategory\tgroup\ttransitivity\tfrequency\tsynonyms\tantonyms\tframes\tcustom_category\tcustom_group";
+
+/// A validation error with its 1-based line number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatError {
+    pub line: usize,
+    pub kind: FormatErrorKind,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            FormatErrorKind::ColumnCount { found } => write!(
+                f, "expected {} to {} tab-separated fields, found {}",
+                REQUIRED_COLUMNS, ALL_COLUMNS, found,
+            ),
+            FormatErrorKind::EmptyField(field) => write!(f, "empty {}", field),
+            FormatErrorKind::UnknownCategory(name) => write!(f, "unknown category '{}'", name),
+            FormatErrorKind::UnknownGroup(name) => write!(f, "unknown group '{}'", name),
+            FormatErrorKind::InvalidTransitivity(value) => write!(
+                f, "transitivity must be transitive, intransitive or both, found '{}'", value,
+            ),
+            FormatErrorKind::InvalidFrequency(value) => write!(f, "frequency must be 1-100, found '{}'", value),
+            FormatErrorKind::UnknownFrame(name) => write!(f, "unknown frame '{}'", name),
+            FormatErrorKind::DuplicateForm { form, first_line: Some(first) } => {
+                write!(f, "'{}' is already defined for this group on line {}", form, first)
+            }
+            FormatErrorKind::DuplicateForm { form, first_line: None } => {
+                write!(f, "'{}' is already in the database for this group", form)
+            }
+            FormatErrorKind::SharedForm { form, base, first_line: Some(first) } => {
+                write!(f, "'{}' is already a form of '{}' on line {}", form, base, first)
+            }
+            FormatErrorKind::SharedForm { form, base, first_line: None } => {
+                write!(f, "'{}' is already a form of '{}' in the database", form, base)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl VerbDatabase {
+    /// Load verbs from the plain-text format
+    ///
+    /// Either every line is valid and all entries are added, or nothing is
+    /// added and every error is returned. Returns the number of entries added.
+    pub fn load_from_str(&mut self, text: &str) -> Result<usize, Vec<FormatError>> {
+        let mut entries = Vec::new();
+        let mut errors = Vec::new();
+        let mut seen: HashMap<(String, VerbGroup), usize> = HashMap::new();
+        let mut seen_forms: HashMap<String, (String, usize)> = HashMap::new();
+
+        for (i, raw) in text.lines().enumerate() {
+            let line = i + 1;
+            if tsv::is_blank(raw) {
+                continue;
+            }
+            match parse_line(raw, &self.registry) {
+                Ok(entry) => {
+                    let key = (entry.base.clone(), entry.group);
+                    let in_db = self.entries_of(&entry.base)
+                        .is_some_and(|existing| existing.iter().any(|e| e.group == entry.group));
+                    if let Some(&first) = seen.get(&key) {
+                        errors.push(FormatError {
+                            line,
+                            kind: FormatErrorKind::DuplicateForm { form: entry.base, first_line: Some(first) },
+                        });
+                    } else if in_db {
+                        errors.push(FormatError {
+                            line,
+                            kind: FormatErrorKind::DuplicateForm { form: entry.base, first_line: None },
+                        });
+                    } else if let Some(kind) = self.shared_form(&entry, &seen_forms) {
+                        errors.push(FormatError { line, kind });
+                    } else {
+                        for form in inflected_forms(&entry) {
+                            seen_forms.entry(form.to_string()).or_insert_with(|| (entry.base.clone(), line));
+                        }
+                        seen.insert(key, line);
+                        entries.push(entry);
+                    }
+                }
+                Err(kind) => errors.push(FormatError { line, kind }),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        let added = entries.len();
+        for entry in entries {
+            self.add(entry);
+        }
+        self.rebuild_indexes();
+        Ok(added)
+    }
+
+    /// First inflected form of `entry` that already belongs to another base,
+    /// on an earlier line or in the database
+    fn shared_form(&self, entry: &VerbEntry, seen_forms: &HashMap<String, (String, usize)>) -> Option<FormatErrorKind> {
+        inflected_forms(entry).find_map(|form| {
+            if let Some((base, first)) = seen_forms.get(form).filter(|(base, _)| *base != entry.base) {
+                return Some(FormatErrorKind::SharedForm { form: form.to_string(), base: base.clone(), first_line: Some(*first) });
+            }
+            self.readings_of(form).into_iter()
+                .find(|&(base, role)| role != FormRole::Base && base != entry.base)
+                .map(|(base, _)| FormatErrorKind::SharedForm { form: form.to_string(), base: base.to_string(), first_line: None })
+        })
+    }
+
+    /// Write every entry in the plain-text format, sorted by base form
+    pub fn export_to_string(&self) -> String {
+        let mut entries: Vec<&VerbEntry> = self.all_verbs().collect();
+        entries.sort_by(|a, b| a.base.cmp(&b.base)
+            .then(a.category.name().cmp(b.category.name()))
+            .then(a.group.name().cmp(b.group.name())));
+
+        let mut out = String::from(HEADER);
+        out.push('\n');
+        for entry in entries {
+            out.push_str(&format_entry(entry, &self.registry));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Past, participle, third person and spelling-variant forms of an entry
+fn inflected_forms(entry: &VerbEntry) -> impl Iterator<Item = &str> {
+    [&entry.past, &entry.past_participle, &entry.present_participle, &entry.third_person]
+        .into_iter()
+        .chain(&entry.variants)
+        .map(|f| f.as_str())
+        .filter(move |f| *f != entry.base)
+}
+
+/// Render one entry as a line (without newline)
+fn format_entry(entry: &VerbEntry, registry: &CategoryRegistry) -> String {
+    let generated = inflection::inflect(&entry.base);
+    let form = |value: &str, generated: &str, explicit: bool| {
+        if explicit || value != generated { value.to_string() } else { EMPTY.to_string() }
+    };
+    let list = |words: &[String]| if words.is_empty() { EMPTY.to_string() } else { words.join(",") };
+    let transitivity = match entry.transitive {
+        Some(true) => "transitive",
+        Some(false) => "intransitive",
+        None => "both",
+    };
+
+    [
+        entry.base.clone(),
+        form(&entry.past, &generated.past, entry.irregular),
+        form(&entry.past_participle, &generated.past, entry.irregular),
+        form(&entry.present_participle, &generated.present_participle, false),
+        form(&entry.third_person, &generated.third_person, false),
+        format!("{:?}", entry.category),
+        format!("{:?}", entry.group),
+        transitivity.to_string(),
+        entry.frequency.to_string(),
+        list(&entry.synonyms),
+        list(&entry.antonyms),
+        frames(entry),
+        entry.custom_category.map_or(EMPTY, |id| registry.category_name(id.into())).to_string(),
+        entry.custom_group.map_or(EMPTY, |id| registry.group_name(id.into())).to_string(),
+    ].join("\t")
+}
+
+/// Frames column: `-` when the entry uses its group defaults
+fn frames(entry: &VerbEntry) -> String {
+    if entry.frames == frames::default_frames(entry.group, entry.transitive) {
+        return EMPTY.to_string();
+    }
+    let names: Vec<&str> = entry.frames.iter().filter_map(|f| f.data_name()).collect();
+    if names.is_empty() { EMPTY.to_string() } else { names.join(",") }
+}
+
+/// Parse one non-comment line into an entry, resolving custom names in `registry`
+fn parse_line(line: &str, registry: &CategoryRegistry) -> Result<VerbEntry, FormatErrorKind> {
+    let line = tsv::parse_line(
+        line,
+        |name| FunctionalCategory::from_name(tsv::variant_name(name, tsv::CATEGORY_NAMES)),
+        |name| VerbGroup::from_name(tsv::variant_name(name, tsv::GROUP_NAMES)),
+    )?;
+    let mut entry = if line.irregular {
+        VerbEntry::irregular(&line.base, &line.past, &line.past_participle, line.category, line.group, line.transitive, line.frequency)
+    } else {
+        VerbEntry::regular(&line.base, line.category, line.group, line.transitive, line.frequency)
+    };
+    entry.present_participle = line.present_participle;
+    entry.third_person = line.third_person;
+    entry.synonyms = line.synonyms;
+    entry.antonyms = line.antonyms;
+    if let Some(names) = line.frames {
+        entry.frames = names.iter().filter_map(|n| Frame::from_name(n)).collect();
+    }
+    if let Some(name) = line.custom_category {
+        match registry.resolve_category(&name) {
+            Some(CategoryKey::Custom(id)) => entry.custom_category = Some(id),
+            _ => return Err(FormatErrorKind::UnknownCategory(name)),
+        }
+    }
+    if let Some(name) = line.custom_group {
+        match registry.resolve_group(&name) {
+            Some(GroupKey::Custom(id)) => entry.custom_group = Some(id),
+            _ => return Err(FormatErrorKind::UnknownGroup(name)),
+        }
+    }
+    Ok(entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_from_str() {
+        let text = "# custom verbs\n\
+                    \n\
+                    yeet\t-\t-\t-\t-\tPhysical\tThrow\ttransitive\t20\tthrow,toss\n\
+                    smite\tsmote\tsmitten\t-\t-\tDestruction\tKill\ttransitive\t5\t-\tspare\n";
+        let mut db = VerbDatabase::new();
+        assert_eq!(db.load_from_str(text), Ok(2));
+        assert_eq!(db.base_form("yeeted"), Some("yeet"));
+        assert_eq!(db.base_form("smitten"), Some("smite"));
+        assert!(db.lookup("smite").unwrap().irregular);
+        assert_eq!(db.lookup("yeet").unwrap().synonyms, vec!["throw", "toss"]);
+        assert_eq!(db.stats.total_verbs, 2);
+    }
+
+    #[test]
+    fn test_errors_carry_line_numbers() {
+        let text = "walk\t-\t-\t-\t-\tMovement\tWalk\tboth\t95\n\
+                    # comment\n\
+                    zoom\t-\t-\t-\t-\tTeleportation\tWalk\tboth\t50\n\
+                    walk\t-\t-\t-\t-\tMovement\tWalk\tboth\t90\n\
+                    broken row\n\
+                    hop\t-\t-\t-\t-\tMovement\tJump\tsometimes\t40\n\
+                    wend\twent\twent\t-\t-\tMovement\tWalk\tboth\t10\n\
+                    go\twent\tgone\t-\t-\tMovement\tWalk\tintransitive\t100\n";
+        let mut db = VerbDatabase::new();
+        let errors = db.load_from_str(text).unwrap_err();
+        let lines: Vec<usize> = errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![3, 4, 5, 6, 8]);
+        assert_eq!(errors[0].kind, FormatErrorKind::UnknownCategory("Teleportation".to_string()));
+        assert_eq!(errors[1].kind, FormatErrorKind::DuplicateForm { form: "walk".to_string(), first_line: Some(1) });
+        assert!(matches!(errors[2].kind, FormatErrorKind::ColumnCount { found: 1 }));
+        assert!(errors[3].to_string().starts_with("line 6:"));
+        assert_eq!(errors[4].kind, FormatErrorKind::SharedForm {
+            form: "went".to_string(),
+            base: "wend".to_string(),
+            first_line: Some(7),
+        });
+        // Nothing is added when a line is invalid
+        assert!(db.is_empty());
+    }
+
+    #[test]
+    fn test_builtin_round_trip() {
+        let builtin = VerbDatabase::with_builtin();
+        let text = builtin.export_to_string();
+        let mut db = VerbDatabase::new();
+        assert_eq!(db.load_from_str(&text), Ok(builtin.len()));
+        assert_eq!(db.export_to_string(), text);
+        assert_eq!(db.stats.irregular_verbs, builtin.stats.irregular_verbs);
+        assert_eq!(db.base_form("went"), Some("go"));
+        assert_eq!(db.lookup("tell").unwrap().frames, builtin.lookup("tell").unwrap().frames);
+    }
+
+    #[test]
+    fn test_frames_column() {
+        let text = "tell\ttold\ttold\t-\t-\tCommunication\tSpeak\ttransitive\t95\t-\t-\ttransitive,recipient_that\n";
+        let mut db = VerbDatabase::new();
+        assert_eq!(db.load_from_str(text), Ok(1));
+        assert_eq!(db.lookup("tell").unwrap().frames, vec![Frame::TRANSITIVE, Frame::RECIPIENT_THAT]);
+        assert!(db.export_to_string().ends_with("\ttransitive,recipient_that\t-\t-\n"));
+
+        let errors = VerbDatabase::new().load_from_str(&text.replace("recipient_that", "bitransitive")).unwrap_err();
+        assert_eq!(errors[0].kind, FormatErrorKind::UnknownFrame("bitransitive".to_string()));
+        for name in tsv::FRAME_NAMES {
+            assert_eq!(Frame::from_name(name).and_then(|f| f.data_name()), Some(*name));
+        }
+    }
+
+    #[test]
+    fn test_custom_taxonomy_columns() {
+        let mut db = VerbDatabase::new();
+        let devops = db.register_category("DevOps", "").unwrap();
+        let release = db.register_group("Release", devops).unwrap();
+        db.add(VerbEntry::regular("ship", FunctionalCategory::Transfer, VerbGroup::Send, Some(true), 50)
+            .in_custom_group(release));
+        let text = db.export_to_string();
+        assert!(text.ends_with("\tDevOps\tRelease\n"));
+
+        let mut copy = VerbDatabase::new();
+        assert_eq!(copy.load_from_str(&text).unwrap_err()[0].kind, FormatErrorKind::UnknownCategory("DevOps".to_string()));
+        let devops = copy.register_category("DevOps", "").unwrap();
+        let release = copy.register_group("Release", devops).unwrap();
+        assert_eq!(copy.load_from_str(&text), Ok(1));
+        assert_eq!(copy.lookup("ship").unwrap().group_key(), GroupKey::Custom(release));
+        assert_eq!(copy.lookup("ship").unwrap().category_key(), CategoryKey::Custom(devops));
+        assert_eq!(copy.export_to_string(), text);
+    }
+
+    #[test]
+    fn test_display_names_shared_with_build() {
+        // The build script resolves display names through these tables only
+        for category in FunctionalCategory::all() {
+            assert_eq!(tsv::variant_name(category.name(), tsv::CATEGORY_NAMES), format!("{:?}", category));
+        }
+        for group in VerbGroup::all() {
+            assert_eq!(tsv::variant_name(group.name(), tsv::GROUP_NAMES), format!("{:?}", group));
+        }
+    }
+}
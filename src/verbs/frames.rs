@@ -0,0 +1,518 @@
+//! VerbNet-style subcategorisation frames
+//!
+//! A frame lists the argument slots a verb takes after it and the thematic
+//! role each slot plays:
+//!
+//! | Frame | Example |
+//! |-------|---------|
+//! | `NP V NP NP` | give **Mary** **the book** |
+//! | `NP V NP to NP` | give **the book** to **Mary** |
+//! | `NP V that S` | say that **it rains** |
+//! | `NP V to VP` | want to **go home** |
+//! | `NP V NP PP` | put **the book** on **the table** |
+//!
+//! Entries get frames from their group and transitivity; entries whose group
+//! default does not fit override them with `with_frames`, or with the frames
+//! column of the verb data (see `verbs::format`).
+
+use super::VerbGroup;
+use crate::grammar::EnglishGrammar;
+
+/// Thematic role of a verb argument
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThematicRole {
+    /// Doer of the action: "**John** gave..."
+    Agent,
+    /// Thing affected or moved: "gave **the book**"
+    Theme,
+    /// Receiver of a transfer or message: "gave **Mary**..."
+    Recipient,
+    /// Place or destination: "put it **on the table**"
+    Location,
+    /// Means used: "cut it **with a knife**"
+    Instrument,
+}
+
+impl ThematicRole {
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ThematicRole::Agent => "Agent",
+            ThematicRole::Theme => "Theme",
+            ThematicRole::Recipient => "Recipient",
+            ThematicRole::Location => "Location",
+            ThematicRole::Instrument => "Instrument",
+        }
+    }
+}
+
+/// Syntactic shape of an argument slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotKind {
+    /// Bare noun phrase: "the book"
+    NounPhrase,
+    /// Prepositional phrase headed by one of the listed prepositions
+    Prep(&'static [&'static str]),
+    /// "that"-clause: "that it rains"
+    ThatClause,
+    /// To-infinitive: "to go home"
+    Infinitive,
+    /// Adjective or noun predicate of a copula: "(is) happy"
+    Predicate,
+}
+
+/// One argument slot of a frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slot {
+    pub kind: SlotKind,
+    pub role: ThematicRole,
+}
+
+impl Slot {
+    const fn new(kind: SlotKind, role: ThematicRole) -> Self {
+        Self { kind, role }
+    }
+}
+
+const RECIPIENT_PREPS: &[&str] = &["to", "for"];
+const LOCATION_PREPS: &[&str] = &[
+    "on", "in", "into", "onto", "under", "at", "near", "beside", "behind",
+    "between", "above", "below", "inside", "over", "to",
+];
+const MOTION_PREPS: &[&str] = &[
+    "to", "into", "toward", "towards", "from", "through", "across", "along",
+    "around", "up", "down", "out", "past", "over", "onto", "in", "at",
+];
+const SOURCE_PREPS: &[&str] = &["from", "off", "out"];
+
+/// Words that start a noun phrase
+pub(crate) const DETERMINERS: &[&str] = &[
+    "a", "an", "the", "my", "your", "his", "her", "its", "our", "their",
+    "this", "that", "these", "those", "some", "any", "every", "each",
+];
+
+/// A subcategorisation frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    /// VerbNet-style description ("NP V NP NP")
+    pub name: &'static str,
+    /// Role of the subject
+    pub subject: ThematicRole,
+    /// Post-verbal slots in surface order
+    pub slots: &'static [Slot],
+}
+
+use SlotKind::*;
+use ThematicRole::*;
+
+impl Frame {
+    /// "John sleeps"
+    pub const INTRANSITIVE: Frame = Frame { name: "NP V", subject: Agent, slots: &[] };
+    /// "The glass broke", "It rains"
+    pub const UNACCUSATIVE: Frame = Frame { name: "NP V", subject: Theme, slots: &[] };
+    /// "John eats fish"
+    pub const TRANSITIVE: Frame = Frame {
+        name: "NP V NP", subject: Agent,
+        slots: &[Slot::new(NounPhrase, Theme)],
+    };
+    /// "John gave Mary the book"
+    pub const DITRANSITIVE: Frame = Frame {
+        name: "NP V NP NP", subject: Agent,
+        slots: &[Slot::new(NounPhrase, Recipient), Slot::new(NounPhrase, Theme)],
+    };
+    /// "John gave the book to Mary"
+    pub const DATIVE: Frame = Frame {
+        name: "NP V NP to NP", subject: Agent,
+        slots: &[Slot::new(NounPhrase, Theme), Slot::new(Prep(RECIPIENT_PREPS), Recipient)],
+    };
+    /// "Put the book on the table"
+    pub const LOCATIVE: Frame = Frame {
+        name: "NP V NP PP", subject: Agent,
+        slots: &[Slot::new(NounPhrase, Theme), Slot::new(Prep(LOCATION_PREPS), Location)],
+    };
+    /// "Take the book from the shelf"
+    pub const SOURCE: Frame = Frame {
+        name: "NP V NP from NP", subject: Agent,
+        slots: &[Slot::new(NounPhrase, Theme), Slot::new(Prep(SOURCE_PREPS), Location)],
+    };
+    /// "Walk to the store"
+    pub const MOTION: Frame = Frame {
+        name: "NP V PP", subject: Agent,
+        slots: &[Slot::new(Prep(MOTION_PREPS), Location)],
+    };
+    /// "Sit on the chair"
+    pub const POSITION: Frame = Frame {
+        name: "NP V PP", subject: Agent,
+        slots: &[Slot::new(Prep(LOCATION_PREPS), Location)],
+    };
+    /// "Cut the bread with a knife"
+    pub const INSTRUMENT: Frame = Frame {
+        name: "NP V NP with NP", subject: Agent,
+        slots: &[Slot::new(NounPhrase, Theme), Slot::new(Prep(&["with"]), Instrument)],
+    };
+    /// "Say that it rains"
+    pub const THAT_CLAUSE: Frame = Frame {
+        name: "NP V that S", subject: Agent,
+        slots: &[Slot::new(ThatClause, Theme)],
+    };
+    /// "Tell Mary that it rains"
+    pub const RECIPIENT_THAT: Frame = Frame {
+        name: "NP V NP that S", subject: Agent,
+        slots: &[Slot::new(NounPhrase, Recipient), Slot::new(ThatClause, Theme)],
+    };
+    /// "Talk to Mary"
+    pub const TO_RECIPIENT: Frame = Frame {
+        name: "NP V to NP", subject: Agent,
+        slots: &[Slot::new(Prep(&["to", "with"]), Recipient)],
+    };
+    /// "Want to go home"
+    pub const INFINITIVE: Frame = Frame {
+        name: "NP V to VP", subject: Agent,
+        slots: &[Slot::new(Infinitive, Theme)],
+    };
+    /// "Ask Mary to leave"
+    pub const OBJECT_INFINITIVE: Frame = Frame {
+        name: "NP V NP to VP", subject: Agent,
+        slots: &[Slot::new(NounPhrase, Recipient), Slot::new(Infinitive, Theme)],
+    };
+    /// "Become happy"
+    pub const COPULAR: Frame = Frame {
+        name: "NP V ADJ", subject: Theme,
+        slots: &[Slot::new(Predicate, Theme)],
+    };
+
+    /// Frame with the given name in the verb data ("ditransitive", "that_clause")
+    pub fn from_name(name: &str) -> Option<Frame> {
+        NAMED.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, f)| *f)
+    }
+
+    /// Name in the verb data, `None` for a frame that is not built in
+    pub fn data_name(&self) -> Option<&'static str> {
+        NAMED.iter().find(|(_, f)| f == self).map(|(n, _)| *n)
+    }
+
+    /// Specificity score: marked slots (PP, clause, infinitive) count double
+    fn specificity(&self) -> usize {
+        self.slots.iter()
+            .map(|s| match s.kind {
+                NounPhrase | Predicate => 1,
+                Prep(_) | ThatClause | Infinitive => 2,
+            })
+            .sum()
+    }
+
+    /// Check whether the frame takes a bare NP object
+    pub fn has_object(&self) -> bool {
+        self.slots.iter().any(|s| s.kind == NounPhrase)
+    }
+
+    /// Role of a slot of the given kind, if any
+    pub fn role_of(&self, kind: SlotKind) -> Option<ThematicRole> {
+        self.slots.iter().find(|s| s.kind == kind).map(|s| s.role)
+    }
+
+    /// Role assigned to a prepositional phrase headed by `prep`, if any
+    pub fn role_for_prep(&self, prep: &str) -> Option<ThematicRole> {
+        self.slots.iter().find_map(|s| match s.kind {
+            Prep(preps) if preps.contains(&prep) => Some(s.role),
+            _ => None,
+        })
+    }
+
+    /// Fill the frame's slots from the tokens after the verb
+    ///
+    /// Returns `None` if a slot cannot be filled. Tokens left over after the
+    /// last slot are reported in `FrameFill::unmatched`.
+    pub fn fill(&self, tokens: &[String], grammar: &EnglishGrammar) -> Option<FrameFill> {
+        let mut arguments = Vec::new();
+        let mut pos = 0;
+
+        for (n, slot) in self.slots.iter().enumerate() {
+            let next = self.slots.get(n + 1).map(|s| s.kind);
+            let rest = &tokens[pos..];
+
+            let (text, used) = match slot.kind {
+                NounPhrase => {
+                    let len = noun_phrase_len(rest, next, grammar);
+                    if len == 0 { return None; }
+                    (rest[..len].join(" "), len)
+                }
+                Prep(preps) => {
+                    let head = rest.first()?;
+                    if !preps.contains(&head.as_str()) { return None; }
+                    let len = phrase_len(&rest[1..], grammar);
+                    if len == 0 { return None; }
+                    (rest[1..1 + len].join(" "), 1 + len)
+                }
+                ThatClause => {
+                    if rest.first().map(|t| t.as_str()) != Some("that") || !is_clause(&rest[1..], grammar) {
+                        return None;
+                    }
+                    (rest[1..].join(" "), rest.len())
+                }
+                Infinitive => {
+                    if rest.first().map(|t| t.as_str()) != Some("to") || rest.len() < 2
+                        || grammar.is_article(&rest[1]) {
+                        return None;
+                    }
+                    (rest[1..].join(" "), rest.len())
+                }
+                Predicate => {
+                    if rest.is_empty() { return None; }
+                    (rest.join(" "), rest.len())
+                }
+            };
+
+            arguments.push(Argument { role: slot.role, text });
+            pos += used;
+        }
+
+        Some(FrameFill {
+            frame: *self,
+            arguments,
+            unmatched: tokens[pos..].to_vec(),
+        })
+    }
+}
+
+/// Built-in frames by their name in the verb data
+const NAMED: &[(&str, Frame)] = &[
+    ("intransitive", Frame::INTRANSITIVE), ("unaccusative", Frame::UNACCUSATIVE),
+    ("transitive", Frame::TRANSITIVE), ("ditransitive", Frame::DITRANSITIVE),
+    ("dative", Frame::DATIVE), ("locative", Frame::LOCATIVE), ("source", Frame::SOURCE),
+    ("motion", Frame::MOTION), ("position", Frame::POSITION), ("instrument", Frame::INSTRUMENT),
+    ("that_clause", Frame::THAT_CLAUSE), ("recipient_that", Frame::RECIPIENT_THAT),
+    ("to_recipient", Frame::TO_RECIPIENT), ("infinitive", Frame::INFINITIVE),
+    ("object_infinitive", Frame::OBJECT_INFINITIVE), ("copular", Frame::COPULAR),
+];
+
+/// A filled argument slot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Argument {
+    pub role: ThematicRole,
+    pub text: String,
+}
+
+/// Result of filling a frame from a token sequence
+#[derive(Debug, Clone)]
+pub struct FrameFill {
+    pub frame: Frame,
+    pub arguments: Vec<Argument>,
+    /// Trailing tokens not covered by any slot ("with care")
+    pub unmatched: Vec<String>,
+}
+
+/// Choose the frame that covers the most tokens, preferring more slots
+pub fn best_fill(frames: &[Frame], tokens: &[String], grammar: &EnglishGrammar) -> Option<FrameFill> {
+    let mut best: Option<FrameFill> = None;
+    for frame in frames {
+        if let Some(fill) = frame.fill(tokens, grammar) {
+            let better = match &best {
+                None => true,
+                Some(b) => fill.unmatched.len() < b.unmatched.len()
+                    || (fill.unmatched.len() == b.unmatched.len()
+                        && fill.frame.specificity() > b.frame.specificity()),
+            };
+            if better {
+                best = Some(fill);
+            }
+        }
+    }
+    best
+}
+
+/// Check that the words after "that" form a clause rather than an NP
+/// ("that it rains", "that the file is ready" vs "that file")
+fn is_clause(tokens: &[String], grammar: &EnglishGrammar) -> bool {
+    match tokens.first() {
+        None => false,
+        Some(first) => tokens.len() >= 2
+            && ((grammar.is_pronoun(first) && first != "that")
+                || grammar.is_article(first)
+                || tokens.iter().any(|t| grammar.is_auxiliary(t))),
+    }
+}
+
+/// Length of a phrase running up to the next preposition (except "of")
+fn phrase_len(tokens: &[String], grammar: &EnglishGrammar) -> usize {
+    tokens.iter()
+        .position(|t| t != "of" && grammar.is_preposition(t))
+        .unwrap_or(tokens.len())
+}
+
+/// Length of an object NP, splitting "Mary the book" when another NP follows
+fn noun_phrase_len(tokens: &[String], next: Option<SlotKind>, grammar: &EnglishGrammar) -> usize {
+    let mut len = phrase_len(tokens, grammar);
+    if next == Some(ThatClause) {
+        if let Some(p) = tokens[..len].iter().position(|t| t == "that") {
+            len = p;
+        }
+    }
+    if next == Some(NounPhrase) && len > 1 {
+        // Second NP starts at the next determiner: "Mary | the book",
+        // or after a bare first word: "Mary | apples"
+        len = tokens[1..len].iter()
+            .position(|t| DETERMINERS.contains(&t.as_str()))
+            .map(|p| p + 1)
+            .unwrap_or(if DETERMINERS.contains(&tokens[0].as_str()) { len } else { 1 });
+    }
+    len
+}
+
+/// Default frames for a verb group and transitivity
+pub fn default_frames(group: VerbGroup, transitive: Option<bool>) -> Vec<Frame> {
+    use VerbGroup as G;
+
+    let mut frames = match group {
+        G::Give | G::Lend | G::Send | G::Return => {
+            vec![Frame::TRANSITIVE, Frame::DITRANSITIVE, Frame::DATIVE]
+        }
+        G::Take | G::Steal | G::Borrow | G::Receive | G::Acquire | G::Remove => {
+            vec![Frame::TRANSITIVE, Frame::SOURCE]
+        }
+        G::Put | G::Attach | G::Connect | G::Throw => {
+            vec![Frame::TRANSITIVE, Frame::LOCATIVE]
+        }
+        G::Hit | G::Cut | G::Push | G::Pull | G::Kick | G::Touch | G::Open | G::Close
+        | G::Break | G::Damage | G::Kill | G::Destroy | G::Burn | G::Cook | G::Draw
+        | G::Build | G::Make | G::Create | G::Erase | G::Catch | G::Hold | G::Lift
+        | G::Measure | G::Eat | G::Drink => {
+            vec![Frame::TRANSITIVE, Frame::INSTRUMENT]
+        }
+        G::Write => vec![Frame::TRANSITIVE, Frame::DATIVE, Frame::INSTRUMENT],
+        G::Walk | G::Run | G::Jump | G::Fly | G::Swim | G::Climb | G::Enter | G::Exit
+        | G::Turn | G::Follow => {
+            vec![Frame::INTRANSITIVE, Frame::MOTION]
+        }
+        G::Fall => vec![Frame::UNACCUSATIVE, Frame::MOTION],
+        G::Sleep | G::Wake | G::Sit | G::Stand | G::Lie | G::Kneel | G::Bend => {
+            vec![Frame::INTRANSITIVE, Frame::POSITION]
+        }
+        G::Speak => vec![Frame::THAT_CLAUSE, Frame::TO_RECIPIENT],
+        G::Argue => vec![Frame::INTRANSITIVE, Frame::THAT_CLAUSE, Frame::TO_RECIPIENT],
+        G::Explain | G::Answer | G::Suggest => {
+            vec![Frame::TRANSITIVE, Frame::THAT_CLAUSE, Frame::DATIVE]
+        }
+        G::Promise => vec![Frame::TRANSITIVE, Frame::DITRANSITIVE, Frame::THAT_CLAUSE, Frame::INFINITIVE],
+        G::Ask | G::Warn | G::Command => {
+            vec![Frame::TRANSITIVE, Frame::OBJECT_INFINITIVE, Frame::RECIPIENT_THAT]
+        }
+        G::Think | G::Know | G::Believe | G::Imagine | G::See | G::Hear | G::Feel => {
+            vec![Frame::TRANSITIVE, Frame::THAT_CLAUSE]
+        }
+        G::Remember | G::Forget | G::Learn | G::Decide | G::Plan | G::Hope => {
+            vec![Frame::TRANSITIVE, Frame::THAT_CLAUSE, Frame::INFINITIVE]
+        }
+        G::Want | G::Need | G::Try | G::Begin | G::Continue | G::Fail | G::Love | G::Hate => {
+            vec![Frame::TRANSITIVE, Frame::INFINITIVE]
+        }
+        G::Allow | G::Force | G::Cause | G::HelpCausation | G::Help => {
+            vec![Frame::TRANSITIVE, Frame::OBJECT_INFINITIVE]
+        }
+        G::Be | G::Seem | G::Become | G::Remain => vec![Frame::COPULAR],
+        G::Rain | G::Snow | G::Blow | G::Shine | G::Sound => vec![Frame::UNACCUSATIVE],
+        G::Smell | G::Taste => vec![Frame::TRANSITIVE, Frame::COPULAR],
+        G::Grow => vec![Frame::UNACCUSATIVE, Frame::TRANSITIVE, Frame::COPULAR],
+        G::Fear => vec![Frame::TRANSITIVE, Frame::THAT_CLAUSE, Frame::INFINITIVE],
+        G::Lead | G::Keep => vec![Frame::TRANSITIVE, Frame::LOCATIVE],
+        G::Lose | G::Separate => vec![Frame::TRANSITIVE, Frame::SOURCE],
+        G::Share => vec![Frame::TRANSITIVE, Frame::TO_RECIPIENT],
+        G::Meet | G::Fight | G::Cooperate | G::Compete | G::Combine => {
+            vec![Frame::INTRANSITIVE, Frame::TRANSITIVE]
+        }
+        G::Analyze | G::Enjoy | G::Surprise | G::Anger | G::Satisfy | G::Have | G::Own
+        | G::ControlGroup | G::Govern | G::Supervise | G::Influence | G::Obey | G::Resist
+        | G::End | G::Stop | G::Repeat | G::Prevent | G::Practice | G::Compare | G::Count => {
+            vec![Frame::TRANSITIVE]
+        }
+        G::Suffer | G::Breathe | G::Succeed => vec![Frame::INTRANSITIVE],
+        // Modals take a bare verb phrase, which no frame describes; the
+        // transitivity defaults below apply
+        G::Can | G::Should | G::Generic => Vec::new(),
+    };
+
+    // Align with transitivity: intransitive verbs take no NP object,
+    // transitive verbs need a complement
+    let slotless = |f: &Frame| f.slots.is_empty();
+    match transitive {
+        Some(false) => frames.retain(|f| !f.has_object()),
+        Some(true) => frames.retain(|f| !slotless(f)),
+        None => {}
+    }
+    if transitive != Some(false) && !frames.iter().any(|f| f.has_object() || f.subject == Theme) {
+        frames.insert(0, Frame::TRANSITIVE);
+    }
+    if transitive != Some(true) && !frames.iter().any(slotless) {
+        frames.insert(0, Frame::INTRANSITIVE);
+    }
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|w| w.to_string()).collect()
+    }
+
+    fn role(fill: &FrameFill, role: ThematicRole) -> Option<&str> {
+        fill.arguments.iter().find(|a| a.role == role).map(|a| a.text.as_str())
+    }
+
+    #[test]
+    fn test_ditransitive_and_dative() {
+        let g = EnglishGrammar::new();
+        let frames = default_frames(VerbGroup::Give, Some(true));
+
+        let fill = best_fill(&frames, &tokens("mary the book"), &g).unwrap();
+        assert_eq!(fill.frame, Frame::DITRANSITIVE);
+        assert_eq!(role(&fill, Recipient), Some("mary"));
+        assert_eq!(role(&fill, Theme), Some("the book"));
+
+        let fill = best_fill(&frames, &tokens("the book to mary"), &g).unwrap();
+        assert_eq!(fill.frame, Frame::DATIVE);
+        assert_eq!(role(&fill, Recipient), Some("mary"));
+
+        let fill = best_fill(&frames, &tokens("the red book"), &g).unwrap();
+        assert_eq!(fill.frame, Frame::TRANSITIVE);
+    }
+
+    #[test]
+    fn test_clause_and_infinitive() {
+        let g = EnglishGrammar::new();
+        let say = default_frames(VerbGroup::Speak, Some(true));
+        let fill = best_fill(&say, &tokens("that it is late"), &g).unwrap();
+        assert_eq!(fill.frame, Frame::THAT_CLAUSE);
+        assert_eq!(role(&fill, Theme), Some("it is late"));
+        let fill = best_fill(&say, &tokens("that word"), &g).unwrap();
+        assert_eq!(fill.frame, Frame::TRANSITIVE);
+
+        let want = default_frames(VerbGroup::Want, Some(true));
+        let fill = best_fill(&want, &tokens("to go home"), &g).unwrap();
+        assert_eq!(fill.frame, Frame::INFINITIVE);
+        assert_eq!(role(&fill, Theme), Some("go home"));
+    }
+
+    #[test]
+    fn test_locative_and_leftovers() {
+        let g = EnglishGrammar::new();
+        let put = default_frames(VerbGroup::Put, Some(true));
+        let fill = best_fill(&put, &tokens("the book on the table with care"), &g).unwrap();
+        assert_eq!(fill.frame, Frame::LOCATIVE);
+        assert_eq!(role(&fill, Location), Some("the table"));
+        assert_eq!(fill.unmatched, tokens("with care"));
+
+        let keep = default_frames(VerbGroup::Keep, Some(true));
+        let fill = best_fill(&keep, &tokens("the milk in the fridge"), &g).unwrap();
+        assert_eq!(fill.frame, Frame::LOCATIVE);
+    }
+
+    #[test]
+    fn test_transitivity_filter() {
+        let sleep = default_frames(VerbGroup::Sleep, Some(false));
+        assert!(sleep.iter().all(|f| !f.has_object()));
+        let generic = default_frames(VerbGroup::Generic, None);
+        assert_eq!(generic, vec![Frame::INTRANSITIVE, Frame::TRANSITIVE]);
+    }
+}
@@ -0,0 +1,391 @@
+//! Orthographic inflection for regular verb forms
+//!
+//! Generates past, -ing and third person forms from a base form using
+//! English spelling rules:
+//!
+//! - **Consonant doubling** only when the final syllable is stressed
+//!   (stop → stopped, occur → occurred, but visit → visited)
+//! - **Stress annotations**: a built-in table of stress-final verbs
+//!   (prefer, control, admit...) plus per-verb overrides
+//! - **Exceptions table** for forms the rules cannot derive
+//!   (panic → panicked, singe → singeing)
+//! - **British spelling** of final -l (travel → travelled vs US traveled)
+
+use std::collections::HashMap;
+
+/// Spelling convention for inflected forms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Spelling {
+    /// US spelling: traveled, canceling
+    #[default]
+    American,
+    /// British spelling: travelled, cancelling
+    British,
+}
+
+/// Stress position of a verb's final syllable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stress {
+    /// Final syllable stressed (preFER, conTROL) - final consonant doubles
+    Final,
+    /// Final syllable unstressed (VISit, OFfer) - no doubling
+    NonFinal,
+}
+
+/// Regular inflected forms of a verb
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InflectedForms {
+    /// Past tense (and past participle for regular verbs)
+    pub past: String,
+    /// Present participle (-ing)
+    pub present_participle: String,
+    /// Third person singular
+    pub third_person: String,
+}
+
+/// Multi-syllable verbs with final stress (consonant doubles before -ed/-ing)
+const STRESS_FINAL: &[&str] = &[
+    "abet", "abhor", "acquit", "admit", "allot", "annul", "begin", "beget",
+    "commit", "compel", "concur", "confer", "control", "debug", "defer",
+    "deter", "dispel", "embed", "emit", "enrol", "enthral", "equip", "excel",
+    "expel", "extol", "forbid", "forget", "impel", "incur", "infer", "inter",
+    "occur", "omit", "outbid", "outrun", "outwit", "overlap", "patrol",
+    "permit", "prefer", "propel", "rebel", "recur", "refer", "regret",
+    "remit", "repel", "resubmit", "submit", "transfer", "transmit", "unplug",
+    "unwrap", "upset",
+];
+
+/// Verbs that always double despite non-final stress
+const ALWAYS_DOUBLE: &[&str] = &[
+    "format", "handicap", "humbug", "kidnap", "program", "worship", "zigzag",
+];
+
+/// Forms the spelling rules cannot derive: (base, past, -ing, third person)
+const EXCEPTIONS: &[(&str, &str, &str, &str)] = &[
+    ("panic", "panicked", "panicking", "panics"),
+    ("picnic", "picnicked", "picnicking", "picnics"),
+    ("mimic", "mimicked", "mimicking", "mimics"),
+    ("traffic", "trafficked", "trafficking", "traffics"),
+    ("frolic", "frolicked", "frolicking", "frolics"),
+    ("singe", "singed", "singeing", "singes"),
+    ("dye", "dyed", "dyeing", "dyes"),
+    ("quiz", "quizzed", "quizzing", "quizzes"),
+    ("ski", "skied", "skiing", "skis"),
+    ("taxi", "taxied", "taxiing", "taxis"),
+];
+
+/// Verbs ending in -o that take -es in the third person (most take -s: radios, videos)
+const O_ES: &[&str] = &[
+    "do", "echo", "embargo", "forego", "forgo", "go", "outdo", "overdo", "redo",
+    "torpedo", "undergo", "undo", "veto",
+];
+
+/// Orthographic inflector for regular verb forms
+#[derive(Debug, Clone, Default)]
+pub struct Inflector {
+    spelling: Spelling,
+    /// Per-verb stress overrides
+    stress: HashMap<String, Stress>,
+    /// Per-verb form overrides
+    exceptions: HashMap<String, InflectedForms>,
+}
+
+impl Inflector {
+    /// Create an inflector with US spelling
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an inflector with the given spelling convention
+    pub fn with_spelling(spelling: Spelling) -> Self {
+        Self { spelling, ..Self::default() }
+    }
+
+    /// Annotate the stress of a verb's final syllable
+    pub fn set_stress(&mut self, base: &str, stress: Stress) {
+        self.stress.insert(base.to_lowercase(), stress);
+    }
+
+    /// Register explicit forms for a verb
+    pub fn add_exception(&mut self, base: &str, forms: InflectedForms) {
+        self.exceptions.insert(base.to_lowercase(), forms);
+    }
+
+    /// Spelling convention in use
+    pub fn spelling(&self) -> Spelling {
+        self.spelling
+    }
+
+    /// Stress of the final syllable: override, built-in table, or syllable count
+    pub fn stress_of(&self, base: &str) -> Stress {
+        let b = base.to_lowercase();
+        if let Some(s) = self.stress.get(&b) {
+            return *s;
+        }
+        default_stress(&b)
+    }
+
+    /// Generate all regular forms of a verb
+    pub fn inflect(&self, base: &str) -> InflectedForms {
+        let b = base.to_lowercase();
+        if let Some(forms) = self.exceptions.get(&b) {
+            return forms.clone();
+        }
+        if let Some(&(_, past, ing, third)) = EXCEPTIONS.iter().find(|(e, ..)| *e == b) {
+            return InflectedForms {
+                past: past.to_string(),
+                present_participle: ing.to_string(),
+                third_person: third.to_string(),
+            };
+        }
+        let double = self.should_double(&b);
+        InflectedForms {
+            past: past_form(&b, double),
+            present_participle: ing_form(&b, double),
+            third_person: third_form(&b),
+        }
+    }
+
+    /// Past tense / past participle ("occur" -> "occurred")
+    pub fn past(&self, base: &str) -> String {
+        self.inflect(base).past
+    }
+
+    /// Present participle ("refer" -> "referring")
+    pub fn present_participle(&self, base: &str) -> String {
+        self.inflect(base).present_participle
+    }
+
+    /// Third person singular ("go" -> "goes")
+    pub fn third_person(&self, base: &str) -> String {
+        self.inflect(base).third_person
+    }
+
+    /// British forms that differ from the US ones, with this inflector's
+    /// stress and form overrides
+    pub fn british_variants(&self, base: &str) -> Vec<String> {
+        let us = Inflector { spelling: Spelling::American, ..self.clone() }.inflect(base);
+        let gb = Inflector { spelling: Spelling::British, ..self.clone() }.inflect(base);
+        let mut variants = Vec::new();
+        if gb.past != us.past {
+            variants.push(gb.past);
+        }
+        if gb.present_participle != us.present_participle {
+            variants.push(gb.present_participle);
+        }
+        variants
+    }
+
+    /// Check if the final consonant doubles before -ed/-ing
+    pub fn should_double(&self, base: &str) -> bool {
+        let b = base.to_lowercase();
+        if ALWAYS_DOUBLE.contains(&b.as_str()) {
+            return true;
+        }
+        if !ends_with_cvc(&b) {
+            return false;
+        }
+        // British English doubles final -l after a single vowel regardless of stress
+        if self.spelling == Spelling::British && b.ends_with('l') {
+            return true;
+        }
+        self.stress_of(&b) == Stress::Final
+    }
+}
+
+/// Default stress: monosyllables and listed verbs are stress-final
+fn default_stress(base: &str) -> Stress {
+    if count_syllables(base) <= 1 || STRESS_FINAL.contains(&base) {
+        Stress::Final
+    } else {
+        Stress::NonFinal
+    }
+}
+
+/// Regular forms with US spelling and built-in stress data
+pub fn inflect(base: &str) -> InflectedForms {
+    Inflector::new().inflect(base)
+}
+
+/// British variants of a verb's regular forms that differ from US spelling
+pub fn british_variants(base: &str) -> Vec<String> {
+    Inflector::new().british_variants(base)
+}
+
+fn past_form(base: &str, double: bool) -> String {
+    if base.ends_with('e') {
+        format!("{}d", base)
+    } else if ends_with_consonant_y(base) {
+        format!("{}ied", &base[..base.len() - 1])
+    } else if double {
+        format!("{}{}ed", base, base.chars().last().unwrap_or_default())
+    } else {
+        format!("{}ed", base)
+    }
+}
+
+fn ing_form(base: &str, double: bool) -> String {
+    if let Some(stem) = base.strip_suffix("ie") {
+        format!("{}ying", stem)
+    } else if base.ends_with("ee") || base.ends_with("oe") || base.ends_with("ye") {
+        format!("{}ing", base)
+    } else if let Some(stem) = base.strip_suffix('e') {
+        if stem.is_empty() { format!("{}ing", base) } else { format!("{}ing", stem) }
+    } else if double {
+        format!("{}{}ing", base, base.chars().last().unwrap_or_default())
+    } else {
+        format!("{}ing", base)
+    }
+}
+
+fn third_form(base: &str) -> String {
+    if base.ends_with('s') || base.ends_with('x') || base.ends_with('z')
+        || base.ends_with("ch") || base.ends_with("sh") {
+        format!("{}es", base)
+    } else if ends_with_consonant_y(base) {
+        format!("{}ies", &base[..base.len() - 1])
+    } else if O_ES.contains(&base) {
+        format!("{}es", base)
+    } else {
+        format!("{}s", base)
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    "aeiou".contains(c)
+}
+
+fn ends_with_consonant_y(word: &str) -> bool {
+    let chars: Vec<char> = word.chars().collect();
+    chars.len() > 1 && chars[chars.len() - 1] == 'y' && !is_vowel(chars[chars.len() - 2])
+}
+
+/// Single vowel letter followed by a single final consonant (not w/x/y);
+/// "qu" counts as a consonant (quit, equip)
+fn ends_with_cvc(word: &str) -> bool {
+    let chars: Vec<char> = word.chars().collect();
+    let n = chars.len();
+    if n < 2 {
+        return false;
+    }
+    let last = chars[n - 1];
+    let vowel = chars[n - 2];
+    if is_vowel(last) || ['w', 'x', 'y'].contains(&last) || !is_vowel(vowel) {
+        return false;
+    }
+    if n == 2 {
+        return true;
+    }
+    let before = chars[n - 3];
+    !is_vowel(before) || (before == 'u' && n >= 4 && chars[n - 4] == 'q')
+}
+
+/// Rough syllable count by vowel groups, with silent final -e
+fn count_syllables(word: &str) -> usize {
+    let mut count = 0;
+    let mut prev_vowel = false;
+    for c in word.chars() {
+        let v = is_vowel(c) || c == 'y';
+        if v && !prev_vowel {
+            count += 1;
+        }
+        prev_vowel = v;
+    }
+    if word.ends_with('e') && !word.ends_with("le") && !word.ends_with("ee") && count > 1 {
+        count -= 1;
+    }
+    count.max(1)
+}
+
+/// Forms produced by the original length-based heuristic, which only doubled
+/// the final consonant of words up to 3 letters long. Kept to report changed forms.
+pub(super) fn legacy_forms(base: &str) -> InflectedForms {
+    let chars: Vec<char> = base.chars().collect();
+    let double = chars.len() >= 2 && chars.len() <= 3
+        && !is_vowel(chars[chars.len() - 1]) && is_vowel(chars[chars.len() - 2])
+        && !['w', 'x', 'y'].contains(&chars[chars.len() - 1]);
+
+    let ing = if let Some(stem) = base.strip_suffix("ie") {
+        format!("{}ying", stem)
+    } else if base.ends_with('e') && !base.ends_with("ee") {
+        format!("{}ing", &base[..base.len() - 1])
+    } else if double {
+        format!("{}{}ing", base, chars[chars.len() - 1])
+    } else {
+        format!("{}ing", base)
+    };
+    let third = if base.ends_with('s') || base.ends_with('x') || base.ends_with('z')
+        || base.ends_with("ch") || base.ends_with("sh") {
+        format!("{}es", base)
+    } else if ends_with_consonant_y(base) {
+        format!("{}ies", &base[..base.len() - 1])
+    } else {
+        format!("{}s", base)
+    };
+
+    InflectedForms {
+        past: past_form(base, double),
+        present_participle: ing,
+        third_person: third,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stress_final_doubling() {
+        assert_eq!(inflect("occur").past, "occurred");
+        assert_eq!(inflect("control").past, "controlled");
+        assert_eq!(inflect("refer").present_participle, "referring");
+        assert_eq!(inflect("admit").past, "admitted");
+        assert_eq!(inflect("stop").past, "stopped");
+        assert_eq!(inflect("quit").present_participle, "quitting");
+    }
+
+    #[test]
+    fn test_non_final_stress() {
+        assert_eq!(inflect("visit").past, "visited");
+        assert_eq!(inflect("offer").present_participle, "offering");
+        assert_eq!(inflect("open").past, "opened");
+        assert_eq!(inflect("travel").past, "traveled");
+    }
+
+    #[test]
+    fn test_british_spelling() {
+        let gb = Inflector::with_spelling(Spelling::British);
+        assert_eq!(gb.past("travel"), "travelled");
+        assert_eq!(gb.present_participle("cancel"), "cancelling");
+        assert_eq!(gb.past("visit"), "visited");
+        assert_eq!(british_variants("travel"), vec!["travelled", "travelling"]);
+        assert!(british_variants("walk").is_empty());
+    }
+
+    #[test]
+    fn test_annotations_and_exceptions() {
+        let mut inf = Inflector::new();
+        assert_eq!(inf.past("panic"), "panicked");
+        assert_eq!(inf.present_participle("singe"), "singeing");
+        assert_eq!(inf.third_person("go"), "goes");
+        assert_eq!(inf.third_person("torpedo"), "torpedoes");
+        assert_eq!(inf.third_person("radio"), "radios");
+        assert_eq!(inf.third_person("video"), "videos");
+
+        inf.set_stress("rebut", Stress::Final);
+        assert_eq!(inf.past("rebut"), "rebutted");
+        inf.set_stress("admit", Stress::NonFinal);
+        assert_eq!(inf.past("admit"), "admited");
+    }
+
+    #[test]
+    fn test_mixed_case() {
+        let mut inf = Inflector::new();
+        assert_eq!(inf.stress_of("Occur"), Stress::Final);
+        assert!(inf.should_double("COMMIT"));
+        assert!(inf.should_double("Kidnap"));
+        assert_eq!(inf.past("Occur"), "occurred");
+        inf.set_stress("Rebut", Stress::Final);
+        assert_eq!(inf.stress_of("REBUT"), Stress::Final);
+    }
+}
@@ -22,12 +22,32 @@
 //! - **Consumption**: eat, drink, consume, absorb, digest...
 //! - And 10+ more categories...
 
-use std::collections::HashMap;
-
-mod data;
-mod data2;
-mod data3;
-mod data4;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+pub mod inflection;
+pub mod conjugation;
+pub mod phrasal;
+pub mod frames;
+pub mod relations;
+pub mod similarity;
+pub mod derivation;
+pub mod format;
+mod tsv;
+pub mod registry;
+pub mod taxonomy;
+pub mod selection;
+mod builtin;
+
+use inflection::Stress;
+pub use frames::{Frame, ThematicRole};
+pub use relations::{Relation, LexicalPath};
+pub use phrasal::{PhrasalVerb, PhrasalMatch};
+pub use derivation::{DerivationKind, DerivedWord};
+pub use format::{FormatError, FormatErrorKind};
+pub use registry::{CategoryRegistry, CategoryId, GroupId, CategoryKey, GroupKey, RegistryError};
+pub use taxonomy::{Taxonomy, Taxon, TaxonClass, ClassId};
+pub use selection::{SemanticType, SelectionalPreference};
 
 /// Functional category for verbs
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -116,6 +136,14 @@ impl FunctionalCategory {
         ]
     }
 
+    /// Find a category by its variant or display name, ignoring case
+    /// ("Physical" or "Physical Action")
+    pub fn from_name(name: &str) -> Option<FunctionalCategory> {
+        Self::all().iter().copied().find(|c| {
+            c.name().eq_ignore_ascii_case(name) || format!("{:?}", c).eq_ignore_ascii_case(name)
+        })
+    }
+
     /// Get human-readable name
     pub fn name(&self) -> &'static str {
         match self {
@@ -376,6 +404,48 @@ pub enum VerbGroup {
 }
 
 impl VerbGroup {
+    /// Get all groups
+    pub fn all() -> &'static [VerbGroup] {
+        &[
+            VerbGroup::Walk, VerbGroup::Run, VerbGroup::Jump, VerbGroup::Fly,
+            VerbGroup::Swim, VerbGroup::Climb, VerbGroup::Fall, VerbGroup::Turn,
+            VerbGroup::Enter, VerbGroup::Exit, VerbGroup::See, VerbGroup::Hear,
+            VerbGroup::Feel, VerbGroup::Smell, VerbGroup::Taste, VerbGroup::Speak,
+            VerbGroup::Ask, VerbGroup::Answer, VerbGroup::Explain, VerbGroup::Argue,
+            VerbGroup::Promise, VerbGroup::Warn, VerbGroup::Command, VerbGroup::Suggest,
+            VerbGroup::Think, VerbGroup::Know, VerbGroup::Believe, VerbGroup::Remember,
+            VerbGroup::Forget, VerbGroup::Learn, VerbGroup::Decide, VerbGroup::Plan,
+            VerbGroup::Imagine, VerbGroup::Analyze, VerbGroup::Love, VerbGroup::Hate,
+            VerbGroup::Fear, VerbGroup::Hope, VerbGroup::Enjoy, VerbGroup::Suffer,
+            VerbGroup::Surprise, VerbGroup::Anger, VerbGroup::Satisfy, VerbGroup::Hit,
+            VerbGroup::Cut, VerbGroup::Push, VerbGroup::Pull, VerbGroup::Throw,
+            VerbGroup::Catch, VerbGroup::Hold, VerbGroup::Lift, VerbGroup::Open,
+            VerbGroup::Close, VerbGroup::Touch, VerbGroup::Kick, VerbGroup::Be,
+            VerbGroup::Have, VerbGroup::Seem, VerbGroup::Remain, VerbGroup::Become,
+            VerbGroup::Give, VerbGroup::Take, VerbGroup::Send, VerbGroup::Receive,
+            VerbGroup::Lend, VerbGroup::Borrow, VerbGroup::Steal, VerbGroup::Return,
+            VerbGroup::Make, VerbGroup::Create, VerbGroup::Build, VerbGroup::Write,
+            VerbGroup::Draw, VerbGroup::Cook, VerbGroup::Grow, VerbGroup::Destroy,
+            VerbGroup::Break, VerbGroup::Kill, VerbGroup::Damage, VerbGroup::Burn,
+            VerbGroup::Erase, VerbGroup::ControlGroup, VerbGroup::Lead, VerbGroup::Govern,
+            VerbGroup::Supervise, VerbGroup::Influence, VerbGroup::Own, VerbGroup::Acquire,
+            VerbGroup::Lose, VerbGroup::Keep, VerbGroup::Share, VerbGroup::Meet,
+            VerbGroup::Help, VerbGroup::Fight, VerbGroup::Cooperate, VerbGroup::Compete,
+            VerbGroup::Follow, VerbGroup::Obey, VerbGroup::Resist, VerbGroup::Eat,
+            VerbGroup::Drink, VerbGroup::Breathe, VerbGroup::Sleep, VerbGroup::Wake,
+            VerbGroup::Sit, VerbGroup::Stand, VerbGroup::Lie, VerbGroup::Kneel,
+            VerbGroup::Bend, VerbGroup::Begin, VerbGroup::End, VerbGroup::Continue,
+            VerbGroup::Stop, VerbGroup::Repeat, VerbGroup::Cause, VerbGroup::Allow,
+            VerbGroup::Prevent, VerbGroup::Force, VerbGroup::HelpCausation, VerbGroup::Try,
+            VerbGroup::Succeed, VerbGroup::Fail, VerbGroup::Practice, VerbGroup::Connect,
+            VerbGroup::Separate, VerbGroup::Combine, VerbGroup::Attach, VerbGroup::Shine,
+            VerbGroup::Sound, VerbGroup::Measure, VerbGroup::Compare, VerbGroup::Count,
+            VerbGroup::Put, VerbGroup::Remove, VerbGroup::Rain, VerbGroup::Snow,
+            VerbGroup::Blow, VerbGroup::Want, VerbGroup::Need, VerbGroup::Can,
+            VerbGroup::Should, VerbGroup::Generic,
+        ]
+    }
+
     /// Get the functional category this group belongs to
     pub fn category(&self) -> FunctionalCategory {
         match self {
@@ -482,6 +552,14 @@ impl VerbGroup {
         }
     }
 
+    /// Find a group by its variant or display name, ignoring case
+    /// ("HelpCausation" or "Help (Causative)")
+    pub fn from_name(name: &str) -> Option<VerbGroup> {
+        Self::all().iter().copied().find(|g| {
+            g.name().eq_ignore_ascii_case(name) || format!("{:?}", g).eq_ignore_ascii_case(name)
+        })
+    }
+
     /// Get the name of this group
     pub fn name(&self) -> &'static str {
         match self {
@@ -631,6 +709,45 @@ impl VerbGroup {
     }
 }
 
+/// Which principal part of a verb a surface form is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormRole {
+    /// walk
+    Base,
+    /// walked
+    Past,
+    /// walked (has walked)
+    PastParticiple,
+    /// walking
+    PresentParticiple,
+    /// walks
+    ThirdPerson,
+}
+
+impl FormRole {
+    /// Get all roles
+    pub fn all() -> &'static [FormRole] {
+        &[
+            FormRole::Base,
+            FormRole::Past,
+            FormRole::PastParticiple,
+            FormRole::PresentParticiple,
+            FormRole::ThirdPerson,
+        ]
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            FormRole::Base => "Base",
+            FormRole::Past => "Past",
+            FormRole::PastParticiple => "Past Participle",
+            FormRole::PresentParticiple => "Present Participle",
+            FormRole::ThirdPerson => "Third Person",
+        }
+    }
+}
+
 /// A single verb entry
 #[derive(Debug, Clone)]
 pub struct VerbEntry {
@@ -658,6 +775,14 @@ pub struct VerbEntry {
     pub synonyms: Vec<String>,
     /// Opposite verbs (antonyms)
     pub antonyms: Vec<String>,
+    /// Alternative spellings of inflected forms (British travelled, travelling)
+    pub variants: Vec<String>,
+    /// Subcategorisation frames (argument slots and thematic roles)
+    pub frames: Vec<Frame>,
+    /// Custom category from the database's `CategoryRegistry`
+    pub custom_category: Option<CategoryId>,
+    /// Custom group from the database's `CategoryRegistry`
+    pub custom_group: Option<GroupId>,
 }
 
 impl VerbEntry {
@@ -669,13 +794,13 @@ impl VerbEntry {
         transitive: Option<bool>,
         frequency: u8,
     ) -> Self {
-        let base_s = base.to_string();
+        let forms = inflection::inflect(base);
         Self {
-            past: Self::regular_past(&base_s),
-            past_participle: Self::regular_past(&base_s),
-            present_participle: Self::regular_ing(&base_s),
-            third_person: Self::regular_third(&base_s),
-            base: base_s,
+            past: forms.past.clone(),
+            past_participle: forms.past,
+            present_participle: forms.present_participle,
+            third_person: forms.third_person,
+            base: base.to_string(),
             category,
             group,
             irregular: false,
@@ -683,6 +808,10 @@ impl VerbEntry {
             frequency,
             synonyms: Vec::new(),
             antonyms: Vec::new(),
+            variants: inflection::british_variants(base),
+            frames: frames::default_frames(group, transitive),
+            custom_category: None,
+            custom_group: None,
         }
     }
 
@@ -696,13 +825,13 @@ impl VerbEntry {
         transitive: Option<bool>,
         frequency: u8,
     ) -> Self {
-        let base_s = base.to_string();
+        let forms = inflection::inflect(base);
         Self {
             past: past.to_string(),
             past_participle: past_participle.to_string(),
-            present_participle: Self::regular_ing(&base_s),
-            third_person: Self::regular_third(&base_s),
-            base: base_s,
+            present_participle: forms.present_participle,
+            third_person: forms.third_person,
+            base: base.to_string(),
             category,
             group,
             irregular: true,
@@ -710,6 +839,10 @@ impl VerbEntry {
             frequency,
             synonyms: Vec::new(),
             antonyms: Vec::new(),
+            variants: Vec::new(),
+            frames: frames::default_frames(group, transitive),
+            custom_category: None,
+            custom_group: None,
         }
     }
 
@@ -725,69 +858,85 @@ impl VerbEntry {
         self
     }
 
-    /// Get regular past tense
-    fn regular_past(base: &str) -> String {
-        if base.ends_with('e') {
-            format!("{}d", base)
-        } else if base.ends_with('y') && base.len() > 1 {
-            let chars: Vec<char> = base.chars().collect();
-            let second_last = chars.get(chars.len() - 2);
-            if second_last.map(|c| !"aeiou".contains(*c)).unwrap_or(false) {
-                format!("{}ied", &base[..base.len()-1])
-            } else {
-                format!("{}ed", base)
-            }
-        } else if Self::should_double_final(base) {
-            format!("{}{}ed", base, base.chars().last().unwrap())
-        } else {
-            format!("{}ed", base)
-        }
+    /// Replace the group's default frames
+    pub fn with_frames(mut self, frames: &[Frame]) -> Self {
+        self.frames = frames.to_vec();
+        self
     }
 
-    /// Get regular -ing form
-    fn regular_ing(base: &str) -> String {
-        if let Some(stem) = base.strip_suffix("ie") {
-            format!("{}ying", stem)
-        } else if base.ends_with('e') && !base.ends_with("ee") {
-            format!("{}ing", &base[..base.len()-1])
-        } else if Self::should_double_final(base) {
-            format!("{}{}ing", base, base.chars().last().unwrap())
-        } else {
-            format!("{}ing", base)
+    /// Place the verb in a custom category
+    pub fn in_custom_category(mut self, category: CategoryId) -> Self {
+        self.custom_category = Some(category);
+        self
+    }
+
+    /// Place the verb in a custom group (and the group's custom category, if any)
+    pub fn in_custom_group(mut self, group: GroupId) -> Self {
+        self.custom_group = Some(group);
+        self
+    }
+
+    /// Custom category if set, otherwise the built-in one
+    pub fn category_key(&self) -> CategoryKey {
+        self.custom_category.map(CategoryKey::Custom).unwrap_or(CategoryKey::Builtin(self.category))
+    }
+
+    /// Custom group if set, otherwise the built-in one
+    pub fn group_key(&self) -> GroupKey {
+        self.custom_group.map(GroupKey::Custom).unwrap_or(GroupKey::Builtin(self.group))
+    }
+
+    /// Annotate the stress of the final syllable and regenerate the regular forms
+    ///
+    /// `Stress::Final` doubles the final consonant (rebut -> rebutted).
+    pub fn with_stress(mut self, stress: Stress) -> Self {
+        let mut inflector = inflection::Inflector::new();
+        inflector.set_stress(&self.base, stress);
+        let forms = inflector.inflect(&self.base);
+        if !self.irregular {
+            self.past = forms.past.clone();
+            self.past_participle = forms.past;
+            self.variants = inflector.british_variants(&self.base);
         }
+        self.present_participle = forms.present_participle;
+        self.third_person = forms.third_person;
+        self
     }
 
-    /// Get regular third person singular
-    fn regular_third(base: &str) -> String {
-        if base.ends_with('s') || base.ends_with('x') || base.ends_with('z')
-            || base.ends_with("ch") || base.ends_with("sh") {
-            format!("{}es", base)
-        } else if base.ends_with('y') && base.len() > 1 {
-            let chars: Vec<char> = base.chars().collect();
-            let second_last = chars.get(chars.len() - 2);
-            if second_last.map(|c| !"aeiou".contains(*c)).unwrap_or(false) {
-                format!("{}ies", &base[..base.len()-1])
+    /// Every surface form with its role, variants included
+    ///
+    /// A form filling two roles ("walked") appears once per role.
+    pub fn forms(&self) -> Vec<(&str, FormRole)> {
+        let mut forms = vec![
+            (self.base.as_str(), FormRole::Base),
+            (self.past.as_str(), FormRole::Past),
+            (self.past_participle.as_str(), FormRole::PastParticiple),
+            (self.present_participle.as_str(), FormRole::PresentParticiple),
+            (self.third_person.as_str(), FormRole::ThirdPerson),
+        ];
+        for variant in &self.variants {
+            if variant.ends_with("ing") {
+                forms.push((variant.as_str(), FormRole::PresentParticiple));
             } else {
-                format!("{}s", base)
+                forms.push((variant.as_str(), FormRole::Past));
+                forms.push((variant.as_str(), FormRole::PastParticiple));
             }
-        } else {
-            format!("{}s", base)
         }
+        forms
     }
 
-    /// Check if final consonant should be doubled
-    fn should_double_final(base: &str) -> bool {
-        let chars: Vec<char> = base.chars().collect();
-        if chars.len() < 2 {
-            return false;
+    /// Roles a word form fills for this verb (empty if it does not match)
+    ///
+    /// "walked" is both `Past` and `PastParticiple`; "walks" is `ThirdPerson`.
+    pub fn form_roles(&self, word: &str) -> Vec<FormRole> {
+        let w = word.to_lowercase();
+        let mut roles: Vec<FormRole> = Vec::new();
+        for (form, role) in self.forms() {
+            if form == w && !roles.contains(&role) {
+                roles.push(role);
+            }
         }
-        let last = chars[chars.len() - 1];
-        let second_last = chars[chars.len() - 2];
-
-        // Final consonant after single vowel in stressed syllable
-        !"aeiou".contains(last) && "aeiou".contains(second_last)
-            && chars.len() <= 3 // Simple heuristic for short words
-            && !['w', 'x', 'y'].contains(&last)
+        roles
     }
 
     /// Check if a word form matches this verb
@@ -795,24 +944,70 @@ impl VerbEntry {
         let w = word.to_lowercase();
         w == self.base || w == self.past || w == self.past_participle
             || w == self.present_participle || w == self.third_person
+            || self.variants.contains(&w)
     }
 }
 
 /// The complete verb database
+///
+/// Built-in verbs live in static tables generated at compile time (see
+/// `builtin_verbs.tsv`); the maps below hold only entries added at runtime
+/// and are consulted on top of the tables.
 #[derive(Debug)]
 pub struct VerbDatabase {
-    /// All verb entries by base form (supports multiple categories per verb)
+    /// Consult the built-in tables
+    builtin: bool,
+    /// Runtime entries by base form (supports multiple categories per verb);
+    /// a built-in base is copied here before it gets another sense
     verbs: HashMap<String, Vec<VerbEntry>>,
-    /// Index: all forms -> base form
-    form_index: HashMap<String, String>,
+    /// Index: runtime forms -> every (base form, role) they can be
+    form_index: HashMap<String, Vec<(String, FormRole)>>,
     /// Index: category -> verb bases
     category_index: HashMap<FunctionalCategory, Vec<String>>,
     /// Index: group -> verb bases
     group_index: HashMap<VerbGroup, Vec<String>>,
+    /// Phrasal verb entries ("turn off", "carry out")
+    phrasal: Vec<PhrasalVerb>,
+    /// Index: head verb form -> phrasal entry indices
+    phrasal_index: HashMap<String, Vec<usize>>,
+    /// Symmetric synonym links between base forms
+    synonym_graph: HashMap<String, HashSet<String>>,
+    /// Symmetric antonym links between base forms
+    antonym_graph: HashMap<String, HashSet<String>>,
+    /// Index: form of a synonym that has no entry -> (synonym base, head base)
+    alias_index: HashMap<String, (String, String)>,
+    /// Custom categories and groups
+    registry: CategoryRegistry,
+    /// Index: custom category -> verb bases
+    custom_category_index: HashMap<CategoryId, Vec<String>>,
+    /// Index: custom group -> verb bases
+    custom_group_index: HashMap<GroupId, Vec<String>>,
+    /// Classes and parent links between categories and groups
+    taxonomy: Taxonomy,
+    /// Selectional preferences set at runtime (replace the built-in ones)
+    preferences: HashMap<GroupKey, Vec<SelectionalPreference>>,
     /// Statistics
     pub stats: VerbStats,
 }
 
+/// A verb whose generated forms differ from the original inflection heuristic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InflectionChange {
+    /// Base form
+    pub base: String,
+    /// (old form, new form) pairs
+    pub changed: Vec<(String, String)>,
+}
+
+/// A surface form shared by more than one verb
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormCollision {
+    /// The shared form ("found")
+    pub form: String,
+    /// Every (base, role) reading ("find"/Past, "find"/PastParticiple, "found"/Base)
+    pub readings: Vec<(String, FormRole)>,
+}
+
 /// Database statistics
 #[derive(Debug, Clone, Default)]
 pub struct VerbStats {
@@ -822,16 +1017,30 @@ pub struct VerbStats {
     pub total_forms: usize,
     pub categories_used: usize,
     pub groups_used: usize,
+    pub phrasal_verbs: usize,
+    /// Forms shared by more than one base (see `VerbDatabase::form_collisions`)
+    pub colliding_forms: usize,
 }
 
 impl VerbDatabase {
     /// Create a new empty database
     pub fn new() -> Self {
         Self {
+            builtin: false,
             verbs: HashMap::new(),
             form_index: HashMap::new(),
             category_index: HashMap::new(),
             group_index: HashMap::new(),
+            phrasal: Vec::new(),
+            phrasal_index: HashMap::new(),
+            synonym_graph: HashMap::new(),
+            antonym_graph: HashMap::new(),
+            alias_index: HashMap::new(),
+            registry: CategoryRegistry::new(),
+            custom_category_index: HashMap::new(),
+            custom_group_index: HashMap::new(),
+            taxonomy: Taxonomy::new(),
+            preferences: HashMap::new(),
             stats: VerbStats::default(),
         }
     }
@@ -839,21 +1048,53 @@ impl VerbDatabase {
     /// Create database with all built-in verbs
     pub fn with_builtin() -> Self {
         let mut db = Self::new();
-        db.load_builtin_verbs();
+        db.taxonomy = Taxonomy::builtin();
+        // Indexes and stats (including the form collision scan) are built once
+        db.enable_builtin();
+        db.load_phrasal_verbs();
         db.rebuild_indexes();
         db
     }
 
+    /// Enable the built-in verbs
+    ///
+    /// Nothing is copied: lookups fall through to the static tables.
+    /// Entries added earlier for a built-in base follow its built-in senses.
+    pub fn load_builtin_verbs(&mut self) {
+        self.enable_builtin();
+        self.rebuild_indexes();
+    }
+
+    /// `load_builtin_verbs` without rebuilding the indexes
+    fn enable_builtin(&mut self) {
+        self.builtin = true;
+        for (base, entries) in self.verbs.iter_mut() {
+            if let Some(b) = builtin::base_index(base) {
+                let mut merged = builtin::entries(b).clone();
+                merged.append(entries);
+                *entries = merged;
+            }
+        }
+    }
+
     /// Add a verb entry
-    pub fn add(&mut self, entry: VerbEntry) {
+    pub fn add(&mut self, mut entry: VerbEntry) {
         let base = entry.base.clone();
 
-        // Add to form index
-        self.form_index.insert(entry.base.clone(), base.clone());
-        self.form_index.insert(entry.past.clone(), base.clone());
-        self.form_index.insert(entry.past_participle.clone(), base.clone());
-        self.form_index.insert(entry.present_participle.clone(), base.clone());
-        self.form_index.insert(entry.third_person.clone(), base.clone());
+        // Extending a built-in base: copy its senses first
+        if !self.verbs.contains_key(&base) {
+            if let Some(b) = self.builtin_base(&base) {
+                self.verbs.insert(base.clone(), builtin::entries(b).clone());
+            }
+        }
+
+        // Add to form index (a form can belong to several verbs)
+        for (form, role) in entry.forms() {
+            let readings = self.form_index.entry(form.to_string()).or_default();
+            if !readings.iter().any(|(b, r)| *b == base && *r == role) {
+                readings.push((base.clone(), role));
+            }
+        }
 
         // Add to category index
         self.category_index
@@ -867,23 +1108,169 @@ impl VerbDatabase {
             .or_default()
             .push(base.clone());
 
+        // Add to custom indexes (a custom group implies its custom category)
+        if let Some(group) = entry.custom_group {
+            if entry.custom_category.is_none() {
+                if let Some(CategoryKey::Custom(category)) = self.registry.category_of(GroupKey::Custom(group)) {
+                    entry.custom_category = Some(category);
+                }
+            }
+            self.custom_group_index.entry(group).or_default().push(base.clone());
+        }
+        if let Some(category) = entry.custom_category {
+            self.custom_category_index.entry(category).or_default().push(base.clone());
+        }
+
+        // Link synonyms/antonyms both ways
+        self.index_relations(&base, &entry.synonyms, &entry.antonyms);
+
         // Store entry (supports multiple categories per verb)
         self.verbs.entry(base).or_default().push(entry);
     }
 
     /// Look up a verb by any form (returns first/primary entry)
+    ///
+    /// A form shared by several verbs resolves to the most frequent one (see
+    /// `primary_base`), not to the verb added last as in earlier versions.
+    /// Synonyms without an entry of their own resolve to their head verb.
     pub fn lookup(&self, word: &str) -> Option<&VerbEntry> {
-        let w = word.to_lowercase();
-        self.form_index.get(&w)
-            .and_then(|base| self.verbs.get(base))
-            .and_then(|entries| entries.first())
+        self.lookup_all(word).and_then(|entries| entries.first())
     }
 
     /// Look up ALL entries for a verb (for verbs with multiple categories)
+    ///
+    /// A form shared by several verbs resolves to the primary one (see
+    /// `primary_base`); use `lookup_readings` to see all of them.
     pub fn lookup_all(&self, word: &str) -> Option<&Vec<VerbEntry>> {
-        let w = word.to_lowercase();
-        self.form_index.get(&w)
-            .and_then(|base| self.verbs.get(base))
+        let w = lowercase(word);
+        self.primary_base(&w)
+            .or_else(|| self.alias_of(&w).map(|(_, head)| head))
+            .and_then(|base| self.entries_of(base))
+    }
+
+    /// Every (base, role) reading of a form, ambiguity included
+    ///
+    /// "found" gives ("find", Past), ("find", PastParticiple) and ("found", Base).
+    /// Aliases of a head verb are not listed.
+    pub fn lookup_readings(&self, word: &str) -> Vec<(&str, FormRole)> {
+        self.readings_of(&lowercase(word))
+    }
+
+    /// Readings of a lowercase form: built-in first, then runtime
+    fn readings_of(&self, form: &str) -> Vec<(&str, FormRole)> {
+        let mut readings: Vec<(&str, FormRole)> = Vec::new();
+        if self.builtin {
+            readings.extend(builtin::readings(form).iter().map(|&(b, r)| (builtin::BASES[b as usize].name, r)));
+        }
+        for (base, role) in self.form_index.get(form).into_iter().flatten() {
+            if !readings.contains(&(base.as_str(), *role)) {
+                readings.push((base.as_str(), *role));
+            }
+        }
+        readings
+    }
+
+    /// Entries of a base form, runtime before built-in
+    pub(crate) fn entries_of(&self, base: &str) -> Option<&Vec<VerbEntry>> {
+        self.verbs.get(base).or_else(|| self.builtin_base(base).map(builtin::entries))
+    }
+
+    /// Index of a built-in base, if the built-in tables are enabled
+    fn builtin_base(&self, base: &str) -> Option<usize> {
+        if self.builtin {
+            builtin::base_index(base)
+        } else {
+            None
+        }
+    }
+
+    /// Check if a lowercase form belongs to any entry
+    pub(crate) fn has_form(&self, form: &str) -> bool {
+        self.form_index.contains_key(form) || (self.builtin && !builtin::readings(form).is_empty())
+    }
+
+    /// (synonym, head base) of a lowercase alias form
+    pub(crate) fn alias_of(&self, form: &str) -> Option<(&str, &str)> {
+        self.alias_index.get(form)
+            .map(|(syn, head)| (syn.as_str(), head.as_str()))
+            .or_else(|| if self.builtin { builtin::alias(form) } else { None })
+    }
+
+    /// Highest frequency among the senses of a base
+    fn base_frequency(&self, base: &str) -> u8 {
+        match self.verbs.get(base) {
+            Some(entries) => entries.iter().map(|e| e.frequency).max().unwrap_or(0),
+            None => self.builtin_base(base).map(|b| builtin::BASES[b].frequency).unwrap_or(0),
+        }
+    }
+
+    /// Primary entry of a form together with the roles the form fills
+    ///
+    /// Aliases resolve to their head verb and report the alias's own roles
+    /// ("moseyed" -> walk entry, `Past`).
+    pub fn lookup_with_roles(&self, word: &str) -> Option<(&VerbEntry, Vec<FormRole>)> {
+        let entry = self.lookup(word)?;
+        let mut roles = self.form_roles(word, &entry.base);
+        if roles.is_empty() {
+            if let Some((alias, _)) = self.alias_of(&lowercase(word)) {
+                let w = word.to_lowercase();
+                let forms = inflection::inflect(alias);
+                roles = [
+                    (alias, FormRole::Base),
+                    (forms.past.as_str(), FormRole::Past),
+                    (forms.past.as_str(), FormRole::PastParticiple),
+                    (forms.present_participle.as_str(), FormRole::PresentParticiple),
+                    (forms.third_person.as_str(), FormRole::ThirdPerson),
+                ].into_iter().filter(|(form, _)| *form == w).map(|(_, role)| role).collect();
+            }
+        }
+        Some((entry, roles))
+    }
+
+    /// Roles a form fills for the verb with the given base
+    pub fn form_roles(&self, word: &str, base: &str) -> Vec<FormRole> {
+        self.lookup_readings(word)
+            .into_iter()
+            .filter(|(b, _)| *b == base)
+            .map(|(_, role)| role)
+            .collect()
+    }
+
+    /// Base a shared form resolves to by default
+    ///
+    /// The most frequent verb wins; on a tie a base-form reading wins, then
+    /// the verb added first.
+    fn primary_base(&self, form: &str) -> Option<&str> {
+        let mut best: Option<(&str, (u8, bool))> = None;
+        for (base, role) in self.readings_of(form) {
+            let rank = (self.base_frequency(base), role == FormRole::Base);
+            if best.is_none_or(|(_, r)| rank > r) {
+                best = Some((base, rank));
+            }
+        }
+        best.map(|(base, _)| base)
+    }
+
+    /// Forms shared by more than one base, sorted by form
+    ///
+    /// Audit report for the built-in data: each entry is a form whose
+    /// default reading hides another verb ("lay", "found", "bore").
+    pub fn form_collisions(&self) -> Vec<FormCollision> {
+        let mut collisions: Vec<FormCollision> = self.all_forms()
+            .filter_map(|form| {
+                let mut readings = self.readings_of(form);
+                if readings.iter().all(|(b, _)| *b == readings[0].0) {
+                    return None;
+                }
+                readings.sort_by(|a, b| a.0.cmp(b.0));
+                Some(FormCollision {
+                    form: form.to_string(),
+                    readings: readings.into_iter().map(|(b, r)| (b.to_string(), r)).collect(),
+                })
+            })
+            .collect();
+        collisions.sort_by(|a, b| a.form.cmp(&b.form));
+        collisions
     }
 
     /// Get all categories for a verb
@@ -893,36 +1280,108 @@ impl VerbDatabase {
             .unwrap_or_default()
     }
 
-    /// Get all verbs in a category
-    pub fn by_category(&self, category: FunctionalCategory) -> Vec<&VerbEntry> {
-        self.category_index.get(&category)
-            .map(|bases| bases.iter()
-                .filter_map(|b| self.verbs.get(b))
-                .flat_map(|entries| entries.iter())
-                .filter(|e| e.category == category)
-                .collect())
-            .unwrap_or_default()
+    /// Get all verbs in a built-in or custom category
+    pub fn by_category(&self, category: impl Into<CategoryKey>) -> Vec<&VerbEntry> {
+        match category.into() {
+            CategoryKey::Builtin(c) => {
+                let bases = self.builtin_bases(builtin::category_bases(c)).chain(self.runtime_bases(self.category_index.get(&c)));
+                self.collect_entries(bases, |e| e.category == c)
+            }
+            CategoryKey::Custom(id) => {
+                self.collect_entries(self.runtime_bases(self.custom_category_index.get(&id)), |e| e.custom_category == Some(id))
+            }
+        }
     }
 
-    /// Get all verbs in a group
-    pub fn by_group(&self, group: VerbGroup) -> Vec<&VerbEntry> {
-        self.group_index.get(&group)
-            .map(|bases| bases.iter()
-                .filter_map(|b| self.verbs.get(b))
-                .flat_map(|entries| entries.iter())
-                .filter(|e| e.group == group)
-                .collect())
-            .unwrap_or_default()
+    /// Get all verbs in a built-in or custom group
+    pub fn by_group(&self, group: impl Into<GroupKey>) -> Vec<&VerbEntry> {
+        match group.into() {
+            GroupKey::Builtin(g) => {
+                let bases = self.builtin_bases(builtin::group_bases(g)).chain(self.runtime_bases(self.group_index.get(&g)));
+                self.collect_entries(bases, |e| e.group == g)
+            }
+            GroupKey::Custom(id) => {
+                self.collect_entries(self.runtime_bases(self.custom_group_index.get(&id)), |e| e.custom_group == Some(id))
+            }
+        }
+    }
+
+    /// Entries of the listed bases accepted by `keep`
+    fn collect_entries<'a, F: Fn(&VerbEntry) -> bool>(
+        &'a self,
+        bases: impl Iterator<Item = &'a str>,
+        keep: F,
+    ) -> Vec<&'a VerbEntry> {
+        let mut seen = HashSet::new();
+        bases
+            .filter(|b| seen.insert(*b))
+            .filter_map(|b| self.entries_of(b))
+            .flat_map(|entries| entries.iter())
+            .filter(|e| keep(e))
+            .collect()
+    }
+
+    /// Names of built-in bases from a generated index
+    fn builtin_bases<'a>(&self, indices: &'static [u16]) -> impl Iterator<Item = &'a str> {
+        let indices = if self.builtin { indices } else { &[] };
+        indices.iter().map(|&b| builtin::BASES[b as usize].name)
+    }
+
+    /// Bases listed in a runtime index
+    fn runtime_bases<'a>(&self, bases: Option<&'a Vec<String>>) -> impl Iterator<Item = &'a str> {
+        bases.into_iter().flatten().map(|b| b.as_str())
+    }
+
+    /// Every indexed form, built-in first
+    fn all_forms(&self) -> impl Iterator<Item = &str> {
+        let builtin: &[(&str, &[(u16, FormRole)])] = if self.builtin { &builtin::FORMS } else { &[] };
+        builtin.iter()
+            .map(|(form, _)| *form)
+            .chain(self.form_index.keys()
+                .map(|f| f.as_str())
+                .filter(move |f| !self.builtin || builtin::readings(f).is_empty()))
+    }
+
+    /// Register a custom category (see `CategoryRegistry::register_category`);
+    /// taxonomy class names are taken too
+    pub fn register_category(&mut self, name: &str, description: &str) -> Result<CategoryId, RegistryError> {
+        self.taxonomy.check_name(name)?;
+        self.registry.register_category(name, description)
+    }
+
+    /// Register a custom group (see `CategoryRegistry::register_group`);
+    /// taxonomy class names are taken too
+    pub fn register_group(&mut self, name: &str, category: impl Into<CategoryKey>) -> Result<GroupId, RegistryError> {
+        self.taxonomy.check_name(name)?;
+        self.registry.register_group(name, category)
+    }
+
+    /// Find a built-in or custom category by name
+    pub fn resolve_category(&self, name: &str) -> Option<CategoryKey> {
+        self.registry.resolve_category(name)
+    }
+
+    /// Find a built-in or custom group by name
+    pub fn resolve_group(&self, name: &str) -> Option<GroupKey> {
+        self.registry.resolve_group(name)
+    }
+
+    /// Custom categories and groups
+    pub fn registry(&self) -> &CategoryRegistry {
+        &self.registry
     }
 
     /// Get the base form of any verb form
     pub fn base_form(&self, word: &str) -> Option<&str> {
-        self.form_index.get(&word.to_lowercase()).map(|s| s.as_str())
+        let w = lowercase(word);
+        self.primary_base(&w)
+            .or_else(|| self.alias_of(&w).map(|(base, _)| base))
     }
 
     /// Check if a word is a known verb form
     pub fn is_verb(&self, word: &str) -> bool {
-        self.form_index.contains_key(&word.to_lowercase())
+        let w = lowercase(word);
+        self.has_form(&w) || self.alias_of(&w).is_some()
     }
 
     /// Get category of a verb
@@ -941,14 +1400,16 @@ impl VerbDatabase {
         let mut regular = 0;
         let mut total_entries = 0;
 
-        for entries in self.verbs.values() {
-            for entry in entries {
-                total_entries += 1;
-                if entry.irregular {
-                    irregular += 1;
-                } else {
-                    regular += 1;
-                }
+        let builtin = self.builtin_indices()
+            .flat_map(|b| builtin::BASES[b].entries.iter())
+            .map(|&i| builtin::ENTRIES[i as usize].irregular);
+        let runtime = self.verbs.values().flatten().map(|e| e.irregular);
+        for is_irregular in builtin.chain(runtime) {
+            total_entries += 1;
+            if is_irregular {
+                irregular += 1;
+            } else {
+                regular += 1;
             }
         }
 
@@ -956,30 +1417,84 @@ impl VerbDatabase {
             total_verbs: total_entries,
             irregular_verbs: irregular,
             regular_verbs: regular,
-            total_forms: self.form_index.len(),
-            categories_used: self.category_index.len(),
-            groups_used: self.group_index.len(),
+            total_forms: self.all_forms().count(),
+            categories_used: FunctionalCategory::all().iter()
+                .filter(|&&c| self.builtin_bases(builtin::category_bases(c)).next().is_some()
+                    || self.category_index.contains_key(&c))
+                .count(),
+            groups_used: VerbGroup::all().iter()
+                .filter(|&&g| self.builtin_bases(builtin::group_bases(g)).next().is_some()
+                    || self.group_index.contains_key(&g))
+                .count(),
+            phrasal_verbs: self.phrasal.len(),
+            colliding_forms: self.form_collisions().len(),
         };
     }
 
+    /// List built-in regular forms that differ from the original length-based
+    /// doubling heuristic (e.g. "occur": "occured" -> "occurred")
+    pub fn inflection_changes(&self) -> Vec<InflectionChange> {
+        let mut changes: Vec<InflectionChange> = self.all_verbs()
+            .filter_map(|entry| {
+                let old = inflection::legacy_forms(&entry.base);
+                let mut changed = Vec::new();
+                if !entry.irregular && old.past != entry.past {
+                    changed.push((old.past, entry.past.clone()));
+                }
+                if old.present_participle != entry.present_participle {
+                    changed.push((old.present_participle, entry.present_participle.clone()));
+                }
+                if old.third_person != entry.third_person {
+                    changed.push((old.third_person, entry.third_person.clone()));
+                }
+                if changed.is_empty() {
+                    None
+                } else {
+                    Some(InflectionChange { base: entry.base.clone(), changed })
+                }
+            })
+            .collect();
+        changes.sort_by(|a, b| a.base.cmp(&b.base));
+        changes.dedup_by(|a, b| a.base == b.base);
+        changes
+    }
+
     /// Get all verbs as iterator (flattens all entries)
     pub fn all_verbs(&self) -> impl Iterator<Item = &VerbEntry> {
-        self.verbs.values().flat_map(|entries| entries.iter())
+        self.builtin_indices()
+            .flat_map(|b| builtin::entries(b).iter())
+            .chain(self.verbs.values().flatten())
+    }
+
+    /// Built-in bases not overridden by runtime entries
+    fn builtin_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        let count = if self.builtin { builtin::BASE_COUNT } else { 0 };
+        (0..count).filter(|&b| !self.verbs.contains_key(builtin::BASES[b].name))
     }
 
     /// Get total count of verb entries (including multi-category)
     pub fn len(&self) -> usize {
-        self.verbs.values().map(|v| v.len()).sum()
+        self.builtin_indices().map(|b| builtin::BASES[b].entries.len()).sum::<usize>()
+            + self.verbs.values().map(|v| v.len()).sum::<usize>()
     }
 
     /// Get count of unique verb base forms
     pub fn unique_verbs(&self) -> usize {
-        self.verbs.len()
+        self.builtin_indices().count() + self.verbs.len()
     }
 
     /// Check if empty
     pub fn is_empty(&self) -> bool {
-        self.verbs.is_empty()
+        !self.builtin && self.verbs.is_empty()
+    }
+}
+
+/// Lowercase a word, borrowing it when it already is
+fn lowercase(word: &str) -> Cow<'_, str> {
+    if word.chars().any(char::is_uppercase) {
+        Cow::Owned(word.to_lowercase())
+    } else {
+        Cow::Borrowed(word)
     }
 }
 
@@ -1009,6 +1524,37 @@ mod tests {
         assert!(v.irregular);
     }
 
+    #[test]
+    fn test_stress_final_inflection() {
+        let v = VerbEntry::regular("occur", FunctionalCategory::State, VerbGroup::Generic, None, 50);
+        assert_eq!(v.past, "occurred");
+        assert_eq!(v.present_participle, "occurring");
+
+        let v = VerbEntry::regular("travel", FunctionalCategory::Movement, VerbGroup::Walk, None, 50);
+        assert_eq!(v.past, "traveled");
+        assert!(v.matches("travelled"));
+
+        let v = VerbEntry::regular("rebut", FunctionalCategory::Communication, VerbGroup::Argue, None, 20)
+            .with_stress(Stress::Final);
+        assert_eq!(v.past, "rebutted");
+
+        // British variants follow the new stress
+        let v = VerbEntry::regular("rebel", FunctionalCategory::Social, VerbGroup::Argue, None, 20)
+            .with_stress(Stress::NonFinal);
+        assert_eq!(v.past, "rebeled");
+        assert!(v.matches("rebelled"));
+    }
+
+    #[test]
+    fn test_inflection_changes_report() {
+        let db = VerbDatabase::with_builtin();
+        assert!(db.is_verb("travelled"));
+        let changes = db.inflection_changes();
+        let control = changes.iter().find(|c| c.base == "control").unwrap();
+        assert!(control.changed.contains(&("controled".to_string(), "controlled".to_string())));
+        assert!(!changes.iter().any(|c| c.base == "walk"));
+    }
+
     #[test]
     fn test_database_lookup() {
         let db = VerbDatabase::with_builtin();
@@ -1016,6 +1562,70 @@ mod tests {
         assert!(db.is_verb("running"));
         assert!(db.is_verb("ran"));
         assert_eq!(db.base_form("running"), Some("run"));
+        // Irregular present forms come from the verb table
+        assert_eq!(db.base_form("is"), Some("be"));
+        assert_eq!(db.base_form("has"), Some("have"));
+    }
+
+    #[test]
+    fn test_shared_forms_keep_every_reading() {
+        let db = VerbDatabase::with_builtin();
+        let found = db.lookup_readings("Found");
+        assert!(found.contains(&("find", FormRole::Past)));
+        assert!(found.contains(&("find", FormRole::PastParticiple)));
+        assert!(found.contains(&("found", FormRole::Base)));
+        assert!(db.lookup_readings("lay").contains(&("lie", FormRole::Past)));
+        assert_eq!(db.lookup_readings("walked"), vec![("walk", FormRole::Past), ("walk", FormRole::PastParticiple)]);
+
+        let collisions = db.form_collisions();
+        assert!(collisions.iter().any(|c| c.form == "lay"));
+        assert!(!collisions.iter().any(|c| c.form == "walked"));
+        assert_eq!(db.stats.colliding_forms, collisions.len());
+    }
+
+    #[test]
+    fn test_form_roles() {
+        let db = VerbDatabase::with_builtin();
+        let (entry, roles) = db.lookup_with_roles("walked").unwrap();
+        assert_eq!(entry.base, "walk");
+        assert_eq!(roles, vec![FormRole::Past, FormRole::PastParticiple]);
+        assert_eq!(db.lookup_with_roles("runs").unwrap().1, vec![FormRole::ThirdPerson]);
+        assert_eq!(db.lookup_with_roles("ran").unwrap().1, vec![FormRole::Past]);
+        assert_eq!(db.lookup_with_roles("travelling").unwrap().1, vec![FormRole::PresentParticiple]);
+        assert_eq!(db.form_roles("put", "put"), vec![FormRole::Base, FormRole::Past, FormRole::PastParticiple]);
+        assert!(db.lookup_with_roles("table").is_none());
+    }
+
+    #[test]
+    fn test_added_verb_does_not_hide_shared_form() {
+        let mut db = VerbDatabase::new();
+        db.add(VerbEntry::irregular("find", "found", "found", FunctionalCategory::Cognition, VerbGroup::Learn, Some(true), 90));
+        db.add(VerbEntry::regular("found", FunctionalCategory::Creation, VerbGroup::Create, Some(true), 30));
+        assert_eq!(db.base_form("found"), Some("find"));
+        assert_eq!(db.base_form("founded"), Some("found"));
+        assert_eq!(db.lookup_readings("found").len(), 3);
+    }
+
+    #[test]
+    fn test_runtime_entries_extend_builtin() {
+        let mut db = VerbDatabase::with_builtin();
+        let (len, unique) = (db.len(), db.unique_verbs());
+        let walk_senses = db.lookup_all("walk").unwrap().len();
+
+        // A new sense of a built-in verb keeps the built-in ones
+        db.add(VerbEntry::regular("walk", FunctionalCategory::Transfer, VerbGroup::Give, Some(true), 10));
+        assert_eq!(db.lookup_all("walk").unwrap().len(), walk_senses + 1);
+        assert_eq!(db.lookup("walked").unwrap().group, VerbGroup::Walk);
+        assert!(db.by_group(VerbGroup::Give).iter().any(|e| e.base == "walk"));
+        assert!(db.synonyms_of("walk", 1).contains(&"stroll".to_string()));
+        assert_eq!((db.len(), db.unique_verbs()), (len + 1, unique));
+
+        // A new verb links into the built-in synonym graph
+        db.add(VerbEntry::regular("yomp", FunctionalCategory::Movement, VerbGroup::Walk, None, 5)
+            .with_synonyms(&["walk"]));
+        assert_eq!(db.base_form("yomping"), Some("yomp"));
+        assert!(db.synonyms_of("walk", 1).contains(&"yomp".to_string()));
+        assert_eq!(db.unique_verbs(), unique + 1);
     }
 
     #[test]
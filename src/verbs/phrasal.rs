@@ -0,0 +1,434 @@
+//! Phrasal and multi-word verbs ("turn off", "carry out", "put up with")
+//!
+//! Each phrasal verb has its own category and group, independent of its head
+//! verb: "turn off" is Aspectual/Stop while "turn" alone is Movement/Turn.
+//!
+//! Matching is particle-aware:
+//! - **Adjacent**: "turn off the lights", "look after the kids"
+//! - **Separated** (separable verbs only): "turn the lights off"
+//! - **Literal**: a particle that starts a phrase naming a place is a
+//!   preposition ("ran into the house", "put on the table")
+
+use super::{VerbDatabase, FunctionalCategory, VerbGroup, Frame, frames, inflection};
+use super::frames::DETERMINERS;
+
+/// Maximum number of tokens between a separable verb and its particle
+const MAX_SEPARATION: usize = 4;
+
+/// Words that end the object NP of a separated phrasal verb
+const SEPARATION_STOPS: &[&str] = &[
+    "and", "or", "but", "then", "to", "for", "with", "from", "at", "into",
+    "onto", "by", "of", "if", "when", "because", "while", "not",
+];
+
+/// Particles that double as spatial prepositions
+const SPATIAL_PARTICLES: &[&str] = &[
+    "in", "into", "on", "onto", "up", "down", "over", "through", "out", "off", "around",
+];
+
+/// Places and surfaces that make a following phrase a location rather than an object
+const LOCATIVE_NOUNS: &[&str] = &[
+    "bag", "bed", "box", "building", "bus", "car", "chair", "corner", "counter", "desk",
+    "door", "drawer", "field", "floor", "garden", "ground", "hall", "hill", "home", "house",
+    "kitchen", "lake", "office", "park", "pool", "river", "road", "roof", "room", "sea",
+    "shelf", "shop", "sofa", "stairs", "store", "street", "table", "town", "train", "tree",
+    "wall", "water", "window", "woods", "yard",
+];
+
+/// A multi-word verb entry
+#[derive(Debug, Clone)]
+pub struct PhrasalVerb {
+    /// Head verb base form ("turn")
+    pub verb: String,
+    /// Particle(s) ("off", "up with")
+    pub particle: String,
+    /// Functional category of the whole phrasal verb
+    pub category: FunctionalCategory,
+    /// Specific verb group of the whole phrasal verb
+    pub group: VerbGroup,
+    /// Transitivity: true = transitive, false = intransitive, None = both
+    pub transitive: Option<bool>,
+    /// Can the object go between verb and particle? ("turn the lights off")
+    pub separable: bool,
+    /// Frequency (higher = more common, 1-100)
+    pub frequency: u8,
+    /// All inflected forms of the head verb
+    pub head_forms: Vec<String>,
+    /// Subcategorisation frames (after the particle)
+    pub frames: Vec<Frame>,
+}
+
+impl PhrasalVerb {
+    /// Create an inseparable phrasal verb with a regular head
+    pub fn new(
+        verb: &str,
+        particle: &str,
+        category: FunctionalCategory,
+        group: VerbGroup,
+        transitive: Option<bool>,
+        frequency: u8,
+    ) -> Self {
+        let forms = inflection::inflect(verb);
+        Self {
+            verb: verb.to_string(),
+            particle: particle.to_string(),
+            category,
+            group,
+            transitive,
+            separable: false,
+            frequency,
+            head_forms: vec![verb.to_string(), forms.past, forms.present_participle, forms.third_person],
+            frames: frames::default_frames(group, transitive),
+        }
+    }
+
+    /// Mark as separable ("turn the lights off")
+    pub fn separable(mut self) -> Self {
+        self.separable = true;
+        self
+    }
+
+    /// Set irregular head forms not derivable by rule ("bring" -> "brought")
+    pub fn with_head_forms(mut self, past: &str, past_participle: &str) -> Self {
+        self.head_forms[1] = past.to_string();
+        if past_participle != past {
+            self.head_forms.push(past_participle.to_string());
+        }
+        self
+    }
+
+    /// Full base form ("turn off")
+    pub fn base(&self) -> String {
+        format!("{} {}", self.verb, self.particle)
+    }
+
+    /// Particle words
+    fn particle_words(&self) -> Vec<&str> {
+        self.particle.split_whitespace().collect()
+    }
+}
+
+/// A phrasal verb found in a token sequence
+#[derive(Debug, Clone)]
+pub struct PhrasalMatch<'a> {
+    /// The matched entry
+    pub entry: &'a PhrasalVerb,
+    /// Token index of the head verb
+    pub verb_position: usize,
+    /// Token indices of the particle words
+    pub particle_positions: Vec<usize>,
+    /// True if the object sits between verb and particle
+    pub separated: bool,
+}
+
+impl PhrasalMatch<'_> {
+    /// Surface text of the verb + particle ("turn off", "turned off")
+    pub fn surface(&self, tokens: &[String]) -> String {
+        let mut words = vec![tokens[self.verb_position].clone()];
+        words.extend(self.particle_positions.iter().map(|&p| tokens[p].clone()));
+        words.join(" ")
+    }
+
+    /// Check if a token index belongs to the particle
+    pub fn is_particle(&self, position: usize) -> bool {
+        self.particle_positions.contains(&position)
+    }
+}
+
+impl VerbDatabase {
+    /// Add a phrasal verb entry
+    ///
+    /// Forms of the head verb already in the database (including irregular
+    /// ones) are merged into the entry.
+    pub fn add_phrasal(&mut self, mut entry: PhrasalVerb) {
+        if let Some(entries) = self.entries_of(&entry.verb) {
+            for e in entries {
+                for form in [&e.base, &e.past, &e.past_participle, &e.present_participle, &e.third_person] {
+                    if !entry.head_forms.contains(form) {
+                        entry.head_forms.push(form.clone());
+                    }
+                }
+            }
+        }
+
+        let idx = self.phrasal.len();
+        for form in &entry.head_forms {
+            let list = self.phrasal_index.entry(form.clone()).or_default();
+            if !list.contains(&idx) {
+                list.push(idx);
+            }
+        }
+        self.phrasal.push(entry);
+    }
+
+    /// Look up a phrasal verb by its base ("turn off")
+    pub fn lookup_phrasal(&self, base: &str) -> Option<&PhrasalVerb> {
+        let b = base.to_lowercase();
+        self.phrasal.iter().find(|p| p.base() == b)
+    }
+
+    /// All phrasal verbs
+    pub fn phrasal_verbs(&self) -> &[PhrasalVerb] {
+        &self.phrasal
+    }
+
+    /// Match a phrasal verb whose head is at `index` in lowercase `tokens`
+    ///
+    /// Adjacent particles win over separated ones, and longer particles
+    /// ("put up with") over shorter ones ("put up").
+    pub fn match_phrasal<'a>(&'a self, tokens: &[String], index: usize) -> Option<PhrasalMatch<'a>> {
+        let head = tokens.get(index)?;
+        let candidates = self.phrasal_index.get(head)?;
+
+        let mut best: Option<PhrasalMatch<'a>> = None;
+        for &ci in candidates {
+            let entry = &self.phrasal[ci];
+            let words = entry.particle_words();
+
+            // Adjacent: verb particle...
+            let adjacent = words.iter().enumerate()
+                .all(|(k, w)| tokens.get(index + 1 + k).map(|t| t == w).unwrap_or(false));
+            if adjacent && entry.transitive != Some(false) && Self::is_literal(tokens, index + 1 + words.len(), words[words.len() - 1]) {
+                continue;
+            }
+            if adjacent {
+                let m = PhrasalMatch {
+                    entry,
+                    verb_position: index,
+                    particle_positions: (index + 1..index + 1 + words.len()).collect(),
+                    separated: false,
+                };
+                if best.as_ref().map(|b| b.separated || b.particle_positions.len() < words.len()).unwrap_or(true) {
+                    best = Some(m);
+                }
+                continue;
+            }
+
+            // Separated: verb object... particle
+            if entry.separable && words.len() == 1 && best.is_none() {
+                if let Some(pos) = Self::find_separated_particle(tokens, index, words[0]) {
+                    best = Some(PhrasalMatch {
+                        entry,
+                        verb_position: index,
+                        particle_positions: vec![pos],
+                        separated: true,
+                    });
+                }
+            }
+        }
+        best
+    }
+
+    /// Whether a particle followed by the phrase at `start` is a preposition
+    /// heading a place ("into the house") rather than a verb particle
+    fn is_literal(tokens: &[String], start: usize, particle: &str) -> bool {
+        if !SPATIAL_PARTICLES.contains(&particle) {
+            return false;
+        }
+        tokens.iter().skip(start)
+            .skip_while(|t| DETERMINERS.contains(&t.as_str()))
+            .take(3)
+            .take_while(|t| !SEPARATION_STOPS.contains(&t.as_str()) && !starts_clause(t))
+            .any(|t| LOCATIVE_NOUNS.contains(&t.as_str()))
+    }
+
+    fn find_separated_particle(tokens: &[String], index: usize, particle: &str) -> Option<usize> {
+        let end = (index + 2 + MAX_SEPARATION).min(tokens.len());
+        for pos in index + 2..end {
+            let token = tokens[pos].as_str();
+            if token == particle {
+                // "put the book on the table": a particle followed by a
+                // determiner is a preposition
+                let next = tokens.get(pos + 1).map(|s| s.as_str());
+                if next.map(|n| DETERMINERS.contains(&n)).unwrap_or(false) {
+                    return None;
+                }
+                return Some(pos);
+            }
+            if SEPARATION_STOPS.contains(&token) || starts_clause(token) {
+                return None;
+            }
+        }
+        None
+    }
+
+    /// Load built-in phrasal verbs
+    pub(super) fn load_phrasal_verbs(&mut self) {
+        use FunctionalCategory::*;
+
+        // Devices and processes
+        self.add_phrasal(PhrasalVerb::new("turn", "on", Aspectual, VerbGroup::Begin, Some(true), 85).separable());
+        self.add_phrasal(PhrasalVerb::new("turn", "off", Aspectual, VerbGroup::Stop, Some(true), 85).separable());
+        self.add_phrasal(PhrasalVerb::new("switch", "on", Aspectual, VerbGroup::Begin, Some(true), 70).separable());
+        self.add_phrasal(PhrasalVerb::new("switch", "off", Aspectual, VerbGroup::Stop, Some(true), 70).separable());
+        self.add_phrasal(PhrasalVerb::new("shut", "down", Aspectual, VerbGroup::Stop, None, 70).separable());
+        self.add_phrasal(PhrasalVerb::new("shut", "off", Aspectual, VerbGroup::Stop, Some(true), 55).separable());
+        self.add_phrasal(PhrasalVerb::new("start", "up", Aspectual, VerbGroup::Begin, None, 60).separable());
+        self.add_phrasal(PhrasalVerb::new("boot", "up", Aspectual, VerbGroup::Begin, None, 45).separable());
+        self.add_phrasal(PhrasalVerb::new("log", "in", Connection, VerbGroup::Connect, Some(false), 55));
+        self.add_phrasal(PhrasalVerb::new("log", "out", Connection, VerbGroup::Separate, Some(false), 55));
+        self.add_phrasal(PhrasalVerb::new("back", "up", Possession, VerbGroup::Keep, Some(true), 55).separable());
+        self.add_phrasal(PhrasalVerb::new("set", "up", Creation, VerbGroup::Build, Some(true), 80).separable());
+
+        // Execution and completion
+        self.add_phrasal(PhrasalVerb::new("carry", "out", Control, VerbGroup::ControlGroup, Some(true), 75).separable());
+        self.add_phrasal(PhrasalVerb::new("carry", "on", Aspectual, VerbGroup::Continue, None, 65));
+        self.add_phrasal(PhrasalVerb::new("go", "on", Aspectual, VerbGroup::Continue, Some(false), 75));
+        self.add_phrasal(PhrasalVerb::new("keep", "on", Aspectual, VerbGroup::Continue, None, 55));
+        self.add_phrasal(PhrasalVerb::new("give", "up", Aspectual, VerbGroup::Stop, None, 80).separable());
+        self.add_phrasal(PhrasalVerb::new("call", "off", Aspectual, VerbGroup::Stop, Some(true), 55).separable());
+        self.add_phrasal(PhrasalVerb::new("put", "off", Aspectual, VerbGroup::Stop, Some(true), 55).separable());
+        self.add_phrasal(PhrasalVerb::new("put", "out", Aspectual, VerbGroup::Stop, Some(true), 50).separable());
+        self.add_phrasal(PhrasalVerb::new("hang", "up", Aspectual, VerbGroup::End, None, 55)
+            .with_head_forms("hung", "hung"));
+        self.add_phrasal(PhrasalVerb::new("break", "down", Attempt, VerbGroup::Fail, Some(false), 60));
+
+        // Cognition and communication
+        self.add_phrasal(PhrasalVerb::new("look", "up", Cognition, VerbGroup::Learn, Some(true), 65).separable());
+        self.add_phrasal(PhrasalVerb::new("find", "out", Cognition, VerbGroup::Learn, None, 80).separable());
+        self.add_phrasal(PhrasalVerb::new("figure", "out", Cognition, VerbGroup::Know, Some(true), 70).separable());
+        self.add_phrasal(PhrasalVerb::new("work", "out", Cognition, VerbGroup::Analyze, None, 70).separable());
+        self.add_phrasal(PhrasalVerb::new("sort", "out", Cognition, VerbGroup::Analyze, Some(true), 55).separable());
+        self.add_phrasal(PhrasalVerb::new("look", "into", Cognition, VerbGroup::Analyze, Some(true), 60));
+        self.add_phrasal(PhrasalVerb::new("go", "over", Cognition, VerbGroup::Analyze, Some(true), 55));
+        self.add_phrasal(PhrasalVerb::new("come", "across", Cognition, VerbGroup::Learn, Some(true), 50));
+        self.add_phrasal(PhrasalVerb::new("point", "out", Communication, VerbGroup::Explain, Some(true), 70).separable());
+        self.add_phrasal(PhrasalVerb::new("bring", "up", Communication, VerbGroup::Suggest, Some(true), 65)
+            .with_head_forms("brought", "brought").separable());
+        self.add_phrasal(PhrasalVerb::new("call", "back", Communication, VerbGroup::Answer, Some(true), 50).separable());
+        self.add_phrasal(PhrasalVerb::new("turn", "down", Communication, VerbGroup::Answer, Some(true), 55).separable());
+        self.add_phrasal(PhrasalVerb::new("fill", "out", Creation, VerbGroup::Write, Some(true), 55).separable());
+        self.add_phrasal(PhrasalVerb::new("fill", "in", Creation, VerbGroup::Write, Some(true), 50).separable());
+        self.add_phrasal(PhrasalVerb::new("write", "down", Creation, VerbGroup::Write, Some(true), 60).separable());
+        self.add_phrasal(PhrasalVerb::new("make", "up", Creation, VerbGroup::Create, Some(true), 65).separable());
+
+        // Destruction and removal
+        self.add_phrasal(PhrasalVerb::new("throw", "away", Destruction, VerbGroup::Erase, Some(true), 65).separable());
+        self.add_phrasal(PhrasalVerb::new("throw", "out", Destruction, VerbGroup::Erase, Some(true), 60).separable());
+        self.add_phrasal(PhrasalVerb::new("get", "rid of", Destruction, VerbGroup::Erase, Some(true), 65));
+        self.add_phrasal(PhrasalVerb::new("wipe", "out", Destruction, VerbGroup::Destroy, Some(true), 55).separable());
+        self.add_phrasal(PhrasalVerb::new("tear", "down", Destruction, VerbGroup::Destroy, Some(true), 50)
+            .with_head_forms("tore", "torn").separable());
+        self.add_phrasal(PhrasalVerb::new("knock", "down", Destruction, VerbGroup::Destroy, Some(true), 50).separable());
+        self.add_phrasal(PhrasalVerb::new("blow", "up", Destruction, VerbGroup::Destroy, None, 55).separable());
+        self.add_phrasal(PhrasalVerb::new("cut", "off", Connection, VerbGroup::Separate, Some(true), 55).separable());
+        self.add_phrasal(PhrasalVerb::new("break", "up", Connection, VerbGroup::Separate, None, 60));
+        self.add_phrasal(PhrasalVerb::new("take", "off", Position, VerbGroup::Remove, None, 70).separable());
+
+        // Transfer and position
+        self.add_phrasal(PhrasalVerb::new("give", "back", Transfer, VerbGroup::Return, Some(true), 60).separable());
+        self.add_phrasal(PhrasalVerb::new("give", "away", Transfer, VerbGroup::Give, Some(true), 55).separable());
+        self.add_phrasal(PhrasalVerb::new("hand", "over", Transfer, VerbGroup::Give, Some(true), 55).separable());
+        self.add_phrasal(PhrasalVerb::new("hand", "in", Transfer, VerbGroup::Give, Some(true), 50).separable());
+        self.add_phrasal(PhrasalVerb::new("send", "back", Transfer, VerbGroup::Return, Some(true), 50).separable());
+        self.add_phrasal(PhrasalVerb::new("pick", "up", Physical, VerbGroup::Lift, Some(true), 80).separable());
+        self.add_phrasal(PhrasalVerb::new("put", "down", Position, VerbGroup::Put, Some(true), 60).separable());
+        self.add_phrasal(PhrasalVerb::new("put", "away", Position, VerbGroup::Put, Some(true), 55).separable());
+        self.add_phrasal(PhrasalVerb::new("put", "on", Position, VerbGroup::Put, Some(true), 65).separable());
+        self.add_phrasal(PhrasalVerb::new("run", "out of", Possession, VerbGroup::Lose, Some(true), 60));
+
+        // Social and emotion
+        self.add_phrasal(PhrasalVerb::new("look", "after", Social, VerbGroup::Help, Some(true), 65));
+        self.add_phrasal(PhrasalVerb::new("take", "care of", Social, VerbGroup::Help, Some(true), 70));
+        self.add_phrasal(PhrasalVerb::new("run", "into", Social, VerbGroup::Meet, Some(true), 55));
+        self.add_phrasal(PhrasalVerb::new("give", "in", Social, VerbGroup::Obey, Some(false), 55));
+        self.add_phrasal(PhrasalVerb::new("deal", "with", Control, VerbGroup::ControlGroup, Some(true), 75)
+            .with_head_forms("dealt", "dealt"));
+        self.add_phrasal(PhrasalVerb::new("look", "forward to", Emotion, VerbGroup::Hope, Some(true), 65));
+        self.add_phrasal(PhrasalVerb::new("put", "up with", Emotion, VerbGroup::Suffer, Some(true), 50));
+        self.add_phrasal(PhrasalVerb::new("calm", "down", Emotion, VerbGroup::Satisfy, None, 55).separable());
+        self.add_phrasal(PhrasalVerb::new("cheer", "up", Emotion, VerbGroup::Satisfy, None, 50).separable());
+
+        // Movement and body
+        self.add_phrasal(PhrasalVerb::new("come", "back", Movement, VerbGroup::Enter, Some(false), 80));
+        self.add_phrasal(PhrasalVerb::new("go", "back", Movement, VerbGroup::Enter, Some(false), 75));
+        self.add_phrasal(PhrasalVerb::new("come", "in", Movement, VerbGroup::Enter, Some(false), 65));
+        self.add_phrasal(PhrasalVerb::new("show", "up", Movement, VerbGroup::Enter, Some(false), 60)
+            .with_head_forms("showed", "shown"));
+        self.add_phrasal(PhrasalVerb::new("check", "in", Movement, VerbGroup::Enter, Some(false), 50));
+        self.add_phrasal(PhrasalVerb::new("check", "out", Movement, VerbGroup::Exit, None, 55));
+        self.add_phrasal(PhrasalVerb::new("go", "away", Movement, VerbGroup::Exit, Some(false), 65));
+        self.add_phrasal(PhrasalVerb::new("get", "away", Movement, VerbGroup::Exit, Some(false), 55));
+        self.add_phrasal(PhrasalVerb::new("set", "off", Movement, VerbGroup::Exit, Some(false), 50));
+        self.add_phrasal(PhrasalVerb::new("run", "away", Movement, VerbGroup::Run, Some(false), 60));
+        self.add_phrasal(PhrasalVerb::new("catch", "up", Movement, VerbGroup::Run, Some(false), 55));
+        self.add_phrasal(PhrasalVerb::new("get", "up", Body, VerbGroup::Stand, Some(false), 75));
+        self.add_phrasal(PhrasalVerb::new("stand", "up", Body, VerbGroup::Stand, Some(false), 65));
+        self.add_phrasal(PhrasalVerb::new("sit", "down", Body, VerbGroup::Sit, Some(false), 70));
+        self.add_phrasal(PhrasalVerb::new("lie", "down", Body, VerbGroup::Lie, Some(false), 60)
+            .with_head_forms("lay", "lain"));
+        self.add_phrasal(PhrasalVerb::new("wake", "up", Body, VerbGroup::Wake, None, 75).separable());
+        self.add_phrasal(PhrasalVerb::new("grow", "up", State, VerbGroup::Become, Some(false), 65));
+    }
+}
+
+/// Auxiliaries and markers that start a new clause
+fn starts_clause(token: &str) -> bool {
+    matches!(token, "is" | "are" | "was" | "were" | "will" | "would" | "can" | "could"
+        | "should" | "must" | "please")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_adjacent_match() {
+        let db = VerbDatabase::with_builtin();
+        let t = tokens("turn off the lights");
+        let m = db.match_phrasal(&t, 0).unwrap();
+        assert_eq!(m.entry.base(), "turn off");
+        assert_eq!(m.entry.category, FunctionalCategory::Aspectual);
+        assert!(!m.separated);
+
+        let t = tokens("we carried out the plan");
+        let m = db.match_phrasal(&t, 1).unwrap();
+        assert_eq!(m.entry.base(), "carry out");
+        assert_eq!(m.surface(&t), "carried out");
+    }
+
+    #[test]
+    fn test_literal_preposition() {
+        let db = VerbDatabase::with_builtin();
+        // A particle heading a place is a preposition
+        assert!(db.match_phrasal(&tokens("she ran into the house"), 1).is_none());
+        assert!(db.match_phrasal(&tokens("put on the table"), 0).is_none());
+
+        let m = db.match_phrasal(&tokens("she ran into an old friend"), 1).unwrap();
+        assert_eq!(m.entry.base(), "run into");
+        let m = db.match_phrasal(&tokens("put on your coat"), 0).unwrap();
+        assert_eq!(m.entry.base(), "put on");
+    }
+
+    #[test]
+    fn test_separated_match() {
+        let db = VerbDatabase::with_builtin();
+        let t = tokens("turn the lights off");
+        let m = db.match_phrasal(&t, 0).unwrap();
+        assert_eq!(m.entry.base(), "turn off");
+        assert!(m.separated);
+        assert_eq!(m.particle_positions, vec![3]);
+
+        // Particle introducing an NP is a preposition
+        let t = tokens("put the book on the table");
+        assert!(db.match_phrasal(&t, 0).is_none());
+
+        // Inseparable verbs never split
+        let t = tokens("look the kids after");
+        assert!(db.match_phrasal(&t, 0).is_none());
+    }
+
+    #[test]
+    fn test_multiword_particle() {
+        let db = VerbDatabase::with_builtin();
+        let t = tokens("i cannot put up with this noise");
+        let m = db.match_phrasal(&t, 2).unwrap();
+        assert_eq!(m.entry.base(), "put up with");
+        assert_eq!(m.particle_positions, vec![3, 4]);
+        assert!(db.lookup_phrasal("get rid of").is_some());
+    }
+}
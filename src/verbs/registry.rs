@@ -0,0 +1,309 @@
+//! Custom categories and groups
+//!
+//! `FunctionalCategory` and `VerbGroup` are fixed; a `CategoryRegistry`
+//! adds domain-specific ones next to them. A custom verb keeps the nearest
+//! built-in category and group in `category`/`group`, so code that only
+//! knows the enums still works, and carries its custom IDs on top:
+//!
+//! ```
+//! use nl_sre_english::verbs::{VerbDatabase, VerbEntry, FunctionalCategory, VerbGroup};
+//!
+//! let mut db = VerbDatabase::with_builtin();
+//! let devops = db.register_category("DevOps", "Deployment and operations").unwrap();
+//! let release = db.register_group("Release", devops).unwrap();
+//! db.add(VerbEntry::regular("deploy", FunctionalCategory::Transfer, VerbGroup::Send, Some(true), 40)
+//!     .in_custom_group(release));
+//!
+//! assert_eq!(db.by_category(devops)[0].base, "deploy");
+//! assert_eq!(db.resolve_category("devops"), Some(devops.into()));
+//! ```
+
+use std::fmt;
+
+use super::{FunctionalCategory, VerbGroup};
+use super::taxonomy::{ClassId, Taxon};
+
+/// ID of a custom category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CategoryId(u16);
+
+/// ID of a custom group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GroupId(u16);
+
+/// A built-in or custom category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CategoryKey {
+    Builtin(FunctionalCategory),
+    Custom(CategoryId),
+}
+
+/// A built-in or custom group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupKey {
+    Builtin(VerbGroup),
+    Custom(GroupId),
+}
+
+impl From<FunctionalCategory> for CategoryKey {
+    fn from(category: FunctionalCategory) -> Self {
+        CategoryKey::Builtin(category)
+    }
+}
+
+impl From<CategoryId> for CategoryKey {
+    fn from(id: CategoryId) -> Self {
+        CategoryKey::Custom(id)
+    }
+}
+
+impl From<VerbGroup> for GroupKey {
+    fn from(group: VerbGroup) -> Self {
+        GroupKey::Builtin(group)
+    }
+}
+
+impl From<GroupId> for GroupKey {
+    fn from(id: GroupId) -> Self {
+        GroupKey::Custom(id)
+    }
+}
+
+/// A registered custom category
+#[derive(Debug, Clone)]
+pub struct CustomCategory {
+    pub id: CategoryId,
+    pub name: String,
+    pub description: String,
+}
+
+/// A registered custom group
+#[derive(Debug, Clone)]
+pub struct CustomGroup {
+    pub id: GroupId,
+    pub name: String,
+    /// Category the group belongs to (built-in or custom)
+    pub category: CategoryKey,
+}
+
+/// Why a registration was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+    /// The name is already used by a built-in category or group
+    BuiltinName(String),
+    /// The name is already used by another custom category, group or class
+    DuplicateName(String),
+    /// A group with this name is registered under another category
+    GroupConflict { name: String, category: CategoryKey },
+    /// The parent category was not registered here
+    UnknownCategory(CategoryId),
+    /// The group was not registered here
+    UnknownGroup(GroupId),
+    /// The taxonomy class was not registered here
+    UnknownClass(ClassId),
+    /// Groups cannot have children in the taxonomy
+    InvalidParent(Taxon),
+    /// Built-in categories are taxonomy roots
+    BuiltinRoot(FunctionalCategory),
+    /// The new parent lies below the node
+    Cycle(Taxon),
+    /// No IDs left
+    Full,
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::BuiltinName(name) => write!(f, "'{}' is a built-in name", name),
+            RegistryError::DuplicateName(name) => write!(f, "'{}' is already registered", name),
+            RegistryError::GroupConflict { name, category } => {
+                write!(f, "group '{}' is already registered under {:?}", name, category)
+            }
+            RegistryError::UnknownCategory(id) => write!(f, "unknown custom category {:?}", id),
+            RegistryError::UnknownGroup(id) => write!(f, "unknown custom group {:?}", id),
+            RegistryError::UnknownClass(id) => write!(f, "unknown taxonomy class {:?}", id),
+            RegistryError::InvalidParent(node) => write!(f, "{:?} cannot have children", node),
+            RegistryError::BuiltinRoot(c) => write!(f, "built-in category '{}' cannot be moved", c.name()),
+            RegistryError::Cycle(node) => write!(f, "moving {:?} would create a cycle", node),
+            RegistryError::Full => write!(f, "registry is full"),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// Custom categories and groups, looked up by ID or name
+#[derive(Debug, Clone, Default)]
+pub struct CategoryRegistry {
+    categories: Vec<CustomCategory>,
+    groups: Vec<CustomGroup>,
+}
+
+impl CategoryRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a category, or return the ID of one with the same name
+    ///
+    /// Category and group names share one namespace.
+    pub fn register_category(&mut self, name: &str, description: &str) -> Result<CategoryId, RegistryError> {
+        if is_builtin_name(name) {
+            return Err(RegistryError::BuiltinName(name.to_string()));
+        }
+        if self.groups.iter().any(|g| g.name.eq_ignore_ascii_case(name)) {
+            return Err(RegistryError::DuplicateName(name.to_string()));
+        }
+        if let Some(existing) = self.categories.iter().find(|c| c.name.eq_ignore_ascii_case(name)) {
+            return Ok(existing.id);
+        }
+        let id = CategoryId(u16::try_from(self.categories.len()).map_err(|_| RegistryError::Full)?);
+        self.categories.push(CustomCategory {
+            id,
+            name: name.to_string(),
+            description: description.to_string(),
+        });
+        Ok(id)
+    }
+
+    /// Register a group under a category, or return the ID of one with the same
+    /// name in the same category
+    pub fn register_group(&mut self, name: &str, category: impl Into<CategoryKey>) -> Result<GroupId, RegistryError> {
+        let category = category.into();
+        if is_builtin_name(name) {
+            return Err(RegistryError::BuiltinName(name.to_string()));
+        }
+        if self.categories.iter().any(|c| c.name.eq_ignore_ascii_case(name)) {
+            return Err(RegistryError::DuplicateName(name.to_string()));
+        }
+        if let CategoryKey::Custom(id) = category {
+            if self.category(id).is_none() {
+                return Err(RegistryError::UnknownCategory(id));
+            }
+        }
+        if let Some(existing) = self.groups.iter().find(|g| g.name.eq_ignore_ascii_case(name)) {
+            if existing.category != category {
+                return Err(RegistryError::GroupConflict { name: existing.name.clone(), category: existing.category });
+            }
+            return Ok(existing.id);
+        }
+        let id = GroupId(u16::try_from(self.groups.len()).map_err(|_| RegistryError::Full)?);
+        self.groups.push(CustomGroup { id, name: name.to_string(), category });
+        Ok(id)
+    }
+
+    /// Get a custom category by ID
+    pub fn category(&self, id: CategoryId) -> Option<&CustomCategory> {
+        self.categories.get(id.0 as usize)
+    }
+
+    /// Get a custom group by ID
+    pub fn group(&self, id: GroupId) -> Option<&CustomGroup> {
+        self.groups.get(id.0 as usize)
+    }
+
+    /// Find a built-in or custom category by name (case-insensitive)
+    pub fn resolve_category(&self, name: &str) -> Option<CategoryKey> {
+        FunctionalCategory::from_name(name).map(CategoryKey::Builtin)
+            .or_else(|| self.categories.iter()
+                .find(|c| c.name.eq_ignore_ascii_case(name))
+                .map(|c| CategoryKey::Custom(c.id)))
+    }
+
+    /// Find a built-in or custom group by name (case-insensitive)
+    pub fn resolve_group(&self, name: &str) -> Option<GroupKey> {
+        VerbGroup::from_name(name).map(GroupKey::Builtin)
+            .or_else(|| self.groups.iter()
+                .find(|g| g.name.eq_ignore_ascii_case(name))
+                .map(|g| GroupKey::Custom(g.id)))
+    }
+
+    /// Display name of a category
+    pub fn category_name(&self, key: CategoryKey) -> &str {
+        match key {
+            CategoryKey::Builtin(c) => c.name(),
+            CategoryKey::Custom(id) => self.category(id).map(|c| c.name.as_str()).unwrap_or("?"),
+        }
+    }
+
+    /// Display name of a group
+    pub fn group_name(&self, key: GroupKey) -> &str {
+        match key {
+            GroupKey::Builtin(g) => g.name(),
+            GroupKey::Custom(id) => self.group(id).map(|g| g.name.as_str()).unwrap_or("?"),
+        }
+    }
+
+    /// Category a group belongs to
+    pub fn category_of(&self, group: GroupKey) -> Option<CategoryKey> {
+        match group {
+            GroupKey::Builtin(g) => Some(CategoryKey::Builtin(g.category())),
+            GroupKey::Custom(id) => self.group(id).map(|g| g.category),
+        }
+    }
+
+    /// All custom categories
+    pub fn categories(&self) -> &[CustomCategory] {
+        &self.categories
+    }
+
+    /// All custom groups
+    pub fn groups(&self) -> &[CustomGroup] {
+        &self.groups
+    }
+}
+
+/// Name of a built-in category or group
+fn is_builtin_name(name: &str) -> bool {
+    FunctionalCategory::from_name(name).is_some() || VerbGroup::from_name(name).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_and_resolve() {
+        let mut registry = CategoryRegistry::new();
+        let devops = registry.register_category("DevOps", "Deployment and operations").unwrap();
+        assert_eq!(registry.register_category("devops", "").unwrap(), devops);
+        let release = registry.register_group("Release", devops).unwrap();
+        let provision = registry.register_group("Provision", FunctionalCategory::Creation).unwrap();
+
+        assert_eq!(registry.resolve_category("DEVOPS"), Some(CategoryKey::Custom(devops)));
+        assert_eq!(registry.resolve_category("Movement"), Some(CategoryKey::Builtin(FunctionalCategory::Movement)));
+        assert_eq!(registry.category_of(release.into()), Some(CategoryKey::Custom(devops)));
+        assert_eq!(registry.category_of(provision.into()), Some(FunctionalCategory::Creation.into()));
+        assert_eq!(registry.group_name(release.into()), "Release");
+    }
+
+    #[test]
+    fn test_builtin_names_rejected() {
+        let mut registry = CategoryRegistry::new();
+        assert_eq!(registry.register_category("Movement", ""), Err(RegistryError::BuiltinName("Movement".to_string())));
+        assert!(registry.register_group("Walk", FunctionalCategory::Movement).is_err());
+        assert_eq!(registry.register_category("Walk", ""), Err(RegistryError::BuiltinName("Walk".to_string())));
+        assert_eq!(registry.register_group("Movement", FunctionalCategory::Movement),
+            Err(RegistryError::BuiltinName("Movement".to_string())));
+    }
+
+    #[test]
+    fn test_shared_namespace() {
+        let mut registry = CategoryRegistry::new();
+        let ops = registry.register_category("Ops", "").unwrap();
+        assert_eq!(registry.register_group("ops", ops), Err(RegistryError::DuplicateName("ops".to_string())));
+        registry.register_group("Release", ops).unwrap();
+        assert_eq!(registry.register_category("Release", ""), Err(RegistryError::DuplicateName("Release".to_string())));
+    }
+
+    #[test]
+    fn test_group_category_conflict() {
+        let mut registry = CategoryRegistry::new();
+        let release = registry.register_group("Release", FunctionalCategory::Creation).unwrap();
+        assert_eq!(registry.register_group("release", FunctionalCategory::Creation), Ok(release));
+        assert_eq!(
+            registry.register_group("Release", FunctionalCategory::Movement),
+            Err(RegistryError::GroupConflict { name: "Release".to_string(), category: FunctionalCategory::Creation.into() }),
+        );
+    }
+}
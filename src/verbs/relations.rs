@@ -0,0 +1,300 @@
+//! Synonym/antonym graph over the verb database
+//!
+//! `VerbEntry::synonyms` and `antonyms` are linked in both directions when
+//! an entry is added, so "walk" -> "stroll" also gives "stroll" -> "walk".
+//! Single-word synonyms that are not entries themselves become aliases of
+//! the head verb: if "walk" lists "mosey" and there is no "mosey" entry,
+//! `lookup("moseyed")` returns the "walk" entry.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::{VerbDatabase, VerbGroup, builtin, inflection};
+
+/// Kind of lexical relation between two verbs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relation {
+    Synonym,
+    Antonym,
+}
+
+/// A chain of lexical relations between two verbs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LexicalPath {
+    /// Verbs along the path, including both ends
+    pub words: Vec<String>,
+    /// Relation of each step (`words.len() - 1` entries)
+    pub relations: Vec<Relation>,
+}
+
+impl LexicalPath {
+    /// Number of steps
+    pub fn len(&self) -> usize {
+        self.relations.len()
+    }
+
+    /// Check if the path has no steps (a verb to itself)
+    pub fn is_empty(&self) -> bool {
+        self.relations.is_empty()
+    }
+
+    /// An odd number of antonym steps makes the ends opposites
+    pub fn is_antonymous(&self) -> bool {
+        self.relations.iter().filter(|r| **r == Relation::Antonym).count() % 2 == 1
+    }
+}
+
+impl VerbDatabase {
+    /// Link an entry's synonyms and antonyms and register aliases
+    pub(super) fn index_relations(&mut self, base: &str, synonyms: &[String], antonyms: &[String]) {
+        for syn in synonyms {
+            link(&mut self.synonym_graph, base, syn);
+            if !syn.contains(' ') && !self.has_form(syn) && self.alias_of(syn).is_none() {
+                let forms = inflection::inflect(syn);
+                for form in [syn.clone(), forms.past, forms.present_participle, forms.third_person] {
+                    self.alias_index.entry(form).or_insert_with(|| (syn.clone(), base.to_string()));
+                }
+            }
+        }
+        for ant in antonyms {
+            link(&mut self.antonym_graph, base, ant);
+        }
+    }
+
+    /// Sorted neighbours of a node: built-in links plus runtime links
+    fn neighbours(&self, relation: Relation, node: &str) -> Vec<String> {
+        let (graph, builtin): (_, &[&str]) = match relation {
+            Relation::Synonym => (&self.synonym_graph, if self.builtin { builtin::synonyms(node) } else { &[] }),
+            Relation::Antonym => (&self.antonym_graph, if self.builtin { builtin::antonyms(node) } else { &[] }),
+        };
+        let mut next: Vec<String> = builtin.iter().map(|w| w.to_string()).collect();
+        next.extend(graph.get(node).into_iter().flatten().cloned());
+        next.sort();
+        next.dedup();
+        next
+    }
+
+    /// Resolve any verb form or alias to the node used in the graph
+    fn relation_node(&self, verb: &str) -> String {
+        let w = verb.to_lowercase();
+        self.base_form(&w).map(|b| b.to_string()).unwrap_or(w)
+    }
+
+    /// Synonyms of a verb, following synonym links up to `depth` steps
+    ///
+    /// Closer synonyms come first; ties are alphabetical.
+    pub fn synonyms_of(&self, verb: &str, depth: usize) -> Vec<String> {
+        let start = self.relation_node(verb);
+        let mut found: Vec<(usize, String)> = bfs(|n| self.neighbours(Relation::Synonym, n), &start, depth, |_| true)
+            .into_iter()
+            .filter(|(w, _)| *w != start)
+            .map(|(w, d)| (d, w))
+            .collect();
+        found.sort();
+        found.into_iter().map(|(_, w)| w).collect()
+    }
+
+    /// Direct antonyms of a verb (in either direction)
+    pub fn antonyms_of(&self, verb: &str) -> Vec<String> {
+        let node = self.relation_node(verb);
+        self.neighbours(Relation::Antonym, &node)
+    }
+
+    /// Number of synonym steps between two verbs, if at most `max_distance`
+    pub fn synonym_distance(&self, a: &str, b: &str, max_distance: usize) -> Option<usize> {
+        let (a, b) = (self.relation_node(a), self.relation_node(b));
+        bfs(|n| self.neighbours(Relation::Synonym, n), &a, max_distance, |_| true).get(&b).copied()
+    }
+
+    /// Shortest chain of synonym/antonym links between two verbs
+    ///
+    /// Searches from both ends at once, widening the smaller frontier a
+    /// level at a time, so only the neighbourhoods of the two verbs are visited.
+    pub fn path_between(&self, a: &str, b: &str) -> Option<LexicalPath> {
+        let (start, goal) = (self.relation_node(a), self.relation_node(b));
+        if start == goal {
+            return Some(LexicalPath { words: vec![start], relations: Vec::new() });
+        }
+        let mut forward: Trail = HashMap::from([(start.clone(), None)]);
+        let mut backward: Trail = HashMap::from([(goal.clone(), None)]);
+        let (mut front, mut back) = (vec![start], vec![goal]);
+
+        while !front.is_empty() && !back.is_empty() {
+            let (frontier, visited, other) = if front.len() <= back.len() {
+                (&mut front, &mut forward, &backward)
+            } else {
+                (&mut back, &mut backward, &forward)
+            };
+            let mut next_level = Vec::new();
+            let mut meeting = None;
+            for node in frontier.drain(..) {
+                for rel in [Relation::Synonym, Relation::Antonym] {
+                    for next in self.neighbours(rel, &node) {
+                        if visited.contains_key(&next) {
+                            continue;
+                        }
+                        visited.insert(next.clone(), Some((node.clone(), rel)));
+                        if meeting.is_none() && other.contains_key(&next) {
+                            meeting = Some(next.clone());
+                        }
+                        next_level.push(next);
+                    }
+                }
+            }
+            if let Some(meeting) = meeting {
+                return Some(join(&forward, &backward, &meeting));
+            }
+            *frontier = next_level;
+        }
+        None
+    }
+
+    /// Verbs linked to `verb` by up to `depth` synonym steps without leaving its group
+    pub fn related_in_group(&self, verb: &str, depth: usize) -> Vec<String> {
+        let start = self.relation_node(verb);
+        let Some(group) = self.get_group(&start) else { return Vec::new() };
+        let in_group = |w: &str| self.group_of_node(w) == Some(group);
+        let mut result: Vec<String> = bfs(|n| self.neighbours(Relation::Synonym, n), &start, depth, in_group)
+            .into_keys()
+            .filter(|w| *w != start)
+            .collect();
+        result.sort();
+        result
+    }
+
+    /// Group of a graph node: its own entry, or the head of an alias
+    fn group_of_node(&self, node: &str) -> Option<VerbGroup> {
+        self.entries_of(node)
+            .and_then(|entries| entries.first())
+            .map(|e| e.group)
+            .or_else(|| self.alias_of(node)
+                .and_then(|(_, head)| self.entries_of(head))
+                .and_then(|entries| entries.first())
+                .map(|e| e.group))
+    }
+}
+
+/// Search tree of one side of `path_between`: each node's step back towards that side's end
+type Trail = HashMap<String, Option<(String, Relation)>>;
+
+/// Path through the node where the two searches met
+fn join(forward: &Trail, backward: &Trail, meeting: &str) -> LexicalPath {
+    let mut words = vec![meeting.to_string()];
+    let mut relations = Vec::new();
+    let mut cur = meeting;
+    while let Some(Some((prev, rel))) = forward.get(cur) {
+        words.push(prev.clone());
+        relations.push(*rel);
+        cur = prev;
+    }
+    words.reverse();
+    relations.reverse();
+    let mut cur = meeting;
+    while let Some(Some((next, rel))) = backward.get(cur) {
+        words.push(next.clone());
+        relations.push(*rel);
+        cur = next;
+    }
+    LexicalPath { words, relations }
+}
+
+fn link(graph: &mut HashMap<String, HashSet<String>>, a: &str, b: &str) {
+    if a == b {
+        return;
+    }
+    graph.entry(a.to_string()).or_default().insert(b.to_string());
+    graph.entry(b.to_string()).or_default().insert(a.to_string());
+}
+
+/// Breadth-first distances from `start`, visiting only nodes accepted by `keep`
+fn bfs<N: Fn(&str) -> Vec<String>, F: Fn(&str) -> bool>(
+    neighbours: N,
+    start: &str,
+    depth: usize,
+    keep: F,
+) -> HashMap<String, usize> {
+    let mut dist = HashMap::from([(start.to_string(), 0)]);
+    let mut queue = VecDeque::from([start.to_string()]);
+    while let Some(node) = queue.pop_front() {
+        let d = dist[&node];
+        if d >= depth {
+            continue;
+        }
+        for next in neighbours(&node) {
+            if !dist.contains_key(&next) && keep(&next) {
+                dist.insert(next.clone(), d + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verbs::{FunctionalCategory, VerbEntry};
+
+    #[test]
+    fn test_symmetric_synonyms() {
+        let db = VerbDatabase::with_builtin();
+        let walk = db.synonyms_of("walk", 1);
+        assert!(walk.contains(&"stroll".to_string()));
+        // Reverse direction comes from walk's list
+        assert!(db.synonyms_of("stroll", 1).contains(&"walk".to_string()));
+        // Transitive closure grows with depth
+        assert!(db.synonyms_of("stroll", 2).len() > db.synonyms_of("stroll", 1).len());
+        assert_eq!(db.synonym_distance("walk", "walked", 3), Some(0));
+        assert_eq!(db.synonym_distance("walk", "stroll", 1), Some(1));
+        let near = db.synonyms_of("stroll", 1);
+        let far = db.synonyms_of("stroll", 2).into_iter().find(|w| !near.contains(w)).unwrap();
+        assert_eq!(db.synonym_distance("stroll", &far, 1), None);
+        assert_eq!(db.synonym_distance("stroll", &far, 2), Some(2));
+    }
+
+    #[test]
+    fn test_alias_lookup() {
+        let mut db = VerbDatabase::new();
+        db.add(VerbEntry::regular("walk", FunctionalCategory::Movement, VerbGroup::Walk, None, 95)
+            .with_synonyms(&["stroll", "amble"]));
+        let entry = db.lookup("stroll").unwrap();
+        assert_eq!(entry.base, "walk");
+        assert_eq!(db.get_category("ambled"), Some(FunctionalCategory::Movement));
+        assert_eq!(db.base_form("strolling"), Some("stroll"));
+        assert!(db.is_verb("stroll"));
+
+        // A later entry of its own takes precedence over the alias
+        db.add(VerbEntry::regular("stroll", FunctionalCategory::Movement, VerbGroup::Walk, None, 60));
+        assert_eq!(db.lookup("stroll").unwrap().base, "stroll");
+    }
+
+    #[test]
+    fn test_antonyms_and_paths() {
+        let db = VerbDatabase::with_builtin();
+        assert!(db.antonyms_of("give").contains(&"take".to_string()));
+        assert!(db.antonyms_of("take").contains(&"give".to_string()));
+
+        let path = db.path_between("donate", "take").unwrap();
+        assert_eq!(path.words.first().map(|s| s.as_str()), Some("donate"));
+        assert_eq!(path.words.last().map(|s| s.as_str()), Some("take"));
+        assert!(path.is_antonymous());
+        assert!(db.path_between("walk", "walk").unwrap().is_empty());
+        for (i, rel) in path.relations.iter().enumerate() {
+            assert!(db.neighbours(*rel, &path.words[i]).contains(&path.words[i + 1]));
+        }
+        assert_eq!(db.path_between("stroll", "walk").map(|p| p.len()), Some(1));
+
+        let mut unlinked = VerbDatabase::new();
+        unlinked.add(VerbEntry::regular("walk", FunctionalCategory::Movement, VerbGroup::Walk, None, 95));
+        unlinked.add(VerbEntry::regular("jump", FunctionalCategory::Movement, VerbGroup::Walk, None, 80));
+        assert_eq!(unlinked.path_between("walk", "jump"), None);
+    }
+
+    #[test]
+    fn test_related_in_group() {
+        let db = VerbDatabase::with_builtin();
+        let related = db.related_in_group("walk", 3);
+        assert!(related.contains(&"stroll".to_string()));
+        assert!(db.related_in_group("walk", 1).len() <= related.len());
+        assert!(related.iter().all(|w| db.get_group(w) == Some(VerbGroup::Walk)));
+    }
+}
@@ -0,0 +1,188 @@
+//! Selectional preferences
+//!
+//! What kind of thing a verb group expects in each role: only animate beings
+//! eat, and what they eat is food. "The rock ate the sandwich" and "Drink the
+//! chair" break these preferences; the disambiguator reports such arguments
+//! and uses the preferences to rank corrections and verb senses.
+//!
+//! ```
+//! use nl_sre_english::verbs::{VerbDatabase, VerbGroup, SemanticType};
+//! use nl_sre_english::srl::SemanticRole;
+//!
+//! let db = VerbDatabase::with_builtin();
+//! let eat = db.preferences(VerbGroup::Eat);
+//! let agent = eat.iter().find(|p| p.role == SemanticRole::Agent).unwrap();
+//! assert_eq!(agent.types, vec![SemanticType::Animate]);
+//! ```
+
+use crate::semantic::SemanticEntry;
+use crate::srl::SemanticRole;
+use super::{VerbDatabase, VerbGroup, GroupKey};
+
+/// Kind of noun a role accepts, checked against the tags of `SemanticDB`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SemanticType {
+    /// People and animals
+    Animate,
+    /// People
+    Human,
+    /// Companies, teams, institutions
+    Organization,
+    /// Living things, plants included
+    Living,
+    /// Physical objects
+    Concrete,
+    /// Ideas, fields, states
+    Abstract,
+    /// Food
+    Edible,
+    /// Drinks and other liquids
+    Liquid,
+    /// Places
+    Location,
+    /// Texts, media and data
+    Information,
+    /// Money and payments
+    Money,
+    /// Vehicles
+    Vehicle,
+    /// Man-made objects
+    Artifact,
+    /// Body parts
+    Body,
+    /// Days, periods, events in time
+    Time,
+}
+
+impl SemanticType {
+    /// Lowercase name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SemanticType::Animate => "animate",
+            SemanticType::Human => "human",
+            SemanticType::Organization => "organization",
+            SemanticType::Living => "living",
+            SemanticType::Concrete => "concrete",
+            SemanticType::Abstract => "abstract",
+            SemanticType::Edible => "edible",
+            SemanticType::Liquid => "liquid",
+            SemanticType::Location => "location",
+            SemanticType::Information => "information",
+            SemanticType::Money => "money",
+            SemanticType::Vehicle => "vehicle",
+            SemanticType::Artifact => "artifact",
+            SemanticType::Body => "body",
+            SemanticType::Time => "time",
+        }
+    }
+
+    /// Check if a noun is of this type
+    pub fn accepts(&self, noun: &SemanticEntry) -> bool {
+        let tag = |t: &str| noun.has_tag(t);
+        match self {
+            SemanticType::Animate => tag("animate"),
+            SemanticType::Human => tag("human"),
+            SemanticType::Organization => tag("organization") || tag("institution") || tag("group"),
+            SemanticType::Living => tag("living") || tag("animate"),
+            SemanticType::Concrete => tag("concrete") || tag("animate") || tag("body"),
+            SemanticType::Abstract => tag("abstract"),
+            SemanticType::Edible => tag("edible"),
+            SemanticType::Liquid => tag("liquid"),
+            SemanticType::Location => tag("location"),
+            SemanticType::Information => tag("information") || tag("readable") || tag("media"),
+            SemanticType::Money => tag("money"),
+            SemanticType::Vehicle => tag("vehicle"),
+            SemanticType::Artifact => tag("artifact"),
+            SemanticType::Body => tag("body"),
+            SemanticType::Time => tag("time"),
+        }
+    }
+}
+
+/// Types a verb accepts in one role (any of them will do)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectionalPreference {
+    /// Role constrained
+    pub role: SemanticRole,
+    /// Accepted types
+    pub types: Vec<SemanticType>,
+}
+
+impl SelectionalPreference {
+    /// Create a preference
+    pub fn new(role: SemanticRole, types: &[SemanticType]) -> Self {
+        Self { role, types: types.to_vec() }
+    }
+
+    /// Check if a noun satisfies the preference
+    pub fn accepts(&self, noun: &SemanticEntry) -> bool {
+        self.types.iter().any(|t| t.accepts(noun))
+    }
+}
+
+impl VerbGroup {
+    /// Built-in selectional preferences of the group
+    ///
+    /// Groups whose arguments vary too much ("run a company", "the engine
+    /// runs") have none.
+    pub fn preferences(&self) -> Vec<SelectionalPreference> {
+        use SemanticRole::{Agent, Patient};
+        use SemanticType::*;
+        let specs: &[(SemanticRole, &[SemanticType])] = match self {
+            VerbGroup::Eat => &[(Agent, &[Animate]), (Patient, &[Edible])],
+            VerbGroup::Drink => &[(Agent, &[Animate]), (Patient, &[Liquid])],
+            VerbGroup::Cook => &[(Agent, &[Human]), (Patient, &[Edible])],
+            VerbGroup::Taste => &[(Agent, &[Animate]), (Patient, &[Edible])],
+            VerbGroup::Breathe | VerbGroup::Sleep | VerbGroup::Wake
+            | VerbGroup::Walk | VerbGroup::Jump | VerbGroup::Swim | VerbGroup::Climb
+            | VerbGroup::Sit | VerbGroup::Kneel | VerbGroup::Kick
+            | VerbGroup::See | VerbGroup::Hear | VerbGroup::Smell
+            | VerbGroup::Love | VerbGroup::Hate | VerbGroup::Fear | VerbGroup::Enjoy => &[(Agent, &[Animate])],
+            VerbGroup::Fly => &[(Agent, &[Animate, Vehicle])],
+            VerbGroup::Speak | VerbGroup::Ask | VerbGroup::Answer | VerbGroup::Explain
+            | VerbGroup::Argue | VerbGroup::Promise | VerbGroup::Warn | VerbGroup::Suggest => &[(Agent, &[Human, Organization])],
+            VerbGroup::Think | VerbGroup::Know | VerbGroup::Believe | VerbGroup::Remember
+            | VerbGroup::Forget | VerbGroup::Imagine => &[(Agent, &[Animate])],
+            VerbGroup::Write => &[(Agent, &[Human, Organization]), (Patient, &[Information, Abstract])],
+            _ => &[],
+        };
+        specs.iter().map(|(role, types)| SelectionalPreference::new(*role, types)).collect()
+    }
+}
+
+impl VerbDatabase {
+    /// Selectional preferences of a group
+    ///
+    /// Preferences set with `set_preferences` replace the built-in ones;
+    /// custom groups have none until set.
+    pub fn preferences(&self, group: impl Into<GroupKey>) -> Vec<SelectionalPreference> {
+        let key = group.into();
+        match (self.preferences.get(&key), key) {
+            (Some(prefs), _) => prefs.clone(),
+            (None, GroupKey::Builtin(group)) => group.preferences(),
+            (None, GroupKey::Custom(_)) => Vec::new(),
+        }
+    }
+
+    /// Replace the selectional preferences of a group
+    pub fn set_preferences(&mut self, group: impl Into<GroupKey>, preferences: Vec<SelectionalPreference>) {
+        self.preferences.insert(group.into(), preferences);
+    }
+
+    /// Preference of a group for one role
+    pub fn preference(&self, group: impl Into<GroupKey>, role: SemanticRole) -> Option<SelectionalPreference> {
+        self.preferences(group).into_iter().find(|p| p.role == role)
+    }
+
+    /// Preference of a verb sense for one role, falling back from a group
+    /// without preferences set to the sense's built-in group (an empty list
+    /// set with `set_preferences` clears them)
+    pub(crate) fn sense_preference(&self, key: GroupKey, group: VerbGroup, role: SemanticRole) -> Option<SelectionalPreference> {
+        let preferences = if self.preferences.contains_key(&key) {
+            self.preferences(key)
+        } else {
+            group.preferences()
+        };
+        preferences.into_iter().find(|p| p.role == role)
+    }
+}
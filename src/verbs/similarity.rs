@@ -0,0 +1,143 @@
+//! Deterministic verb similarity
+//!
+//! Scores come from the taxonomy and the synonym graph only:
+//!
+//! | Evidence | Score |
+//! |----------|-------|
+//! | Same verb | 1.0 |
+//! | Synonyms at distance 1 / 2 / 3 | 0.9 / 0.75 / 0.6 |
+//! | Same `VerbGroup` | 0.7 |
+//! | Same `FunctionalCategory` | 0.4 |
+//! | Antonyms | best score × 0.2 |
+//!
+//! The best evidence wins; every score carries an explanation.
+
+use super::{VerbDatabase, VerbEntry};
+
+/// Maximum synonym-graph distance that counts as evidence
+const MAX_SYNONYM_DISTANCE: usize = 3;
+
+/// Score multiplier for antonyms ("open" vs "close")
+const ANTONYM_FACTOR: f64 = 0.2;
+
+/// Similarity between two verbs with the evidence behind it
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerbSimilarity {
+    /// Score in [0, 1]
+    pub score: f64,
+    /// Some senses share a group
+    pub same_group: bool,
+    /// Some senses share a category
+    pub same_category: bool,
+    /// Steps in the synonym graph, if connected
+    pub synonym_distance: Option<usize>,
+    /// The verbs are (possibly indirect) antonyms
+    pub antonyms: bool,
+    /// Human-readable reason for the score
+    pub explanation: String,
+}
+
+impl VerbDatabase {
+    /// Similarity between two verbs (any inflected form)
+    pub fn similarity(&self, a: &str, b: &str) -> VerbSimilarity {
+        let (base_a, base_b) = match (self.base_form(a), self.base_form(b)) {
+            (Some(x), Some(y)) => (x.to_string(), y.to_string()),
+            _ => {
+                return VerbSimilarity {
+                    score: 0.0,
+                    same_group: false,
+                    same_category: false,
+                    synonym_distance: None,
+                    antonyms: false,
+                    explanation: format!("'{}' or '{}' is not a known verb", a, b),
+                };
+            }
+        };
+
+        if base_a == base_b {
+            return VerbSimilarity {
+                score: 1.0,
+                same_group: true,
+                same_category: true,
+                synonym_distance: Some(0),
+                antonyms: false,
+                explanation: format!("same verb '{}'", base_a),
+            };
+        }
+
+        let senses_a: Vec<&VerbEntry> = self.lookup_all(&base_a).map(|v| v.iter().collect()).unwrap_or_default();
+        let senses_b: Vec<&VerbEntry> = self.lookup_all(&base_b).map(|v| v.iter().collect()).unwrap_or_default();
+        let shared = |f: &dyn Fn(&VerbEntry, &VerbEntry) -> bool| {
+            senses_a.iter().find_map(|x| senses_b.iter().find(|y| f(x, y)).map(|y| (*x, *y)))
+        };
+        let group = shared(&|x, y| x.group == y.group);
+        let category = shared(&|x, y| x.category == y.category);
+
+        let distance = self.synonym_distance(&base_a, &base_b, MAX_SYNONYM_DISTANCE);
+        let antonyms = self.antonyms_of(&base_a).contains(&base_b)
+            || self.path_between(&base_a, &base_b)
+                .map(|p| p.len() <= 2 && p.is_antonymous())
+                .unwrap_or(false);
+
+        let mut candidates: Vec<(f64, String)> = Vec::new();
+        if let Some(d) = distance {
+            let score = match d {
+                1 => 0.9,
+                2 => 0.75,
+                _ => 0.6,
+            };
+            candidates.push((score, format!("synonyms at distance {}", d)));
+        }
+        if let Some((x, _)) = group {
+            candidates.push((0.7, format!("same group {}", x.group.name())));
+        }
+        if let Some((x, _)) = category {
+            candidates.push((0.4, format!("same category {}", x.category.name())));
+        }
+
+        let (mut score, mut explanation) = candidates.into_iter()
+            .fold((0.0, "unrelated".to_string()), |best, c| if c.0 > best.0 { c } else { best });
+        if antonyms {
+            score *= ANTONYM_FACTOR;
+            explanation = format!("antonyms ({})", explanation);
+        }
+
+        VerbSimilarity {
+            score,
+            same_group: group.is_some(),
+            same_category: category.is_some(),
+            synonym_distance: distance,
+            antonyms,
+            explanation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verb_similarity_ordering() {
+        let db = VerbDatabase::with_builtin();
+        let same = db.similarity("walked", "walk");
+        let synonym = db.similarity("walk", "stroll");
+        let group = db.similarity("run", "sprint");
+        let unrelated = db.similarity("walk", "eat");
+
+        assert_eq!(same.score, 1.0);
+        assert!(synonym.score >= 0.7 && synonym.synonym_distance == Some(1));
+        assert!(group.same_group);
+        assert!(unrelated.score < synonym.score);
+        assert_eq!(db.similarity("walk", "stroll"), db.similarity("walk", "stroll"));
+    }
+
+    #[test]
+    fn test_antonyms_are_dissimilar() {
+        let db = VerbDatabase::with_builtin();
+        let sim = db.similarity("give", "take");
+        assert!(sim.antonyms);
+        assert!(sim.score < 0.3);
+        assert!(sim.explanation.starts_with("antonyms"));
+    }
+}
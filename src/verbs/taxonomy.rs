@@ -0,0 +1,487 @@
+//! Hierarchical category taxonomy
+//!
+//! Categories, groups and verbs form a forest: built-in categories are the
+//! roots, groups hang under their category and verbs are the leaves.
+//! Named classes can be inserted between levels, and custom categories can
+//! be nested under other categories:
+//!
+//! ```text
+//! Movement
+//! └── Self-motion
+//!     └── Run
+//!         ├── sprint
+//!         └── dash
+//! ```
+//!
+//! Queries walk parent links at call time, so a rule written against an
+//! inner node also covers groups registered under it later:
+//!
+//! ```
+//! use nl_sre_english::verbs::{VerbDatabase, VerbEntry, FunctionalCategory, VerbGroup};
+//!
+//! let mut db = VerbDatabase::with_builtin();
+//! let purge = db.register_group("Purge", FunctionalCategory::Destruction).unwrap();
+//! db.add(VerbEntry::regular("shred", FunctionalCategory::Destruction, VerbGroup::Destroy, Some(true), 30)
+//!     .in_custom_group(purge));
+//!
+//! assert!(db.is_a("shredded", FunctionalCategory::Destruction));
+//! let self_motion = db.resolve_taxon("Self-motion").unwrap();
+//! assert!(db.is_a("sprint", self_motion));
+//! assert_eq!(db.common_ancestor("sprint", "swim"), Some(self_motion));
+//! ```
+
+use std::collections::HashMap;
+
+use super::{
+    VerbDatabase, VerbEntry, FunctionalCategory, VerbGroup,
+    CategoryRegistry, CategoryId, GroupId, CategoryKey, GroupKey, RegistryError,
+};
+
+/// ID of a taxonomy class (an inner node such as "Self-motion")
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClassId(u16);
+
+/// A node of the taxonomy (verbs are leaves and are queried by word)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Taxon {
+    Category(CategoryKey),
+    Class(ClassId),
+    Group(GroupKey),
+}
+
+impl From<CategoryKey> for Taxon {
+    fn from(key: CategoryKey) -> Self {
+        Taxon::Category(key)
+    }
+}
+
+impl From<GroupKey> for Taxon {
+    fn from(key: GroupKey) -> Self {
+        Taxon::Group(key)
+    }
+}
+
+impl From<ClassId> for Taxon {
+    fn from(id: ClassId) -> Self {
+        Taxon::Class(id)
+    }
+}
+
+impl From<FunctionalCategory> for Taxon {
+    fn from(category: FunctionalCategory) -> Self {
+        Taxon::Category(category.into())
+    }
+}
+
+impl From<CategoryId> for Taxon {
+    fn from(id: CategoryId) -> Self {
+        Taxon::Category(id.into())
+    }
+}
+
+impl From<VerbGroup> for Taxon {
+    fn from(group: VerbGroup) -> Self {
+        Taxon::Group(group.into())
+    }
+}
+
+impl From<GroupId> for Taxon {
+    fn from(id: GroupId) -> Self {
+        Taxon::Group(id.into())
+    }
+}
+
+/// A registered taxonomy class
+#[derive(Debug, Clone)]
+pub struct TaxonClass {
+    pub id: ClassId,
+    pub name: String,
+}
+
+/// Built-in classes: (name, parent category, groups moved under the class)
+const BUILTIN_CLASSES: &[(&str, FunctionalCategory, &[VerbGroup])] = &[
+    ("Self-motion", FunctionalCategory::Movement,
+        &[VerbGroup::Walk, VerbGroup::Run, VerbGroup::Jump, VerbGroup::Fly, VerbGroup::Swim, VerbGroup::Climb]),
+    ("Directed motion", FunctionalCategory::Movement,
+        &[VerbGroup::Enter, VerbGroup::Exit, VerbGroup::Fall, VerbGroup::Turn]),
+    ("Positive emotion", FunctionalCategory::Emotion,
+        &[VerbGroup::Love, VerbGroup::Hope, VerbGroup::Enjoy, VerbGroup::Satisfy]),
+    ("Negative emotion", FunctionalCategory::Emotion,
+        &[VerbGroup::Hate, VerbGroup::Fear, VerbGroup::Suffer, VerbGroup::Anger]),
+    ("Giving", FunctionalCategory::Transfer,
+        &[VerbGroup::Give, VerbGroup::Send, VerbGroup::Lend, VerbGroup::Return]),
+    ("Getting", FunctionalCategory::Transfer,
+        &[VerbGroup::Take, VerbGroup::Receive, VerbGroup::Borrow, VerbGroup::Steal]),
+];
+
+/// Classes and parent links on top of the category/group mapping
+///
+/// Nodes without an explicit parent fall back to the flat mapping: a
+/// group's parent is its category, and categories are roots.
+#[derive(Debug, Clone, Default)]
+pub struct Taxonomy {
+    classes: Vec<TaxonClass>,
+    parents: HashMap<Taxon, Taxon>,
+}
+
+impl Taxonomy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Taxonomy with the built-in intermediate classes
+    pub fn builtin() -> Self {
+        let registry = CategoryRegistry::new();
+        let mut taxonomy = Self::new();
+        for (name, category, groups) in BUILTIN_CLASSES {
+            let class = taxonomy.register_class(name, *category, &registry)
+                .expect("built-in class");
+            for group in *groups {
+                taxonomy.set_parent(*group, class, &registry).expect("built-in group");
+            }
+        }
+        taxonomy
+    }
+
+    /// Register a class under a parent, or return the ID of one with the same
+    /// name under the same parent
+    pub fn register_class(
+        &mut self,
+        name: &str,
+        parent: impl Into<Taxon>,
+        registry: &CategoryRegistry,
+    ) -> Result<ClassId, RegistryError> {
+        if FunctionalCategory::from_name(name).is_some() || VerbGroup::from_name(name).is_some() {
+            return Err(RegistryError::BuiltinName(name.to_string()));
+        }
+        if registry.resolve_category(name).is_some() || registry.resolve_group(name).is_some() {
+            return Err(RegistryError::DuplicateName(name.to_string()));
+        }
+        let parent = parent.into();
+        if let Some(existing) = self.classes.iter().find(|c| c.name.eq_ignore_ascii_case(name)) {
+            if self.parents.get(&Taxon::Class(existing.id)) != Some(&parent) {
+                return Err(self.check_name(name).unwrap_err());
+            }
+            return Ok(existing.id);
+        }
+        self.check_parent(parent, registry)?;
+        let id = ClassId(u16::try_from(self.classes.len()).map_err(|_| RegistryError::Full)?);
+        self.classes.push(TaxonClass { id, name: name.to_string() });
+        self.parents.insert(Taxon::Class(id), parent);
+        Ok(id)
+    }
+
+    /// Check that no class has a name, for a new category or group
+    pub(super) fn check_name(&self, name: &str) -> Result<(), RegistryError> {
+        match self.classes.iter().find(|c| c.name.eq_ignore_ascii_case(name)) {
+            Some(_) if BUILTIN_CLASSES.iter().any(|(n, _, _)| n.eq_ignore_ascii_case(name)) => {
+                Err(RegistryError::BuiltinName(name.to_string()))
+            }
+            Some(class) => Err(RegistryError::DuplicateName(class.name.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Move a class, group or custom category under a new parent
+    ///
+    /// Built-in categories stay roots, groups cannot have children, and a
+    /// node cannot be moved below itself.
+    pub fn set_parent(
+        &mut self,
+        node: impl Into<Taxon>,
+        parent: impl Into<Taxon>,
+        registry: &CategoryRegistry,
+    ) -> Result<(), RegistryError> {
+        let (node, parent) = (node.into(), parent.into());
+        self.check_node(node, registry)?;
+        if let Taxon::Category(CategoryKey::Builtin(category)) = node {
+            return Err(RegistryError::BuiltinRoot(category));
+        }
+        self.check_parent(parent, registry)?;
+        if self.is_under(parent, node, registry) {
+            return Err(RegistryError::Cycle(node));
+        }
+        self.parents.insert(node, parent);
+        Ok(())
+    }
+
+    fn check_node(&self, node: Taxon, registry: &CategoryRegistry) -> Result<(), RegistryError> {
+        match node {
+            Taxon::Category(CategoryKey::Custom(id)) if registry.category(id).is_none() => {
+                Err(RegistryError::UnknownCategory(id))
+            }
+            Taxon::Group(GroupKey::Custom(id)) if registry.group(id).is_none() => Err(RegistryError::UnknownGroup(id)),
+            Taxon::Class(id) if self.class(id).is_none() => Err(RegistryError::UnknownClass(id)),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_parent(&self, parent: Taxon, registry: &CategoryRegistry) -> Result<(), RegistryError> {
+        if let Taxon::Group(_) = parent {
+            return Err(RegistryError::InvalidParent(parent));
+        }
+        self.check_node(parent, registry)
+    }
+
+    /// Get a class by ID
+    pub fn class(&self, id: ClassId) -> Option<&TaxonClass> {
+        self.classes.get(id.0 as usize)
+    }
+
+    /// All classes
+    pub fn classes(&self) -> &[TaxonClass] {
+        &self.classes
+    }
+
+    /// Find a class, category or group by name (case-insensitive)
+    pub fn resolve(&self, name: &str, registry: &CategoryRegistry) -> Option<Taxon> {
+        self.classes.iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name))
+            .map(|c| Taxon::Class(c.id))
+            .or_else(|| registry.resolve_category(name).map(Taxon::Category))
+            .or_else(|| registry.resolve_group(name).map(Taxon::Group))
+    }
+
+    /// Display name of a node
+    pub fn name<'a>(&'a self, node: Taxon, registry: &'a CategoryRegistry) -> &'a str {
+        match node {
+            Taxon::Category(key) => registry.category_name(key),
+            Taxon::Group(key) => registry.group_name(key),
+            Taxon::Class(id) => self.class(id).map(|c| c.name.as_str()).unwrap_or("?"),
+        }
+    }
+
+    /// Direct parent of a node (`None` for roots)
+    pub fn parent(&self, node: Taxon, registry: &CategoryRegistry) -> Option<Taxon> {
+        self.parents.get(&node).copied().or_else(|| match node {
+            Taxon::Group(group) => registry.category_of(group).map(Taxon::Category),
+            _ => None,
+        })
+    }
+
+    /// Ancestors of a node, nearest first
+    pub fn ancestors(&self, node: Taxon, registry: &CategoryRegistry) -> Vec<Taxon> {
+        let mut chain = Vec::new();
+        let mut current = self.parent(node, registry);
+        while let Some(parent) = current {
+            if chain.contains(&parent) {
+                break;
+            }
+            chain.push(parent);
+            current = self.parent(parent, registry);
+        }
+        chain
+    }
+
+    /// Check if `node` is `ancestor` or lies below it
+    pub fn is_under(&self, node: Taxon, ancestor: Taxon, registry: &CategoryRegistry) -> bool {
+        node == ancestor || self.ancestors(node, registry).contains(&ancestor)
+    }
+
+    /// All nodes below a node (classes, custom categories and groups)
+    pub fn descendants(&self, node: Taxon, registry: &CategoryRegistry) -> Vec<Taxon> {
+        self.nodes(registry)
+            .filter(|n| *n != node && self.is_under(*n, node, registry))
+            .collect()
+    }
+
+    /// Direct children of a node
+    pub fn children(&self, node: Taxon, registry: &CategoryRegistry) -> Vec<Taxon> {
+        self.nodes(registry)
+            .filter(|n| self.parent(*n, registry) == Some(node))
+            .collect()
+    }
+
+    /// Deepest node that both nodes are under (either node itself counts)
+    pub fn lowest_common_ancestor(&self, a: Taxon, b: Taxon, registry: &CategoryRegistry) -> Option<Taxon> {
+        let mut chain_a = vec![a];
+        chain_a.extend(self.ancestors(a, registry));
+        std::iter::once(b)
+            .chain(self.ancestors(b, registry))
+            .find(|n| chain_a.contains(n))
+    }
+
+    /// Number of ancestors of a node (roots have depth 0)
+    pub fn depth(&self, node: Taxon, registry: &CategoryRegistry) -> usize {
+        self.ancestors(node, registry).len()
+    }
+
+    /// Every node that can have a parent
+    fn nodes<'a>(&'a self, registry: &'a CategoryRegistry) -> impl Iterator<Item = Taxon> + 'a {
+        self.classes.iter().map(|c| Taxon::Class(c.id))
+            .chain(registry.categories().iter().map(|c| Taxon::Category(CategoryKey::Custom(c.id))))
+            .chain(VerbGroup::all().iter().map(|g| Taxon::Group(GroupKey::Builtin(*g))))
+            .chain(registry.groups().iter().map(|g| Taxon::Group(GroupKey::Custom(g.id))))
+    }
+}
+
+impl VerbDatabase {
+    /// Register a taxonomy class (see `Taxonomy::register_class`)
+    pub fn register_class(&mut self, name: &str, parent: impl Into<Taxon>) -> Result<ClassId, RegistryError> {
+        self.taxonomy.register_class(name, parent, &self.registry)
+    }
+
+    /// Move a node under a new parent (see `Taxonomy::set_parent`)
+    pub fn set_parent(&mut self, node: impl Into<Taxon>, parent: impl Into<Taxon>) -> Result<(), RegistryError> {
+        self.taxonomy.set_parent(node, parent, &self.registry)
+    }
+
+    /// Classes and parent links
+    pub fn taxonomy(&self) -> &Taxonomy {
+        &self.taxonomy
+    }
+
+    /// Find a class, category or group by name
+    pub fn resolve_taxon(&self, name: &str) -> Option<Taxon> {
+        self.taxonomy.resolve(name, &self.registry)
+    }
+
+    /// Display name of a taxonomy node
+    pub fn taxon_name(&self, node: Taxon) -> &str {
+        self.taxonomy.name(node, &self.registry)
+    }
+
+    /// Ancestors of a node, nearest first
+    pub fn ancestors(&self, node: impl Into<Taxon>) -> Vec<Taxon> {
+        self.taxonomy.ancestors(node.into(), &self.registry)
+    }
+
+    /// All nodes below a node
+    pub fn descendants(&self, node: impl Into<Taxon>) -> Vec<Taxon> {
+        self.taxonomy.descendants(node.into(), &self.registry)
+    }
+
+    /// Check if a node is another node or lies below it
+    ///
+    /// Works on the keys carried by `DetectedAction`:
+    /// `db.is_under(action.group_key(), FunctionalCategory::Destruction)`.
+    pub fn is_under(&self, node: impl Into<Taxon>, ancestor: impl Into<Taxon>) -> bool {
+        self.taxonomy.is_under(node.into(), ancestor.into(), &self.registry)
+    }
+
+    /// Deepest node that both nodes are under
+    pub fn lowest_common_ancestor(&self, a: impl Into<Taxon>, b: impl Into<Taxon>) -> Option<Taxon> {
+        self.taxonomy.lowest_common_ancestor(a.into(), b.into(), &self.registry)
+    }
+
+    /// Nodes an entry hangs from: its custom and built-in group and category
+    fn entry_taxa(&self, entry: &VerbEntry) -> Vec<Taxon> {
+        let mut taxa = vec![Taxon::Group(entry.group_key())];
+        for node in [
+            Taxon::Group(GroupKey::Builtin(entry.group)),
+            Taxon::Category(entry.category_key()),
+            Taxon::Category(CategoryKey::Builtin(entry.category)),
+        ] {
+            if !taxa.contains(&node) {
+                taxa.push(node);
+            }
+        }
+        taxa
+    }
+
+    /// Check if an entry lies below a node
+    pub fn entry_is_a(&self, entry: &VerbEntry, node: impl Into<Taxon>) -> bool {
+        let node = node.into();
+        self.entry_taxa(entry).into_iter().any(|t| self.taxonomy.is_under(t, node, &self.registry))
+    }
+
+    /// Check if any sense of a verb (any form) lies below a node
+    pub fn is_a(&self, verb: &str, node: impl Into<Taxon>) -> bool {
+        let node = node.into();
+        self.lookup_all(verb)
+            .map(|entries| entries.iter().any(|e| self.entry_is_a(e, node)))
+            .unwrap_or(false)
+    }
+
+    /// Every node above any sense of a verb, nearest first
+    pub fn verb_ancestors(&self, verb: &str) -> Vec<Taxon> {
+        let mut found = Vec::new();
+        for entry in self.lookup_all(verb).into_iter().flatten() {
+            for start in self.entry_taxa(entry) {
+                for node in std::iter::once(start).chain(self.ancestors(start)) {
+                    if !found.contains(&node) {
+                        found.push(node);
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    /// All verb entries below a node
+    pub fn verbs_under(&self, node: impl Into<Taxon>) -> Vec<&VerbEntry> {
+        let node = node.into();
+        let mut entries: Vec<&VerbEntry> = self.all_verbs().filter(|e| self.entry_is_a(e, node)).collect();
+        entries.sort_by(|a, b| a.base.cmp(&b.base));
+        entries
+    }
+
+    /// Deepest node shared by any senses of two verbs
+    pub fn common_ancestor(&self, a: &str, b: &str) -> Option<Taxon> {
+        let above_b = self.verb_ancestors(b);
+        self.verb_ancestors(a)
+            .into_iter()
+            .filter(|n| above_b.contains(n))
+            .max_by_key(|n| self.taxonomy.depth(*n, &self.registry))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_hierarchy() {
+        let db = VerbDatabase::with_builtin();
+        let self_motion = db.resolve_taxon("self-motion").unwrap();
+        assert_eq!(db.ancestors(VerbGroup::Run), vec![self_motion, FunctionalCategory::Movement.into()]);
+        assert!(db.descendants(FunctionalCategory::Movement).contains(&VerbGroup::Run.into()));
+        assert!(db.is_a("dashed", VerbGroup::Run));
+        assert!(db.is_a("sprint", FunctionalCategory::Movement));
+        assert!(!db.is_a("sprint", FunctionalCategory::Destruction));
+        assert_eq!(db.lowest_common_ancestor(VerbGroup::Run, VerbGroup::Enter), Some(FunctionalCategory::Movement.into()));
+        assert_eq!(db.common_ancestor("sprint", "dash"), Some(VerbGroup::Run.into()));
+        assert_eq!(db.lowest_common_ancestor(VerbGroup::Run, VerbGroup::Eat), None);
+    }
+
+    #[test]
+    fn test_new_subgroups_inherit_rules() {
+        let mut db = VerbDatabase::with_builtin();
+        let wipe = db.register_class("Data loss", FunctionalCategory::Destruction).unwrap();
+        db.set_parent(VerbGroup::Erase, wipe).unwrap();
+        let purge = db.register_group("Purge", FunctionalCategory::Control).unwrap();
+        db.add(VerbEntry::regular("purge", FunctionalCategory::Control, VerbGroup::ControlGroup, Some(true), 20)
+            .in_custom_group(purge));
+        assert!(!db.is_a("purge", FunctionalCategory::Destruction));
+
+        db.set_parent(purge, wipe).unwrap();
+        assert!(db.is_a("purged", FunctionalCategory::Destruction));
+        assert!(db.is_under(GroupKey::Custom(purge), wipe));
+        assert!(db.verbs_under(wipe).iter().any(|e| e.base == "delete"));
+        assert_eq!(db.taxon_name(wipe.into()), "Data loss");
+    }
+
+    #[test]
+    fn test_invalid_parents_rejected() {
+        let mut db = VerbDatabase::with_builtin();
+        let outer = db.register_class("Outer", FunctionalCategory::Social).unwrap();
+        let inner = db.register_class("Inner", outer).unwrap();
+        assert_eq!(db.set_parent(outer, inner), Err(RegistryError::Cycle(outer.into())));
+        assert_eq!(db.set_parent(FunctionalCategory::Social, outer),
+            Err(RegistryError::BuiltinRoot(FunctionalCategory::Social)));
+        assert_eq!(db.set_parent(VerbGroup::Help, VerbGroup::Meet),
+            Err(RegistryError::InvalidParent(VerbGroup::Meet.into())));
+        assert_eq!(db.register_class("Run", outer), Err(RegistryError::BuiltinName("Run".to_string())));
+        assert_eq!(db.register_class("inner", outer), Ok(inner));
+        assert_eq!(db.register_class("Inner", FunctionalCategory::Social),
+            Err(RegistryError::DuplicateName("Inner".to_string())));
+        let devops = db.register_category("DevOps", "").unwrap();
+        assert_eq!(db.register_class("devops", outer), Err(RegistryError::DuplicateName("devops".to_string())));
+        assert!(db.set_parent(devops, outer).is_ok());
+        // Categories and groups cannot take a class name
+        assert_eq!(db.register_group("Self-motion", FunctionalCategory::Movement),
+            Err(RegistryError::BuiltinName("Self-motion".to_string())));
+        assert_eq!(db.register_category("Giving", ""), Err(RegistryError::BuiltinName("Giving".to_string())));
+        assert_eq!(db.register_category("inner", ""), Err(RegistryError::DuplicateName("Inner".to_string())));
+    }
+}
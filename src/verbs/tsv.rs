@@ -0,0 +1,170 @@
+//! Line parser for the plain-text verb format
+//!
+//! Shared by `format` and the build script, so the built-in data and files
+//! loaded at runtime are read by the same rules. Category and group names
+//! are resolved by the caller.
+
+use super::inflection;
+
+/// Number of mandatory columns (up to frequency)
+pub const REQUIRED_COLUMNS: usize = 9;
+/// Number of columns including synonyms, antonyms, frames and the custom taxonomy
+pub const ALL_COLUMNS: usize = 14;
+
+/// Marker for a generated form or an empty list
+pub const EMPTY: &str = "-";
+
+/// Frame names of the frames column, the lowercase names of the `Frame` constants
+pub const FRAME_NAMES: &[&str] = &[
+    "intransitive", "unaccusative", "transitive", "ditransitive", "dative", "locative",
+    "source", "motion", "position", "instrument", "that_clause", "recipient_that",
+    "to_recipient", "infinitive", "object_infinitive", "copular",
+];
+
+/// Category display names that differ from the variant name
+pub const CATEGORY_NAMES: &[(&str, &str)] = &[
+    ("Physical", "Physical Action"),
+    ("Change", "Change of State"),
+    ("Body", "Body Function"),
+];
+
+/// Group display names that differ from the variant name
+pub const GROUP_NAMES: &[(&str, &str)] = &[
+    ("ControlGroup", "Control"),
+    ("HelpCausation", "Help (Causative)"),
+];
+
+/// What is wrong with a line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatErrorKind {
+    /// Wrong number of tab-separated fields
+    ColumnCount { found: usize },
+    /// A mandatory field is empty
+    EmptyField(&'static str),
+    /// Category name not in `FunctionalCategory`
+    UnknownCategory(String),
+    /// Group name not in `VerbGroup`
+    UnknownGroup(String),
+    /// Not `transitive`, `intransitive` or `both`
+    InvalidTransitivity(String),
+    /// Not a number in 1-100
+    InvalidFrequency(String),
+    /// Frame name not in `FRAME_NAMES`
+    UnknownFrame(String),
+    /// The same base form is already defined for this group
+    /// (`first_line` is `None` when it came from the database itself)
+    DuplicateForm { form: String, first_line: Option<usize> },
+    /// An inflected form already belongs to another base
+    SharedForm { form: String, base: String, first_line: Option<usize> },
+}
+
+/// One parsed line, with generated forms filled in
+#[derive(Debug, Clone)]
+pub struct VerbLine<C, G> {
+    pub base: String,
+    pub past: String,
+    pub past_participle: String,
+    pub present_participle: String,
+    pub third_person: String,
+    /// Past and past participle were given explicitly
+    pub irregular: bool,
+    pub category: C,
+    pub group: G,
+    pub transitive: Option<bool>,
+    pub frequency: u8,
+    pub synonyms: Vec<String>,
+    pub antonyms: Vec<String>,
+    /// Frame names, `None` for the group defaults
+    pub frames: Option<Vec<String>>,
+    /// Custom category name, resolved by the caller
+    pub custom_category: Option<String>,
+    /// Custom group name, resolved by the caller
+    pub custom_group: Option<String>,
+}
+
+/// Whether a line carries no data (blank or a `#` comment)
+pub fn is_blank(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.is_empty() || trimmed.starts_with('#')
+}
+
+/// Variant name for a name written in the data, given the display names
+/// that differ from their variant
+pub fn variant_name<'a>(name: &'a str, display_names: &[(&'a str, &str)]) -> &'a str {
+    display_names.iter()
+        .find(|(_, display)| display.eq_ignore_ascii_case(name))
+        .map_or(name, |(variant, _)| variant)
+}
+
+/// Parse one data line, resolving category and group names with the given functions
+pub fn parse_line<C, G>(
+    line: &str,
+    category: impl Fn(&str) -> Option<C>,
+    group: impl Fn(&str) -> Option<G>,
+) -> Result<VerbLine<C, G>, FormatErrorKind> {
+    let fields: Vec<&str> = line.trim_end_matches('\r').split('\t').map(|f| f.trim()).collect();
+    if fields.len() < REQUIRED_COLUMNS || fields.len() > ALL_COLUMNS {
+        return Err(FormatErrorKind::ColumnCount { found: fields.len() });
+    }
+
+    const NAMES: [&str; REQUIRED_COLUMNS] = [
+        "base", "past", "past participle", "present participle", "third person",
+        "category", "group", "transitivity", "frequency",
+    ];
+    if let Some(i) = (0..REQUIRED_COLUMNS).find(|&i| fields[i].is_empty()) {
+        return Err(FormatErrorKind::EmptyField(NAMES[i]));
+    }
+
+    let base = fields[0].to_lowercase();
+    let category = category(fields[5]).ok_or_else(|| FormatErrorKind::UnknownCategory(fields[5].to_string()))?;
+    let group = group(fields[6]).ok_or_else(|| FormatErrorKind::UnknownGroup(fields[6].to_string()))?;
+    let transitive = match fields[7].to_lowercase().as_str() {
+        "transitive" => Some(true),
+        "intransitive" => Some(false),
+        "both" => None,
+        _ => return Err(FormatErrorKind::InvalidTransitivity(fields[7].to_string())),
+    };
+    let frequency = fields[8].parse::<u8>().ok()
+        .filter(|f| (1..=100).contains(f))
+        .ok_or_else(|| FormatErrorKind::InvalidFrequency(fields[8].to_string()))?;
+
+    let frames = match fields.get(11).filter(|f| !f.is_empty() && **f != EMPTY) {
+        Some(f) => {
+            let names: Vec<String> = f.split(',').map(|w| w.trim().to_lowercase()).filter(|w| !w.is_empty()).collect();
+            if let Some(unknown) = names.iter().find(|n| !FRAME_NAMES.contains(&n.as_str())) {
+                return Err(FormatErrorKind::UnknownFrame(unknown.clone()));
+            }
+            Some(names)
+        }
+        None => None,
+    };
+
+    let explicit = |i: usize| (fields[i] != EMPTY).then(|| fields[i].to_lowercase());
+    let generated = inflection::inflect(&base);
+    let irregular = explicit(1).is_some() || explicit(2).is_some();
+    let name = |i: usize| fields.get(i).filter(|f| !f.is_empty() && **f != EMPTY).map(|f| f.to_string());
+    let list = |i: usize| -> Vec<String> {
+        fields.get(i)
+            .filter(|f| !f.is_empty() && **f != EMPTY)
+            .map(|f| f.split(',').map(|w| w.trim().to_lowercase()).filter(|w| !w.is_empty()).collect())
+            .unwrap_or_default()
+    };
+
+    Ok(VerbLine {
+        past: explicit(1).unwrap_or_else(|| generated.past.clone()),
+        past_participle: explicit(2).unwrap_or_else(|| generated.past.clone()),
+        present_participle: explicit(3).unwrap_or(generated.present_participle),
+        third_person: explicit(4).unwrap_or(generated.third_person),
+        base,
+        irregular,
+        category,
+        group,
+        transitive,
+        frequency,
+        synonyms: list(9),
+        antonyms: list(10),
+        frames,
+        custom_category: name(12),
+        custom_group: name(13),
+    })
+}
@@ -266,6 +266,35 @@ fn test_disambiguator_process_sentence() {
     assert_eq!(result.detected_actions[0].base_form, "run");
 }
 
+#[test]
+fn test_verb_form_collision_report() {
+    let db = nl_sre_english::VerbDatabase::with_builtin();
+    let collisions = db.form_collisions();
+
+    // The full listing is printed by `cargo run --example form_collisions`
+    for form in ["found", "lay"] {
+        assert!(collisions.iter().any(|c| c.form == form), "'{}' should be reported", form);
+    }
+}
+
+#[test]
+fn test_shared_form_resolves_to_most_frequent_verb() {
+    use nl_sre_english::verbs::{VerbDatabase, VerbEntry, VerbGroup};
+
+    // Lookup used to return whichever verb was added last; the most
+    // frequent reading now wins regardless of insertion order
+    let mut db = VerbDatabase::new();
+    db.add(VerbEntry::irregular("find", "found", "found", FunctionalCategory::Cognition, VerbGroup::Learn, Some(true), 90));
+    db.add(VerbEntry::regular("found", FunctionalCategory::Creation, VerbGroup::Build, Some(true), 30));
+    assert_eq!(db.base_form("found"), Some("find"));
+    assert_eq!(db.lookup("founded").unwrap().base, "found");
+
+    let builtin = VerbDatabase::with_builtin();
+    assert_eq!(builtin.base_form("found"), Some("find"));
+    // "lay" is as frequent as "lie", so its base-form reading wins
+    assert_eq!(builtin.base_form("lay"), Some("lay"));
+}
+
 #[test]
 fn test_disambiguator_verbs_by_category() {
     let disambiguator = SemanticDisambiguator::new();